@@ -0,0 +1,208 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
+
+// eg:
+// #[derive(Manageable)]
+// #[sql_gen(table = "users", primary_key = "UserID", version = "lock_version")]
+// struct User {
+//   #[sql_gen(column = "UserID")]
+//   id: i64,
+//   name: String,
+//   #[sql_gen(skip)]
+//   password_confirmation: String,
+// }
+#[proc_macro_derive(Manageable, attributes(sql_gen))]
+pub fn derive_manageable(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  match expand(&input) {
+    Ok(tokens) => tokens.into(),
+    Err(error) => error.to_compile_error().into(),
+  }
+}
+
+struct Attr {
+  ident: syn::Ident,
+  attr_name: String,
+  column_name: String,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+  let mut table_name: Option<LitStr> = None;
+  let mut primary_key: Option<LitStr> = None;
+  let mut version: Option<LitStr> = None;
+  for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("sql_gen")) {
+    attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("table") {
+        table_name = Some(meta.value()?.parse()?);
+      } else if meta.path.is_ident("primary_key") {
+        primary_key = Some(meta.value()?.parse()?);
+      } else if meta.path.is_ident("version") {
+        version = Some(meta.value()?.parse()?);
+      } else {
+        return Err(meta.error("expected `table`, `primary_key` or `version`"));
+      }
+      Ok(())
+    })?;
+  }
+
+  let fields = match &input.data {
+    Data::Struct(data) => match &data.fields {
+      Fields::Named(fields) => &fields.named,
+      _ => return Err(syn::Error::new_spanned(&input.ident, "Manageable can only be derived for structs with named fields")),
+    },
+    _ => return Err(syn::Error::new_spanned(&input.ident, "Manageable can only be derived for structs")),
+  };
+
+  let mut attrs = vec![];
+  for field in fields.iter() {
+    let ident = field.ident.clone().unwrap();
+    // r#type => type
+    let attr_name = ident.to_string().trim_start_matches("r#").to_owned();
+    let mut column_name = attr_name.clone();
+    let mut column_span = ident.span();
+    let mut skip = false;
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("sql_gen")) {
+      attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("column") {
+          let column = meta.value()?.parse::<LitStr>()?;
+          column_name = column.value();
+          column_span = column.span();
+        } else if meta.path.is_ident("skip") {
+          skip = true;
+        } else {
+          return Err(meta.error("expected `column` or `skip`"));
+        }
+        Ok(())
+      })?;
+    }
+    if skip {
+      continue;
+    }
+    // 列名和属性名需要一一对应, eg: #[sql_gen(column = "name")] nickname 与 name 冲突
+    if let Some(other) = attrs.iter().find(|other: &&Attr| other.column_name == column_name) {
+      return Err(syn::Error::new(column_span, format!("duplicate column `{}`, already used by field `{}`", column_name, other.ident)));
+    }
+    attrs.push(Attr { ident, attr_name, column_name });
+  }
+
+  let name = &input.ident;
+  let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+  let table_name_fn = table_name.map(|table_name| quote! {
+    fn table_name() -> String { #table_name.to_owned() }
+  });
+  let primary_key_fn = primary_key.map(|primary_key| quote! {
+    fn primary_key() -> &'static str { #primary_key }
+  });
+  let version_fn = version.map(|version| quote! {
+    fn version_column() -> Option<&'static str> { Some(#version) }
+  });
+  let attr_names = attrs.iter().map(|attr| &attr.attr_name).collect::<Vec<_>>();
+  let column_names = attrs.iter().map(|attr| &attr.column_name).collect::<Vec<_>>();
+  let idents = attrs.iter().map(|attr| &attr.ident).collect::<Vec<_>>();
+
+  Ok(quote! {
+    impl #impl_generics ::sql_gen::Manageable for #name #ty_generics #where_clause {
+      #table_name_fn
+      #primary_key_fn
+      #version_fn
+
+      fn table_column_names() -> Vec<&'static str> { vec![#(#column_names),*] }
+      fn attr_names() -> Vec<&'static str> { vec![#(#attr_names),*] }
+      fn attr_name_to_table_column_name<'a>(attr_name: &'a str) -> Result<&'a str, ::sql_gen::SqlError> {
+        match attr_name {
+          #(#attr_names => Ok(#column_names),)*
+          _ => Err(::sql_gen::SqlError::UnknownColumn { table: Self::table_name(), column: attr_name.to_owned() }),
+        }
+      }
+      fn table_column_name_to_attr_name<'a>(table_column_name: &'a str) -> Result<&'a str, ::sql_gen::SqlError> {
+        match table_column_name {
+          #(#column_names => Ok(#attr_names),)*
+          _ => Err(::sql_gen::SqlError::UnknownColumn { table: Self::table_name(), column: table_column_name.to_owned() }),
+        }
+      }
+
+      fn get_json_value_from_attr_name__(&self, attr_name: &str) -> Option<::sql_gen::serde_json::Value> {
+        match attr_name {
+          #(#attr_names => ::sql_gen::serde_json::to_value(&self.#idents).ok(),)*
+          _ => None,
+        }
+      }
+      fn get_sql_value_from_attr_name__(&self, attr_name: &str) -> Option<::sql_gen::SqlValue> {
+        #[allow(unused_imports)]
+        use ::sql_gen::collectors::sql_value::{ToSqlValue, SerializeToSqlValue};
+        match attr_name {
+          #(#attr_names => (&::sql_gen::collectors::sql_value::AttrValue(&self.#idents)).to_sql_value(),)*
+          _ => None,
+        }
+      }
+    }
+  })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn expand_with_columns() {
+      let input: DeriveInput = syn::parse_quote! {
+        #[sql_gen(table = "users", primary_key = "UserID")]
+        struct User {
+          #[sql_gen(column = "UserID")]
+          id: i64,
+          r#type: String,
+          #[sql_gen(skip)]
+          password_confirmation: String,
+        }
+      };
+      let tokens = expand(&input).unwrap().to_string();
+      assert!(tokens.contains(&quote! { fn table_column_names() -> Vec<&'static str> { vec!["UserID", "type"] } }.to_string()));
+      assert!(tokens.contains(&quote! { fn attr_names() -> Vec<&'static str> { vec!["id", "type"] } }.to_string()));
+      assert!(!tokens.contains("password_confirmation"));
+    }
+    #[test]
+    fn expand_with_duplicate_columns() {
+      // 重命名后与其它列同名
+      let input: DeriveInput = syn::parse_quote! {
+        struct User {
+          name: String,
+          #[sql_gen(column = "name")]
+          nickname: String,
+        }
+      };
+      assert_eq!(expand(&input).unwrap_err().to_string(), "duplicate column `name`, already used by field `name`");
+
+      let input: DeriveInput = syn::parse_quote! {
+        struct User {
+          #[sql_gen(column = "name")]
+          first_name: String,
+          #[sql_gen(column = "name")]
+          last_name: String,
+        }
+      };
+      assert_eq!(expand(&input).unwrap_err().to_string(), "duplicate column `name`, already used by field `first_name`");
+
+      // 跳过的属性不占用列名
+      let input: DeriveInput = syn::parse_quote! {
+        struct User {
+          #[sql_gen(skip)]
+          name: String,
+          #[sql_gen(column = "name")]
+          nickname: String,
+        }
+      };
+      assert!(expand(&input).is_ok());
+    }
+    #[test]
+    fn expand_with_invalid_input() {
+      let input: DeriveInput = syn::parse_quote! { struct User(i64); };
+      assert!(expand(&input).is_err());
+      let input: DeriveInput = syn::parse_quote! {
+        struct User {
+          #[sql_gen(rename = "user_id")]
+          id: i64,
+        }
+      };
+      assert_eq!(expand(&input).unwrap_err().to_string(), "expected `column` or `skip`");
+    }
+}
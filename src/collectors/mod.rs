@@ -1,3 +1,5 @@
 pub mod sql;
+pub mod sql_value;
 
-pub use sql::Sql;
\ No newline at end of file
+pub use sql::{Sql, PlaceholderStyle};
+pub use sql_value::SqlValue;
\ No newline at end of file
@@ -1,17 +1,55 @@
 use crate::error::SqlError;
+use super::SqlValue;
+
+// 预处理语句的占位符形式
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum PlaceholderStyle {
+  // mysql, sqlite: ?
+  #[default]
+  Question,
+  // postgresql: $1, $2 ...
+  Dollar,
+  // sqlite, oracle: 命名参数使用原名 :name, 其它为 :p1, :p2 ..., 参数见 Sql::named_prepare_values
+  Colon,
+  // mssql: @p1, @p2 ...
+  AtP,
+}
+
+impl PlaceholderStyle {
+  // idx 从 1 开始, name: 命名参数的参数名
+  pub fn render(&self, idx: usize, name: Option<&str>) -> String {
+    self.render_with_prefix(idx, name, "p")
+  }
+  // positional_prefix: PlaceholderStyle::Colon 时位置参数的前缀, eg: p => :p1
+  fn render_with_prefix(&self, idx: usize, name: Option<&str>, positional_prefix: &str) -> String {
+    match self {
+      PlaceholderStyle::Question => "?".to_owned(),
+      PlaceholderStyle::Dollar => format!("${}", idx),
+      PlaceholderStyle::Colon => match name {
+        Some(name) => format!(":{}", name),
+        None => format!(":{}{}", positional_prefix, idx),
+      },
+      PlaceholderStyle::AtP => format!("@p{}", idx),
+    }
+  }
+}
 
 #[derive(Clone, Debug)]
 pub struct Sql {
     pub value: String,
-    pub prepare_value: Option<Vec<String>>,
+    pub prepare_value: Option<Vec<SqlValue>>,
+    // 每个占位符在 value 中的位置, 和 prepare_value 一一对应
+    pub placeholders: Vec<std::ops::Range<usize>>,
+    // 每个占位符的参数名, 和 placeholders 一一对应, None: 位置参数
+    pub placeholder_names: Vec<Option<String>>,
+    pub placeholder_style: PlaceholderStyle,
+    // to_sql_string 时 prepare_value 转换为字面量使用的数据库
+    pub dialect: Option<crate::Dialect>,
 }
 
 impl Default for Sql {
   fn default() -> Self {
-      Self {
-        value: "".to_string(),
-        prepare_value: None,
-      }
+      Self::new("".to_string())
   }
 }
 
@@ -20,6 +58,10 @@ impl Sql {
       Self {
         value,
         prepare_value: None,
+        placeholders: vec![],
+        placeholder_names: vec![],
+        placeholder_style: PlaceholderStyle::default(),
+        dialect: None,
       }
   }
   pub fn push(&mut self, char: char) -> &mut Self {
@@ -35,34 +77,117 @@ impl Sql {
     self.push_value(value);
     self.push_value(quote)
   }
-  pub fn push_prepare_value(&mut self, sub_prepare_value: &str) -> &mut Self {
-    if let Some(prepare_value) = &mut self.prepare_value {
-      prepare_value.push(sub_prepare_value.into());
-    } else {
-        self.prepare_value = Some(vec![sub_prepare_value.into()]);
-    }
-    self
+  // 追加一个占位符, 按当前的 placeholder_style 渲染
+  pub fn push_placeholder(&mut self, sub_prepare_value: SqlValue) -> &mut Self {
+    self.push_named_placeholder(None, sub_prepare_value)
   }
-  pub fn push_value_with_prepare_value(&mut self, sub_value: &str, sub_prepare_value: &str) -> &mut Self {
-    self.value.push_str(sub_value);
-    self.push_prepare_value(sub_prepare_value);
+  // 命名参数的占位符, PlaceholderStyle::Colon 时渲染为 :name
+  pub fn push_named_placeholder(&mut self, name: Option<&str>, sub_prepare_value: SqlValue) -> &mut Self {
+    self.push_placeholder_with_prefix(name, sub_prepare_value, "p")
+  }
+  fn push_placeholder_with_prefix(&mut self, name: Option<&str>, sub_prepare_value: SqlValue, positional_prefix: &str) -> &mut Self {
+    let placeholder = self.placeholder_style.render_with_prefix(self.placeholders.len() + 1, name, positional_prefix);
+    let start = self.value.len();
+    self.value.push_str(&placeholder);
+    self.placeholders.push(start..self.value.len());
+    self.placeholder_names.push(name.map(|name| name.to_owned()));
+    self.prepare_value.get_or_insert_with(Vec::new).push(sub_prepare_value);
     self
   }
+  // 手写的 sql 片段, eg: ["a = ? AND b = ?", 1, 2]
+  // 引号内的 ? 不是占位符, ?? 表示 ? 本身, eg: postgresql: data ?? 'key'
+  pub fn push_raw(&mut self, raw: &str, values: Vec<SqlValue>) -> Result<&mut Self, SqlError> {
+    // 先数出片段中的占位符
+    let mut expected = 0;
+    Sql::default().push_raw_with(raw, false, |_| { expected += 1; Ok(SqlValue::Null) })?;
+    if expected != values.len() {
+      return Err(SqlError::ParameterCountMismatch { clause: "SQL", expected, actual: values.len(), sql: raw.to_owned() });
+    }
+    let mut values = values.into_iter();
+    self.push_raw_with(raw, false, |_| Ok(values.next().unwrap_or(SqlValue::Null)))?;
+    Ok(self)
+  }
+  // 命名参数, eg: ["created_at > :since AND owner = :uid", {"since": "2021-01-01", "uid": 1}]
+  // ? 和 :: 不是占位符, eg: postgresql: data ? 'key' AND id::text = :id
+  pub fn push_raw_named(&mut self, raw: &str, values: &std::collections::HashMap<String, SqlValue>) -> Result<&mut Self, SqlError> {
+    let mut used = std::collections::HashSet::new();
+    self.push_raw_with(raw, true, |name| {
+      let name = name.unwrap_or_default();
+      let value = values.get(name).ok_or_else(|| SqlError::MissingParameter { clause: "SQL", name: name.to_owned(), sql: raw.to_owned() })?;
+      used.insert(name.to_owned());
+      Ok(value.clone())
+    })?;
+    let mut unused = values.keys().filter(|name| !used.contains(*name)).cloned().collect::<Vec<String>>();
+    if !unused.is_empty() {
+      unused.sort();
+      return Err(SqlError::UnusedParameter { clause: "SQL", names: unused, sql: raw.to_owned() });
+    }
+    Ok(self)
+  }
+  fn push_raw_with(&mut self, raw: &str, named: bool, mut next_value: impl FnMut(Option<&str>) -> Result<SqlValue, SqlError>) -> Result<(), SqlError> {
+    let mut quote: Option<char> = None;
+    let mut chars = raw.chars().peekable();
+    while let Some(char) = chars.next() {
+      match (quote, char) {
+        (Some(q), _) if char == q => {
+          // 连续两个引号是转义
+          if chars.peek() == Some(&q) {
+            self.value.push(char);
+            chars.next();
+          } else {
+            quote = None;
+          }
+          self.value.push(char);
+        },
+        (Some(_), _) => self.value.push(char),
+        (None, '\'' | '"' | '`') => {
+          quote = Some(char);
+          self.value.push(char);
+        },
+        (None, '?') if !named && chars.peek() == Some(&'?') => {
+          chars.next();
+          self.value.push('?');
+        },
+        (None, '?') if !named => {
+          let value = next_value(None)?;
+          self.push_placeholder(value);
+        },
+        (None, ':') if named && chars.peek() == Some(&':') => {
+          chars.next();
+          self.value.push_str("::");
+        },
+        (None, ':') if named && chars.peek().is_some_and(|c| c.is_ascii_alphabetic() || *c == '_') => {
+          let mut name = String::new();
+          while let Some(c) = chars.peek() && (c.is_ascii_alphanumeric() || *c == '_') {
+            name.push(*c);
+            chars.next();
+          }
+          let value = next_value(Some(&name))?;
+          self.push_named_placeholder(Some(&name), value);
+        },
+        (None, _) => self.value.push(char),
+      }
+    }
+    Ok(())
+  }
 
   pub fn push_sql(&mut self, sql: &Sql) -> &mut Self {
-    if let Some(prepare_value) = &sql.prepare_value {
-      if let Some(self_prepare_value) = &mut self.prepare_value {
-        self_prepare_value.extend_from_slice(prepare_value)
-      } else {
-        self.prepare_value = Some(prepare_value.clone());
-      }
-      self.push_value(&sql.value);
-    } else {
-        self.push_value(&sql.value);
+    self.push_sql_with_prefix(sql, "p")
+  }
+  fn push_sql_with_prefix(&mut self, sql: &Sql, positional_prefix: &str) -> &mut Self {
+    if self.dialect.is_none() {
+      self.dialect = sql.dialect;
     }
+    let mut last = 0;
+    for ((range, name), value) in sql.placeholders.iter().zip(sql.placeholder_names.iter()).zip(sql.prepare_value.iter().flatten()) {
+      self.value.push_str(&sql.value[last..range.start]);
+      self.push_placeholder_with_prefix(name.as_deref(), value.clone(), positional_prefix);
+      last = range.end;
+    }
+    self.value.push_str(&sql.value[last..]);
     self
   }
-  pub fn push_sqls(&mut self, sqls: &Vec<Sql>, join_str: &str) -> &mut Self {
+  pub fn push_sqls(&mut self, sqls: &[Sql], join_str: &str) -> &mut Self {
     let len = sqls.len();
     for (idx, sql) in sqls.iter().enumerate() {
         self.push_sql(sql);
@@ -73,26 +198,65 @@ impl Sql {
     self
   }
 
+  // 重新渲染所有占位符, eg: PlaceholderStyle::Dollar, a = ? AND b = ? => a = $1 AND b = $2
+  pub fn set_placeholder_style(&mut self, style: PlaceholderStyle) -> &mut Self {
+    let sql = std::mem::replace(self, Sql::new("".to_owned()));
+    self.placeholder_style = style;
+    self.push_sql_with_prefix(&sql, &sql.positional_prefix());
+    self.dialect = sql.dialect;
+    self
+  }
+  // PlaceholderStyle::Colon: 位置参数的前缀, 和命名参数冲突时加 _, eg: 有命名参数 :p1 时使用 :p_1
+  fn positional_prefix(&self) -> String {
+    let mut prefix = "p".to_owned();
+    let is_positional_name = |name: &str, prefix: &str| name.strip_prefix(prefix).is_some_and(|idx| !idx.is_empty() && idx.chars().all(|char| char.is_ascii_digit()));
+    while self.placeholder_names.iter().flatten().any(|name| is_positional_name(name, &prefix)) {
+      prefix.push('_');
+    }
+    prefix
+  }
+  // PlaceholderStyle::Colon 时按参数名绑定的值, 和 to_prepared_string 的参数名一致, 同名参数只出现一次
+  // eg: a = :p1 AND (owner = :uid OR creator = :uid) => [("p1", ..), ("uid", ..)]
+  pub fn named_prepare_values(&self) -> Result<Vec<(String, SqlValue)>, SqlError> {
+    let prepare_value = self.prepare_value.as_deref().unwrap_or_default();
+    if prepare_value.len() != self.placeholders.len() {
+      return Err(SqlError::ParameterCountMismatch { clause: "SQL", expected: self.placeholders.len(), actual: prepare_value.len(), sql: self.value.clone() });
+    }
+    let positional_prefix = self.positional_prefix();
+    let mut values: Vec<(String, SqlValue)> = vec![];
+    for (idx, (name, value)) in self.placeholder_names.iter().zip(prepare_value).enumerate() {
+      let name = name.clone().unwrap_or_else(|| format!("{}{}", positional_prefix, idx + 1));
+      match values.iter().find(|(other_name, _)| *other_name == name) {
+        Some((_, other_value)) if other_value == value => (),
+        // 不同片段使用了同名参数, eg: :status 分别为 'paid' 和 'void'
+        Some(_) => return Err(SqlError::ConflictingParameter { clause: "SQL", name, sql: self.value.clone() }),
+        None => values.push((name, value.clone())),
+      }
+    }
+    Ok(values)
+  }
+  // 交给驱动的预处理语句
+  pub fn to_prepared_string(&self, style: PlaceholderStyle) -> String {
+    let mut sql = self.clone();
+    sql.set_placeholder_style(style);
+    sql.value
+  }
+
   pub fn to_sql_string(&self) -> Result<String, SqlError> {
-    if let Some(prepare_value) = &self.prepare_value {
-        let mut replace_idx = 0;
-        let raw_sql = self.value.chars().map(|char|
-            match char {
-                '?' => {
-                    let use_replace_value = prepare_value.get(replace_idx).expect("参数不足");
-                    replace_idx += 1;
-                    Ok(use_replace_value.to_string())
-                },
-                _ => Ok(char.to_string())
-            }).collect::<Result<String, SqlError>>()?;
-        if replace_idx == prepare_value.len() {
-            Ok(raw_sql)
-        } else {
-            Err(SqlError::Message(format!("prepare sql params count not match: {}", raw_sql)))
-        }
-    } else {
-        Ok(self.value.clone())
+    let dialect = self.dialect.unwrap_or_default();
+    let prepare_value = self.prepare_value.as_deref().unwrap_or_default();
+    if prepare_value.len() != self.placeholders.len() {
+      return Err(SqlError::ParameterCountMismatch { clause: "SQL", expected: self.placeholders.len(), actual: prepare_value.len(), sql: self.value.clone() });
     }
+    let mut raw_sql = String::with_capacity(self.value.len());
+    let mut last = 0;
+    for (range, value) in self.placeholders.iter().zip(prepare_value) {
+      raw_sql.push_str(&self.value[last..range.start]);
+      raw_sql.push_str(&value.to_sql_literal(dialect)?);
+      last = range.end;
+    }
+    raw_sql.push_str(&self.value[last..]);
+    Ok(raw_sql)
   }
 
   pub fn is_empty(&self) -> bool {
@@ -105,4 +269,68 @@ impl TryFrom<Sql> for String {
   fn try_from(sql: Sql) -> Result<String, Self::Error> {
     sql.to_sql_string()
   }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn placeholder() {
+      let mut sql = Sql::new("SELECT * FROM users WHERE name = ".to_owned());
+      sql.push_placeholder(SqlValue::from("who?")).push_value(" AND note = 'why?'");
+      sql.push_value(" AND id IN (").push_placeholder(SqlValue::from(1)).push(',').push_placeholder(SqlValue::from(2)).push(')');
+      assert_eq!(&sql.value, "SELECT * FROM users WHERE name = ? AND note = 'why?' AND id IN (?,?)");
+      assert_eq!(sql.to_prepared_string(PlaceholderStyle::Dollar), "SELECT * FROM users WHERE name = $1 AND note = 'why?' AND id IN ($2,$3)");
+      assert_eq!(sql.to_prepared_string(PlaceholderStyle::Colon), "SELECT * FROM users WHERE name = :p1 AND note = 'why?' AND id IN (:p2,:p3)");
+      assert_eq!(sql.to_prepared_string(PlaceholderStyle::AtP), "SELECT * FROM users WHERE name = @p1 AND note = 'why?' AND id IN (@p2,@p3)");
+      assert_eq!(sql.to_sql_string().unwrap(), "SELECT * FROM users WHERE name = 'who?' AND note = 'why?' AND id IN (1,2)");
+
+      sql.set_placeholder_style(PlaceholderStyle::Dollar);
+      let mut parent = Sql::new("WITH t AS (".to_owned());
+      parent.push_placeholder(SqlValue::from(0)).push_value(") ").push_sql(&sql);
+      assert_eq!(&parent.value, "WITH t AS (?) SELECT * FROM users WHERE name = ? AND note = 'why?' AND id IN (?,?)");
+      parent.set_placeholder_style(PlaceholderStyle::Dollar);
+      assert_eq!(&parent.value, "WITH t AS ($1) SELECT * FROM users WHERE name = $2 AND note = 'why?' AND id IN ($3,$4)");
+      assert_eq!(parent.to_sql_string().unwrap(), "WITH t AS (0) SELECT * FROM users WHERE name = 'who?' AND note = 'why?' AND id IN (1,2)");
+    }
+    #[test]
+    fn push_raw() {
+      let mut sql = Sql::default();
+      sql.push_raw("data ?? 'k' AND note = 'it''s ?' AND \"a?\" = ? AND b = ?", vec![SqlValue::from("$1"), SqlValue::from(2)]).unwrap();
+      assert_eq!(&sql.value, "data ? 'k' AND note = 'it''s ?' AND \"a?\" = ? AND b = ?");
+      assert_eq!(sql.to_prepared_string(PlaceholderStyle::Dollar), "data ? 'k' AND note = 'it''s ?' AND \"a?\" = $1 AND b = $2");
+      assert_eq!(sql.to_sql_string().unwrap(), "data ? 'k' AND note = 'it''s ?' AND \"a?\" = '$1' AND b = 2");
+
+      assert_eq!(Sql::default().push_raw("a = ? AND b = ?", vec![SqlValue::from(1)]).unwrap_err(), SqlError::ParameterCountMismatch { clause: "SQL", expected: 2, actual: 1, sql: "a = ? AND b = ?".to_owned() });
+      assert_eq!(Sql::default().push_raw("a = ?", vec![SqlValue::from(1), SqlValue::from(2)]).unwrap_err().to_string(), "SQL: parameter count mismatch, expected 1 but got 2: a = ?");
+    }
+    #[test]
+    fn push_raw_named() {
+      let values = std::collections::HashMap::from([("since".to_owned(), SqlValue::from("2021-01-01")), ("uid".to_owned(), SqlValue::from(1))]);
+      let mut sql = Sql::default();
+      sql.push_raw_named("created_at > :since AND (owner = :uid OR creator = :uid) AND id::text <> ':uid' AND data ? 'k'", &values).unwrap();
+      assert_eq!(&sql.value, "created_at > ? AND (owner = ? OR creator = ?) AND id::text <> ':uid' AND data ? 'k'");
+      assert_eq!(sql.prepare_value, Some(vec![SqlValue::from("2021-01-01"), SqlValue::from(1), SqlValue::from(1)]));
+      assert_eq!(sql.to_prepared_string(PlaceholderStyle::Dollar), "created_at > $1 AND (owner = $2 OR creator = $3) AND id::text <> ':uid' AND data ? 'k'");
+      assert_eq!(sql.to_prepared_string(PlaceholderStyle::Colon), "created_at > :since AND (owner = :uid OR creator = :uid) AND id::text <> ':uid' AND data ? 'k'");
+      let mut parent = Sql::new("SELECT * FROM users WHERE id = ".to_owned());
+      parent.push_placeholder(SqlValue::from(2)).push_value(" AND ").push_sql(&sql);
+      assert_eq!(parent.to_prepared_string(PlaceholderStyle::Colon), "SELECT * FROM users WHERE id = :p1 AND created_at > :since AND (owner = :uid OR creator = :uid) AND id::text <> ':uid' AND data ? 'k'");
+
+      assert_eq!(parent.named_prepare_values().unwrap(), vec![("p1".to_owned(), SqlValue::from(2)), ("since".to_owned(), SqlValue::from("2021-01-01")), ("uid".to_owned(), SqlValue::from(1))]);
+
+      // 位置参数和命名参数 :p1 冲突时使用 :p_1
+      let mut sql = Sql::new("SELECT * FROM users WHERE id = ".to_owned());
+      sql.push_placeholder(SqlValue::from(2)).push_value(" AND ");
+      sql.push_raw_named("parent_id = :p1", &std::collections::HashMap::from([("p1".to_owned(), SqlValue::from(3))])).unwrap();
+      assert_eq!(sql.to_prepared_string(PlaceholderStyle::Colon), "SELECT * FROM users WHERE id = :p_1 AND parent_id = :p1");
+      assert_eq!(sql.named_prepare_values().unwrap(), vec![("p_1".to_owned(), SqlValue::from(2)), ("p1".to_owned(), SqlValue::from(3))]);
+      // 同名参数的值不同
+      sql.push_value(" AND ").push_raw_named("kind = :p1", &std::collections::HashMap::from([("p1".to_owned(), SqlValue::from(4))])).unwrap();
+      assert_eq!(sql.named_prepare_values().unwrap_err(), SqlError::ConflictingParameter { clause: "SQL", name: "p1".to_owned(), sql: sql.value.clone() });
+
+      let error = Sql::default().push_raw_named("owner = :uid AND created_at > :since AND a = :a", &values).unwrap_err();
+      assert_eq!(error.to_string(), "SQL: missing named parameter :a: owner = :uid AND created_at > :since AND a = :a");
+      let error = Sql::default().push_raw_named("owner = :uid", &values).unwrap_err();
+      assert_eq!(error, SqlError::UnusedParameter { clause: "SQL", names: vec!["since".to_owned()], sql: "owner = :uid".to_owned() });
+    }
+}
@@ -0,0 +1,188 @@
+use chrono::SubsecRound;
+use crate::error::SqlError;
+
+// 预处理语句的参数, 驱动可以按类型绑定
+#[derive(Clone, Debug, PartialEq)]
+pub enum SqlValue {
+  Null,
+  Bool(bool),
+  Int(i64),
+  Float(f64),
+  // 精确数值, eg: u64 超出 i64 范围, "12.50"
+  Decimal(String),
+  Text(String),
+  Bytes(Vec<u8>),
+  Date(chrono::NaiveDate),
+  Time(chrono::NaiveTime),
+  DateTime(chrono::NaiveDateTime),
+  Timestamp(chrono::DateTime<chrono::Utc>),
+  Json(serde_json::Value),
+}
+
+impl SqlValue {
+  // 直接拼接到 sql 中的字面量, eg: to_sql_string
+  pub fn to_sql_literal(&self, dialect: crate::Dialect) -> Result<String, SqlError> {
+    let string = match self {
+      SqlValue::Null => "null".to_owned(),
+      SqlValue::Bool(boolean) => {
+        match dialect {
+          crate::Dialect::Mysql | crate::Dialect::Sqlite | crate::Dialect::Mssql => format!("{}", if *boolean {1} else {0}),
+          // postgresql: boolean 不能和 integer 比较
+          crate::Dialect::Postgresql => format!("{}", boolean),
+        }
+      },
+      SqlValue::Int(int) => int.to_string(),
+      // 和 serde_json 的格式保持一致, eg: 1.0
+      SqlValue::Float(float) => {
+        match serde_json::Number::from_f64(*float) {
+          Some(number) => number.to_string(),
+          None => return Err(SqlError::UnsupportedValue { clause: "SQL", value: float.to_string() }),
+        }
+      },
+      SqlValue::Decimal(decimal) => {
+        if !regex::Regex::new(r"^[+-]?(\d+(\.\d*)?|\.\d+)([eE][+-]?\d+)?$").unwrap().is_match(decimal) {
+          return Err(SqlError::UnsupportedValue { clause: "SQL", value: decimal.to_owned() });
+        }
+        decimal.to_owned()
+      },
+      SqlValue::Text(text) => dialect.quote_string(text)?,
+      SqlValue::Bytes(bytes) => {
+        let hex = bytes.iter().map(|byte| format!("{:02x}", byte)).collect::<String>();
+        match dialect {
+          crate::Dialect::Mysql | crate::Dialect::Sqlite => format!("X'{}'", hex),
+          // postgresql: bytea hex format, eg: '\x01ff'
+          crate::Dialect::Postgresql => format!("'\\x{}'", hex),
+          crate::Dialect::Mssql => format!("0x{}", hex),
+        }
+      },
+      SqlValue::Date(date) => format!("'{}'", date.format("%Y-%m-%d")),
+      // 小数秒最多 6 位 (mysql, postgresql 的最大精度), %.f 输出 0, 3 或 6 位
+      SqlValue::Time(time) => format!("'{}'", time.trunc_subsecs(6).format("%H:%M:%S%.f")),
+      SqlValue::DateTime(datetime) => format!("'{}'", datetime.trunc_subsecs(6).format("%Y-%m-%d %H:%M:%S%.f")),
+      SqlValue::Timestamp(timestamp) => {
+        let timestamp = timestamp.trunc_subsecs(6);
+        match dialect {
+          // mysql, sqlite: 不带时区, 使用 UTC 时间
+          crate::Dialect::Mysql | crate::Dialect::Sqlite => format!("'{}'", timestamp.format("%Y-%m-%d %H:%M:%S%.f")),
+          crate::Dialect::Postgresql | crate::Dialect::Mssql => format!("'{}'", timestamp.format("%Y-%m-%d %H:%M:%S%.f%:z")),
+        }
+      },
+      SqlValue::Json(json) => dialect.quote_string(&json.to_string())?,
+    };
+    Ok(string)
+  }
+}
+
+// json 只能表示 Null, Bool, Int, Float, Text, 数组和对象作为 Json
+impl From<&serde_json::Value> for SqlValue {
+  fn from(value: &serde_json::Value) -> Self {
+    match value {
+      serde_json::Value::Null => SqlValue::Null,
+      serde_json::Value::Bool(boolean) => SqlValue::Bool(*boolean),
+      serde_json::Value::Number(number) => {
+        if let Some(int) = number.as_i64() {
+          SqlValue::Int(int)
+        } else if number.is_u64() {
+          SqlValue::Decimal(number.to_string())
+        } else {
+          SqlValue::Float(number.as_f64().unwrap_or(f64::NAN))
+        }
+      },
+      serde_json::Value::String(string) => SqlValue::Text(string.to_owned()),
+      serde_json::Value::Array(_) | serde_json::Value::Object(_) => SqlValue::Json(value.clone()),
+    }
+  }
+}
+impl From<serde_json::Value> for SqlValue {
+  fn from(value: serde_json::Value) -> Self { SqlValue::from(&value) }
+}
+
+macro_rules! impl_from_for_sql_value {
+  ($($type:ty => $variant:ident),* $(,)?) => {
+    $(
+      impl From<$type> for SqlValue {
+        fn from(value: $type) -> Self { SqlValue::$variant(value.into()) }
+      }
+    )*
+  };
+}
+impl_from_for_sql_value!(
+  bool => Bool,
+  i8 => Int, i16 => Int, i32 => Int, i64 => Int, u8 => Int, u16 => Int, u32 => Int,
+  f32 => Float, f64 => Float,
+  String => Text, &str => Text,
+  Vec<u8> => Bytes, &[u8] => Bytes,
+  chrono::NaiveDate => Date,
+  chrono::NaiveTime => Time,
+  chrono::NaiveDateTime => DateTime,
+  chrono::DateTime<chrono::Utc> => Timestamp,
+);
+impl From<u64> for SqlValue {
+  fn from(value: u64) -> Self {
+    i64::try_from(value).map(SqlValue::Int).unwrap_or_else(|_| SqlValue::Decimal(value.to_string()))
+  }
+}
+impl<T: Into<SqlValue>> From<Option<T>> for SqlValue {
+  fn from(value: Option<T>) -> Self {
+    value.map(Into::into).unwrap_or(SqlValue::Null)
+  }
+}
+
+// #[derive(Manageable)] 使用: 实现 Into<SqlValue> 的类型按类型转换 (eg: Vec<u8>, chrono), 其它类型通过 serde 转为 json
+// eg: (&AttrValue(&self.avatar)).to_sql_value()
+#[doc(hidden)]
+pub struct AttrValue<'a, T>(pub &'a T);
+#[doc(hidden)]
+pub trait ToSqlValue {
+  fn to_sql_value(&self) -> Option<SqlValue>;
+}
+impl<T: Clone + Into<SqlValue>> ToSqlValue for AttrValue<'_, T> {
+  fn to_sql_value(&self) -> Option<SqlValue> { Some(self.0.clone().into()) }
+}
+#[doc(hidden)]
+pub trait SerializeToSqlValue {
+  fn to_sql_value(&self) -> Option<SqlValue>;
+}
+impl<T: serde::Serialize> SerializeToSqlValue for &AttrValue<'_, T> {
+  fn to_sql_value(&self) -> Option<SqlValue> { serde_json::to_value(self.0).ok().map(SqlValue::from) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    #[test]
+    fn to_sql_literal() {
+      let bytes = SqlValue::from(vec![0x01u8, 0xff, 0x27]);
+      assert_eq!(bytes.to_sql_literal(crate::Dialect::Mysql).unwrap(), "X'01ff27'");
+      assert_eq!(bytes.to_sql_literal(crate::Dialect::Postgresql).unwrap(), "'\\x01ff27'");
+      assert_eq!(bytes.to_sql_literal(crate::Dialect::Sqlite).unwrap(), "X'01ff27'");
+      assert_eq!(bytes.to_sql_literal(crate::Dialect::Mssql).unwrap(), "0x01ff27");
+
+      let date = SqlValue::from(chrono::NaiveDate::from_ymd_opt(2021, 12, 31).unwrap());
+      assert_eq!(date.to_sql_literal(crate::Dialect::Mysql).unwrap(), "'2021-12-31'");
+      let time = SqlValue::from(chrono::NaiveTime::from_hms_milli_opt(23, 59, 59, 500).unwrap());
+      assert_eq!(time.to_sql_literal(crate::Dialect::Mysql).unwrap(), "'23:59:59.500'");
+      let timestamp = SqlValue::from(chrono::Utc.with_ymd_and_hms(2021, 12, 31, 23, 59, 59).unwrap());
+      assert_eq!(timestamp.to_sql_literal(crate::Dialect::Mysql).unwrap(), "'2021-12-31 23:59:59'");
+      assert_eq!(timestamp.to_sql_literal(crate::Dialect::Postgresql).unwrap(), "'2021-12-31 23:59:59+00:00'");
+      assert_eq!(timestamp.to_sql_literal(crate::Dialect::Mssql).unwrap(), "'2021-12-31 23:59:59+00:00'");
+      // 纳秒截断为微秒
+      let time = SqlValue::from(chrono::NaiveTime::from_hms_nano_opt(23, 59, 59, 123_456_789).unwrap());
+      assert_eq!(time.to_sql_literal(crate::Dialect::Mysql).unwrap(), "'23:59:59.123456'");
+      let datetime = SqlValue::from(chrono::NaiveDate::from_ymd_opt(2021, 12, 31).unwrap().and_hms_nano_opt(23, 59, 59, 1).unwrap());
+      assert_eq!(datetime.to_sql_literal(crate::Dialect::Sqlite).unwrap(), "'2021-12-31 23:59:59'");
+      let timestamp = SqlValue::from(chrono::Utc.with_ymd_and_hms(2021, 12, 31, 23, 59, 59).unwrap() + chrono::Duration::nanoseconds(123_456_789));
+      assert_eq!(timestamp.to_sql_literal(crate::Dialect::Postgresql).unwrap(), "'2021-12-31 23:59:59.123456+00:00'");
+
+      assert_eq!(SqlValue::from(true).to_sql_literal(crate::Dialect::Postgresql).unwrap(), "true");
+      assert_eq!(SqlValue::from(true).to_sql_literal(crate::Dialect::Sqlite).unwrap(), "1");
+      assert_eq!(SqlValue::from(u64::MAX), SqlValue::Decimal("18446744073709551615".to_owned()));
+      assert_eq!(SqlValue::from(None::<i32>), SqlValue::Null);
+      assert_eq!(SqlValue::from(serde_json::json!({"a": "b'c"})).to_sql_literal(crate::Dialect::Mysql).unwrap(), "'{\"a\":\"b''c\"}'");
+
+      assert!(SqlValue::Float(f64::NAN).to_sql_literal(crate::Dialect::Mysql).is_err());
+      assert!(SqlValue::Decimal("1; DROP TABLE users".to_owned()).to_sql_literal(crate::Dialect::Mysql).is_err());
+      assert_eq!(SqlValue::Decimal("-12.50".to_owned()).to_sql_literal(crate::Dialect::Mysql).unwrap(), "-12.50");
+    }
+}
@@ -1,6 +1,6 @@
-
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
+use crate::dialect::Dialect;
 
 
 pub struct DbKeyMapping {
@@ -8,26 +8,88 @@ pub struct DbKeyMapping {
 }
 impl Default for DbKeyMapping {
   fn default() -> Self {
-    let mut m = HashMap::new();
-    m.insert("auto_increment", "AUTO_INCREMENT");
-    m.insert("boolean", "BOOLEAN");
-    m.insert("integer", "INT");
-    m.insert("float", "FLOAT");
-    m.insert("double", "DOUBLE");
-    m.insert("decimal", "DECIMAL");
-    m.insert("string", "VARCHAR");
-    m.insert("text", "TEXT");
-    m.insert("time", "TIME");
-    m.insert("date", "DATE");
-    m.insert("datetime", "DATETIME");
-    m.insert("timestamp", "TIMESTAMP");
-    m.insert("json", "JSON");
-    m.insert("blob", "BLOB");
-    m.insert("binary", "VARBINARY");
-    Self { mapping: m }
+    Self::new(Dialect::default())
   }
 }
 impl DbKeyMapping {
+  pub fn new(dialect: Dialect) -> Self {
+    let mut m = HashMap::new();
+    match dialect {
+      Dialect::Mysql => {
+        m.insert("auto_increment", "AUTO_INCREMENT");
+        m.insert("boolean", "BOOLEAN");
+        m.insert("integer", "INT");
+        m.insert("float", "FLOAT");
+        m.insert("double", "DOUBLE");
+        m.insert("decimal", "DECIMAL");
+        m.insert("string", "VARCHAR");
+        m.insert("text", "TEXT");
+        m.insert("time", "TIME");
+        m.insert("date", "DATE");
+        m.insert("datetime", "DATETIME");
+        m.insert("timestamp", "TIMESTAMP");
+        m.insert("json", "JSON");
+        m.insert("blob", "BLOB");
+        m.insert("binary", "VARBINARY");
+      },
+      // postgresql: https://www.postgresql.org/docs/current/datatype.html
+      Dialect::Postgresql => {
+        m.insert("auto_increment", "GENERATED BY DEFAULT AS IDENTITY");
+        m.insert("boolean", "BOOLEAN");
+        m.insert("integer", "INTEGER");
+        m.insert("float", "REAL");
+        m.insert("double", "DOUBLE PRECISION");
+        m.insert("decimal", "NUMERIC");
+        m.insert("string", "VARCHAR");
+        m.insert("text", "TEXT");
+        m.insert("time", "TIME");
+        m.insert("date", "DATE");
+        m.insert("datetime", "TIMESTAMP");
+        m.insert("timestamp", "TIMESTAMPTZ");
+        m.insert("json", "JSONB");
+        m.insert("blob", "BYTEA");
+        m.insert("binary", "BYTEA");
+      },
+      // sqlite: https://www.sqlite.org/datatype3.html#type_affinity
+      Dialect::Sqlite => {
+        // sqlite: AUTOINCREMENT 只能用于 INTEGER PRIMARY KEY
+        m.insert("auto_increment", "PRIMARY KEY AUTOINCREMENT");
+        m.insert("boolean", "INTEGER");
+        m.insert("integer", "INTEGER");
+        m.insert("float", "REAL");
+        m.insert("double", "REAL");
+        m.insert("decimal", "NUMERIC");
+        m.insert("string", "TEXT");
+        m.insert("text", "TEXT");
+        m.insert("time", "TEXT");
+        m.insert("date", "TEXT");
+        m.insert("datetime", "TEXT");
+        m.insert("timestamp", "TEXT");
+        m.insert("json", "TEXT");
+        m.insert("blob", "BLOB");
+        m.insert("binary", "BLOB");
+      },
+      // mssql: https://learn.microsoft.com/en-us/sql/t-sql/data-types/data-types-transact-sql
+      Dialect::Mssql => {
+        m.insert("auto_increment", "IDENTITY(1,1)");
+        m.insert("boolean", "BIT");
+        m.insert("integer", "INT");
+        m.insert("float", "REAL");
+        m.insert("double", "FLOAT");
+        m.insert("decimal", "DECIMAL");
+        m.insert("string", "NVARCHAR");
+        m.insert("text", "NVARCHAR(MAX)");
+        m.insert("time", "TIME");
+        m.insert("date", "DATE");
+        m.insert("datetime", "DATETIME2");
+        m.insert("timestamp", "DATETIMEOFFSET");
+        m.insert("json", "NVARCHAR(MAX)");
+        m.insert("blob", "VARBINARY(MAX)");
+        m.insert("binary", "VARBINARY");
+      },
+    }
+    Self { mapping: m }
+  }
   pub fn get(&self, key: &str) -> Option<&str> {
     match self.mapping.get(key) {
       Some(v) => Some(*v),
@@ -36,4 +98,16 @@ impl DbKeyMapping {
   }
 }
 
-pub static GLOBAL_DB_KEY_MAPPING: Lazy<DbKeyMapping> = Lazy::new(|| DbKeyMapping::default());
\ No newline at end of file
+static MYSQL_DB_KEY_MAPPING: Lazy<DbKeyMapping> = Lazy::new(|| DbKeyMapping::new(Dialect::Mysql));
+static POSTGRESQL_DB_KEY_MAPPING: Lazy<DbKeyMapping> = Lazy::new(|| DbKeyMapping::new(Dialect::Postgresql));
+static SQLITE_DB_KEY_MAPPING: Lazy<DbKeyMapping> = Lazy::new(|| DbKeyMapping::new(Dialect::Sqlite));
+static MSSQL_DB_KEY_MAPPING: Lazy<DbKeyMapping> = Lazy::new(|| DbKeyMapping::new(Dialect::Mssql));
+
+pub fn db_key_mapping(dialect: Dialect) -> &'static DbKeyMapping {
+  match dialect {
+    Dialect::Mysql => &MYSQL_DB_KEY_MAPPING,
+    Dialect::Postgresql => &POSTGRESQL_DB_KEY_MAPPING,
+    Dialect::Sqlite => &SQLITE_DB_KEY_MAPPING,
+    Dialect::Mssql => &MSSQL_DB_KEY_MAPPING,
+  }
+}
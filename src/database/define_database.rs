@@ -1,5 +1,6 @@
 use crate::collectors::Sql;
 
+#[allow(clippy::enum_variant_names)]
 enum ActionDatabase {
   CreateDatabase,
   AlterDatabase,
@@ -34,37 +35,68 @@ impl Default for DefineDatabase {
 impl DefineDatabase {
   // mysql: https://dev.mysql.com/doc/refman/8.0/en/create-database.html
   pub fn create_database(database_name: &str) -> Self {
-    let mut database = DefineDatabase::default();
-    database.action = Some(ActionDatabase::CreateDatabase);
-    database.database_name = database_name.to_owned();
-    database.charset = Some("utf8mb4".to_owned());
-    database.collation = Some("utf8mb4_0900_ai_ci".to_owned());
-    database
+    DefineDatabase {
+      action: Some(ActionDatabase::CreateDatabase),
+      database_name: database_name.to_owned(),
+      ..DefineDatabase::default()
+    }
   }
   // mysql: https://dev.mysql.com/doc/refman/8.0/en/alter-database.html
-  pub fn alter_database(database_name: &str, callback: impl Fn(&mut Self) -> ()) -> Self {
-    let mut database = DefineDatabase::default();
-    database.action = Some(ActionDatabase::AlterDatabase);
-    database.database_name = database_name.to_owned();
+  pub fn alter_database(database_name: &str, callback: impl Fn(&mut Self)) -> Self {
+    let mut database = DefineDatabase {
+      action: Some(ActionDatabase::AlterDatabase),
+      database_name: database_name.to_owned(),
+      ..DefineDatabase::default()
+    };
     callback(&mut database);
     database
   }
   // mysql: https://dev.mysql.com/doc/refman/8.0/en/drop-database.html
   pub fn drop_database(database_name: &str) -> Self {
-    let mut database = DefineDatabase::default();
-    database.action = Some(ActionDatabase::DropDatabase);
-    database.database_name = database_name.to_owned();
-    database
+    DefineDatabase {
+      action: Some(ActionDatabase::DropDatabase),
+      database_name: database_name.to_owned(),
+      ..DefineDatabase::default()
+    }
+  }
+
+  // 数据库名除 mssql 外不加引号, 只允许字母, 数字, _ 和 $
+  pub fn try_to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::SqlError> {
+    dialect.validate_identifier(&self.database_name)?;
+    if dialect != crate::Dialect::Mssql && !self.database_name.chars().all(|char| char.is_alphanumeric() || char == '_' || char == '$') {
+      return Err(crate::SqlError::InvalidIdentifier { identifier: self.database_name.to_owned(), reason: "invalid character" });
+    }
+    Ok(self.to_sql(dialect))
+  }
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Sql {
+    match dialect {
+      crate::Dialect::Mysql => self.to_mysql_sql(),
+      crate::Dialect::Postgresql => self.to_postgresql_sql(),
+      crate::Dialect::Sqlite => self.to_sqlite_sql(),
+      crate::Dialect::Mssql => self.to_mssql_sql(),
+    }
+  }
+  // 创建数据库时没有指定的字符集和排序规则
+  // sqlite: 数据库即文件, 编码只能在创建前通过 PRAGMA encoding 设置
+  // mssql: 字符集由排序规则决定, 默认使用服务器的排序规则
+  fn default_charset_collation(&self, dialect: crate::Dialect) -> (Option<&str>, Option<&str>) {
+    let (default_charset, default_collation) = match dialect {
+      crate::Dialect::Mysql => (Some("utf8mb4"), Some("utf8mb4_0900_ai_ci")),
+      crate::Dialect::Postgresql => (Some("UTF8"), None),
+      crate::Dialect::Sqlite | crate::Dialect::Mssql => (None, None),
+    };
+    (self.charset.as_deref().or(default_charset), self.collation.as_deref().or(default_collation))
   }
 
-  pub fn to_sql(&self) -> Sql {
+  fn to_mysql_sql(&self) -> Sql {
     match &self.action {
       Some(ActionDatabase::CreateDatabase) => {
         let mut sql = Sql::new(format!("CREATE DATABASE IF NOT EXISTS {}", self.database_name));
-        if let Some(charset) = &self.charset {
+        let (charset, collation) = self.default_charset_collation(crate::Dialect::Mysql);
+        if let Some(charset) = charset {
           sql.push_value(&format!(" CHARACTER SET {}", charset));
         }
-        if let Some(collation) = &self.collation {
+        if let Some(collation) = collation {
           sql.push_value(&format!(" COLLATE {}", collation));
         }
         if let Some(encryption) = &self.encryption {
@@ -96,12 +128,101 @@ impl DefineDatabase {
       None => Sql::default()
     }
   }
+
+  // postgresql: https://www.postgresql.org/docs/current/sql-createdatabase.html
+  fn to_postgresql_sql(&self) -> Sql {
+    match &self.action {
+      Some(ActionDatabase::CreateDatabase) => {
+        // postgresql: 不支持 IF NOT EXISTS
+        let mut sql = Sql::new(format!("CREATE DATABASE {}", self.database_name));
+        let (charset, collation) = self.default_charset_collation(crate::Dialect::Postgresql);
+        if let Some(charset) = charset {
+          sql.push_value(&format!(" ENCODING '{}'", charset));
+        }
+        if let Some(collation) = collation {
+          sql.push_value(&format!(" LC_COLLATE '{}'", collation));
+        }
+        sql.push(';');
+        sql
+      },
+      // postgresql: 创建后不能修改 ENCODING 和 LC_COLLATE
+      Some(ActionDatabase::AlterDatabase) => {
+        let mut sql = Sql::new(format!("ALTER DATABASE {}", self.database_name));
+        if let Some(read_only) = &self.read_only {
+          sql.push_value(&format!(" SET default_transaction_read_only = {}", if *read_only > 0 { "true" } else { "false" }));
+        }
+        sql.push(';');
+        sql
+      },
+      Some(ActionDatabase::DropDatabase) => {
+        Sql::new(format!("DROP DATABASE IF EXISTS {};", self.database_name))
+      },
+      None => Sql::default()
+    }
+  }
+
+  // sqlite: https://www.sqlite.org/lang_attach.html
+  fn to_sqlite_sql(&self) -> Sql {
+    match &self.action {
+      // sqlite: 附加数据库文件, 文件不存在时创建
+      Some(ActionDatabase::CreateDatabase) => {
+        Sql::new(format!("ATTACH DATABASE '{}.db' AS {};", self.database_name, self.database_name))
+      },
+      Some(ActionDatabase::AlterDatabase) => {
+        let mut sqls = vec![];
+        if let Some(charset) = &self.charset {
+          sqls.push(format!("PRAGMA {}.encoding = '{}';", self.database_name, charset));
+        }
+        // sqlite: query_only 作用于当前连接
+        if let Some(read_only) = &self.read_only {
+          sqls.push(format!("PRAGMA query_only = {};", if *read_only > 0 { "true" } else { "false" }));
+        }
+        Sql::new(sqls.join("\n"))
+      },
+      // sqlite: 不能通过 SQL 删除数据库文件, 只能分离
+      Some(ActionDatabase::DropDatabase) => {
+        Sql::new(format!("DETACH DATABASE {};", self.database_name))
+      },
+      None => Sql::default()
+    }
+  }
+
+  // mssql: https://learn.microsoft.com/en-us/sql/t-sql/statements/create-database-transact-sql
+  fn to_mssql_sql(&self) -> Sql {
+    let database_name = crate::Dialect::Mssql.quote_identifier(&self.database_name);
+    match &self.action {
+      // mssql: 没有 CREATE DATABASE IF NOT EXISTS
+      Some(ActionDatabase::CreateDatabase) => {
+        let mut sql = Sql::new(format!("IF DB_ID(N'{}') IS NULL CREATE DATABASE {}", self.database_name, database_name));
+        if let Some(collation) = &self.collation {
+          sql.push_value(&format!(" COLLATE {}", collation));
+        }
+        sql.push(';');
+        sql
+      },
+      // mssql: COLLATE 和 SET 需要分别执行
+      Some(ActionDatabase::AlterDatabase) => {
+        let mut sqls = vec![];
+        if let Some(collation) = &self.collation {
+          sqls.push(format!("ALTER DATABASE {} COLLATE {};", database_name, collation));
+        }
+        if let Some(read_only) = &self.read_only {
+          sqls.push(format!("ALTER DATABASE {} SET {};", database_name, if *read_only > 0 { "READ_ONLY" } else { "READ_WRITE" }));
+        }
+        Sql::new(sqls.join("\n"))
+      },
+      Some(ActionDatabase::DropDatabase) => {
+        Sql::new(format!("DROP DATABASE IF EXISTS {};", database_name))
+      },
+      None => Sql::default()
+    }
+  }
 }
 
 impl TryFrom<DefineDatabase> for String {
   type Error = crate::SqlError;
   fn try_from(database_manager: DefineDatabase) -> Result<String, Self::Error> {
-    database_manager.to_sql().to_sql_string()
+    database_manager.to_sql(crate::Dialect::default()).to_sql_string()
   }
 }
 
@@ -112,9 +233,31 @@ mod tests {
   #[test]
   fn to_sql() {
     let db = DefineDatabase::create_database("sql_gen_prod");
-    #[cfg(feature = "mysql")]
+    // mysql
     {
-      assert_eq!(db.to_sql().to_sql_string(), Ok("CREATE DATABASE IF NOT EXISTS sql_gen_prod CHARACTER SET utf8mb4 COLLATE utf8mb4_0900_ai_ci;".to_owned()));
+      assert_eq!(db.to_sql(crate::Dialect::Mysql).to_sql_string(), Ok("CREATE DATABASE IF NOT EXISTS sql_gen_prod CHARACTER SET utf8mb4 COLLATE utf8mb4_0900_ai_ci;".to_owned()));
+    }
+    // postgresql
+    {
+      assert_eq!(db.to_sql(crate::Dialect::Postgresql).to_sql_string(), Ok("CREATE DATABASE sql_gen_prod ENCODING 'UTF8';".to_owned()));
+    }
+    // sqlite
+    {
+      assert_eq!(db.to_sql(crate::Dialect::Sqlite).to_sql_string(), Ok("ATTACH DATABASE 'sql_gen_prod.db' AS sql_gen_prod;".to_owned()));
+    }
+    // mssql
+    {
+      assert_eq!(db.to_sql(crate::Dialect::Mssql).to_sql_string(), Ok("IF DB_ID(N'sql_gen_prod') IS NULL CREATE DATABASE [sql_gen_prod];".to_owned()));
     }
   }
-}
\ No newline at end of file
+  #[test]
+  fn try_to_sql() {
+    let db = DefineDatabase::create_database("sql_gen; DROP DATABASE prod");
+    assert_eq!(db.try_to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::InvalidIdentifier { identifier: "sql_gen; DROP DATABASE prod".to_owned(), reason: "invalid character" });
+    assert!(db.try_to_sql(crate::Dialect::Mssql).is_ok());
+    let db = DefineDatabase::create_database("");
+    assert_eq!(db.try_to_sql(crate::Dialect::Mssql).unwrap_err().to_string(), "invalid identifier \"\": empty name");
+    let db = DefineDatabase::create_database("sql_gen_prod");
+    assert_eq!(db.try_to_sql(crate::Dialect::Postgresql).unwrap().to_sql_string(), Ok("CREATE DATABASE sql_gen_prod ENCODING 'UTF8';".to_owned()));
+  }
+}
@@ -0,0 +1,176 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Dialect {
+  #[default]
+  Mysql,
+  Postgresql,
+  Sqlite,
+  Mssql,
+}
+
+// 没有指定数据库时(eg: try_into)使用 mysql, 其它数据库通过 to_sql(dialect) 指定
+
+impl Dialect {
+  // 预处理语句的占位符
+  pub fn placeholder_style(&self) -> crate::collectors::PlaceholderStyle {
+    match self {
+      Dialect::Mysql | Dialect::Sqlite => crate::collectors::PlaceholderStyle::Question,
+      // postgresql: $1, $2 ...
+      Dialect::Postgresql => crate::collectors::PlaceholderStyle::Dollar,
+      // mssql: @p1, @p2 ...
+      Dialect::Mssql => crate::collectors::PlaceholderStyle::AtP,
+    }
+  }
+  // 字符串字面量, eg: O'Brien => 'O''Brien'
+  pub fn quote_string(&self, value: &str) -> Result<String, crate::SqlError> {
+    match self {
+      // mysql: 默认 sql_mode 下反斜杠是转义符
+      Dialect::Mysql => {
+        let mut string = String::with_capacity(value.len() + 2);
+        string.push('\'');
+        for char in value.chars() {
+          match char {
+            '\'' => string.push_str("''"),
+            '\\' => string.push_str("\\\\"),
+            '\0' => string.push_str("\\0"),
+            '\n' => string.push_str("\\n"),
+            '\r' => string.push_str("\\r"),
+            '\x1a' => string.push_str("\\Z"),
+            _ => string.push(char),
+          }
+        }
+        string.push('\'');
+        Ok(string)
+      },
+      // postgresql, sqlite, mssql: 字符串中不能包含 NUL
+      _ if value.contains('\0') => {
+        Err(crate::SqlError::DialectUnsupported { dialect: *self, feature: format!("NUL character in string literal {:?}", value) })
+      },
+      // postgresql: 反斜杠使用 E'' 转义, 不受 standard_conforming_strings 影响
+      Dialect::Postgresql if value.contains('\\') => {
+        Ok(format!("E'{}'", value.replace('\\', "\\\\").replace('\'', "''")))
+      },
+      // mssql: 非 ascii 字符需要 N'' 否则会按数据库的代码页转换
+      Dialect::Mssql if !value.is_ascii() => Ok(format!("N'{}'", value.replace('\'', "''"))),
+      _ => Ok(format!("'{}'", value.replace('\'', "''"))),
+    }
+  }
+  // mysql: `name`, postgresql/sqlite: "name", mssql: [name]
+  // schema.table 分别引用: "schema"."table", * 不引用: "users".*
+  pub fn quote_identifier(&self, identifier: &str) -> String {
+    identifier.split('.').map(|part| {
+      match self {
+        _ if part == "*" => part.to_owned(),
+        Dialect::Mysql => format!("`{}`", part.replace('`', "``")),
+        Dialect::Postgresql | Dialect::Sqlite => format!("\"{}\"", part.replace('"', "\"\"")),
+        Dialect::Mssql => format!("[{}]", part.replace(']', "]]")),
+      }
+    }).collect::<Vec<String>>().join(".")
+  }
+  pub fn quote_identifiers<S: AsRef<str>>(&self, identifiers: &[S]) -> Vec<String> {
+    identifiers.iter().map(|identifier| self.quote_identifier(identifier.as_ref())).collect()
+  }
+  // 来自用户输入的标识符(eg: json 的 key)需要先校验
+  pub fn try_quote_identifier(&self, identifier: &str) -> Result<String, crate::SqlError> {
+    self.validate_identifier(identifier)?;
+    Ok(self.quote_identifier(identifier))
+  }
+  pub fn validate_identifier(&self, identifier: &str) -> Result<(), crate::SqlError> {
+    // mysql: 64 个字符, postgresql: 63 个字节 (超出会被截断), mssql: 128 个字符
+    let too_long = |part: &str| {
+      match self {
+        Dialect::Mysql => part.chars().count() > 64,
+        Dialect::Postgresql => part.len() > 63,
+        Dialect::Sqlite => false,
+        Dialect::Mssql => part.chars().count() > 128,
+      }
+    };
+    for part in identifier.split('.') {
+      let reason = if part.is_empty() {
+        "empty name"
+      } else if part.trim() != part {
+        "leading or trailing whitespace"
+      } else if part.chars().any(|char| char.is_control()) {
+        "control character"
+      } else if too_long(part) {
+        "name too long"
+      } else {
+        continue;
+      };
+      return Err(crate::SqlError::InvalidIdentifier { identifier: identifier.to_owned(), reason });
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  // (value, mysql, postgresql, sqlite, mssql), None: 不支持
+  type QuoteStringCase<'a> = (&'a str, Option<&'a str>, Option<&'a str>, Option<&'a str>, Option<&'a str>);
+  #[test]
+  fn quote_string() {
+    let cases: Vec<QuoteStringCase> = vec![
+      ("", Some("''"), Some("''"), Some("''"), Some("''")),
+      ("O'Brien", Some("'O''Brien'"), Some("'O''Brien'"), Some("'O''Brien'"), Some("'O''Brien'")),
+      ("'", Some("''''"), Some("''''"), Some("''''"), Some("''''")),
+      ("''", Some("''''''"), Some("''''''"), Some("''''''"), Some("''''''")),
+      ("'; DROP TABLE users; --", Some("'''; DROP TABLE users; --'"), Some("'''; DROP TABLE users; --'"), Some("'''; DROP TABLE users; --'"), Some("'''; DROP TABLE users; --'")),
+      ("' OR '1'='1", Some("''' OR ''1''=''1'"), Some("''' OR ''1''=''1'"), Some("''' OR ''1''=''1'"), Some("''' OR ''1''=''1'")),
+      (r"\", Some(r"'\\'"), Some(r"E'\\'"), Some(r"'\'"), Some(r"'\'")),
+      (r"\'; DROP TABLE users; --", Some(r"'\\''; DROP TABLE users; --'"), Some(r"E'\\''; DROP TABLE users; --'"), Some(r"'\''; DROP TABLE users; --'"), Some(r"'\''; DROP TABLE users; --'")),
+      (r"\\'", Some(r"'\\\\'''"), Some(r"E'\\\\'''"), Some(r"'\\'''"), Some(r"'\\'''")),
+      (r"C:\temp\new", Some(r"'C:\\temp\\new'"), Some(r"E'C:\\temp\\new'"), Some(r"'C:\temp\new'"), Some(r"'C:\temp\new'")),
+      ("a\0b", Some(r"'a\0b'"), None, None, None),
+      ("\0", Some(r"'\0'"), None, None, None),
+      ("line1\nline2\r\n", Some(r"'line1\nline2\r\n'"), Some("'line1\nline2\r\n'"), Some("'line1\nline2\r\n'"), Some("'line1\nline2\r\n'")),
+      ("\t", Some("'\t'"), Some("'\t'"), Some("'\t'"), Some("'\t'")),
+      ("\x1a", Some(r"'\Z'"), Some("'\x1a'"), Some("'\x1a'"), Some("'\x1a'")),
+      ("\x08\x7f", Some("'\x08\x7f'"), Some("'\x08\x7f'"), Some("'\x08\x7f'"), Some("'\x08\x7f'")),
+      ("\"double\" `backtick` [bracket]", Some("'\"double\" `backtick` [bracket]'"), Some("'\"double\" `backtick` [bracket]'"), Some("'\"double\" `backtick` [bracket]'"), Some("'\"double\" `backtick` [bracket]'")),
+      ("? $1 @p1 :name", Some("'? $1 @p1 :name'"), Some("'? $1 @p1 :name'"), Some("'? $1 @p1 :name'"), Some("'? $1 @p1 :name'")),
+      ("/* comment */ -- comment; #", Some("'/* comment */ -- comment; #'"), Some("'/* comment */ -- comment; #'"), Some("'/* comment */ -- comment; #'"), Some("'/* comment */ -- comment; #'")),
+      ("$$ $tag$", Some("'$$ $tag$'"), Some("'$$ $tag$'"), Some("'$$ $tag$'"), Some("'$$ $tag$'")),
+      ("é 你好 😀", Some("'é 你好 😀'"), Some("'é 你好 😀'"), Some("'é 你好 😀'"), Some("N'é 你好 😀'")),
+      ("\u{2019}' \u{02bc}", Some("'\u{2019}'' \u{02bc}'"), Some("'\u{2019}'' \u{02bc}'"), Some("'\u{2019}'' \u{02bc}'"), Some("N'\u{2019}'' \u{02bc}'")),
+    ];
+    for (value, mysql, postgresql, sqlite, mssql) in cases {
+      assert_eq!(Dialect::Mysql.quote_string(value).ok().as_deref(), mysql, "mysql: {:?}", value);
+      assert_eq!(Dialect::Postgresql.quote_string(value).ok().as_deref(), postgresql, "postgresql: {:?}", value);
+      assert_eq!(Dialect::Sqlite.quote_string(value).ok().as_deref(), sqlite, "sqlite: {:?}", value);
+      assert_eq!(Dialect::Mssql.quote_string(value).ok().as_deref(), mssql, "mssql: {:?}", value);
+    }
+  }
+  #[test]
+  fn quote_identifier() {
+    assert_eq!(Dialect::Mysql.quote_identifier("order"), "`order`");
+    assert_eq!(Dialect::Postgresql.quote_identifier("order"), "\"order\"");
+    assert_eq!(Dialect::Sqlite.quote_identifier("order"), "\"order\"");
+    assert_eq!(Dialect::Mssql.quote_identifier("order"), "[order]");
+    // schema.table
+    assert_eq!(Dialect::Mysql.quote_identifier("sql_gen.users"), "`sql_gen`.`users`");
+    assert_eq!(Dialect::Postgresql.quote_identifier("public.users"), "\"public\".\"users\"");
+    assert_eq!(Dialect::Mssql.quote_identifier("dbo.users"), "[dbo].[users]");
+    assert_eq!(Dialect::Postgresql.quote_identifier("users.*"), "\"users\".*");
+    // 引号需要转义
+    assert_eq!(Dialect::Mysql.quote_identifier("a`b"), "`a``b`");
+    assert_eq!(Dialect::Postgresql.quote_identifier("a\"b"), "\"a\"\"b\"");
+    assert_eq!(Dialect::Sqlite.quote_identifier("a\"; DROP TABLE users; --"), "\"a\"\"; DROP TABLE users; --\"");
+    assert_eq!(Dialect::Mssql.quote_identifier("a]b"), "[a]]b]");
+
+    assert_eq!(Dialect::Mysql.try_quote_identifier("key"), Ok("`key`".to_owned()));
+    assert_eq!(Dialect::Mysql.try_quote_identifier("first name"), Ok("`first name`".to_owned()));
+    assert_eq!(Dialect::Mssql.try_quote_identifier("名字"), Ok("[名字]".to_owned()));
+    for identifier in ["", ".", "users.", ".users", "a..b", " a", "a ", "a\0b", "a\nb", "a\tb"] {
+      for dialect in [Dialect::Mysql, Dialect::Postgresql, Dialect::Sqlite, Dialect::Mssql] {
+        assert!(dialect.try_quote_identifier(identifier).is_err(), "{:?} {:?}", dialect, identifier);
+      }
+    }
+    assert!(Dialect::Mysql.validate_identifier(&"a".repeat(64)).is_ok());
+    assert!(Dialect::Mysql.validate_identifier(&"a".repeat(65)).is_err());
+    assert!(Dialect::Mysql.validate_identifier(&"名".repeat(64)).is_ok());
+    assert!(Dialect::Postgresql.validate_identifier(&"a".repeat(63)).is_ok());
+    assert!(Dialect::Postgresql.validate_identifier(&"名".repeat(63)).is_err());
+    assert!(Dialect::Sqlite.validate_identifier(&"a".repeat(1000)).is_ok());
+    assert!(Dialect::Mssql.validate_identifier(&"a".repeat(129)).is_err());
+  }
+}
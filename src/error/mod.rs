@@ -1,9 +1,65 @@
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SqlError {
-  #[error("generate sql error info")]
-  Message(String),
-  #[error("unknown data store error")]
-  Unknown,
-}
\ No newline at end of file
+  // eg: DELETE, UPDATE 没有 where 条件
+  #[error("{statement}: WHERE condition is required")]
+  MissingWhere { statement: &'static str },
+  // eg: INSERT 没有插入的值
+  #[error("{clause}: value is required")]
+  MissingValue { clause: &'static str },
+  // 占位符和参数数量不一致
+  #[error("{clause}: parameter count mismatch, expected {expected} but got {actual}: {sql}")]
+  ParameterCountMismatch { clause: &'static str, expected: usize, actual: usize, sql: String },
+  #[error("{clause}: missing named parameter :{name}: {sql}")]
+  MissingParameter { clause: &'static str, name: String, sql: String },
+  #[error("{clause}: unused named parameters {}: {sql}", names.join(", "))]
+  UnusedParameter { clause: &'static str, names: Vec<String>, sql: String },
+  // 同名参数的值不同
+  #[error("{clause}: conflicting values for named parameter :{name}: {sql}")]
+  ConflictingParameter { clause: &'static str, name: String, sql: String },
+  // eg: where 条件中的数组, order 中非字符串的方向
+  #[error("{clause}: unsupported value {value}")]
+  UnsupportedValue { clause: &'static str, value: String },
+  // eg: where_range("id", ..)
+  #[error("{clause}: invalid range on {column}: {reason}")]
+  InvalidRange { clause: &'static str, column: String, reason: String },
+  // 属性名和列名无法对应
+  #[error("{table}: unknown column {column}")]
+  UnknownColumn { table: String, column: String },
+  #[error("invalid identifier {identifier:?}: {reason}")]
+  InvalidIdentifier { identifier: String, reason: &'static str },
+  // eg: postgresql 字符串中的 NUL
+  #[error("{dialect:?} does not support {feature}")]
+  DialectUnsupported { dialect: crate::Dialect, feature: String },
+}
+
+impl SqlError {
+  // 标记出错的子句, eg: Having 复用 Where, 出错时改为 HAVING
+  pub fn with_clause(self, clause: &'static str) -> Self {
+    match self {
+      SqlError::MissingValue { .. } => SqlError::MissingValue { clause },
+      SqlError::ParameterCountMismatch { expected, actual, sql, .. } => SqlError::ParameterCountMismatch { clause, expected, actual, sql },
+      SqlError::MissingParameter { name, sql, .. } => SqlError::MissingParameter { clause, name, sql },
+      SqlError::UnusedParameter { names, sql, .. } => SqlError::UnusedParameter { clause, names, sql },
+      SqlError::ConflictingParameter { name, sql, .. } => SqlError::ConflictingParameter { clause, name, sql },
+      SqlError::UnsupportedValue { value, .. } => SqlError::UnsupportedValue { clause, value },
+      SqlError::InvalidRange { column, reason, .. } => SqlError::InvalidRange { clause, column, reason },
+      error => error,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  #[test]
+  fn to_string() {
+    assert_eq!(SqlError::MissingWhere { statement: "DELETE" }.to_string(), "DELETE: WHERE condition is required");
+    assert_eq!(SqlError::UnknownColumn { table: "users".to_owned(), column: "nick".to_owned() }.to_string(), "users: unknown column nick");
+    assert_eq!(SqlError::DialectUnsupported { dialect: crate::Dialect::Sqlite, feature: "column position of name".to_owned() }.to_string(), "Sqlite does not support column position of name");
+    assert_eq!(SqlError::UnsupportedValue { clause: "SQL", value: "[1]".to_owned() }.with_clause("WHERE").to_string(), "WHERE: unsupported value [1]");
+    assert_eq!(SqlError::MissingWhere { statement: "DELETE" }.with_clause("WHERE"), SqlError::MissingWhere { statement: "DELETE" });
+  }
+}
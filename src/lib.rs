@@ -1,7 +1,8 @@
-
-#![feature(let_chains)]
+// #[derive(Manageable)] 生成的代码使用 ::sql_gen 路径
+extern crate self as sql_gen;
 
 pub mod const_data;
+pub mod dialect;
 pub mod methods;
 pub mod error;
 pub mod collectors;
@@ -11,7 +12,7 @@ pub mod table;
 pub mod prelude;
 pub use prelude::*;
 
-pub use const_data::GLOBAL_DB_KEY_MAPPING;
+pub use const_data::db_key_mapping;
 
 pub struct SqlGen {
 }
@@ -20,19 +21,19 @@ impl SqlGen {
     pub fn create_database(database_name: &str) -> DefineDatabase {
         DefineDatabase::create_database(database_name)
     }
-    pub fn alter_database(database_name: &str, callback: impl Fn(&mut DefineDatabase) -> ()) -> DefineDatabase {
+    pub fn alter_database(database_name: &str, callback: impl Fn(&mut DefineDatabase)) -> DefineDatabase {
         DefineDatabase::alter_database(database_name, callback)
     }
     pub fn drop_database(database_name: &str) -> DefineDatabase {
         DefineDatabase::drop_database(database_name)
     }
-    pub fn create_table(table_name: &str, callback: impl Fn(&mut DefineTable) -> ()) -> DefineTable {
+    pub fn create_table(table_name: &str, callback: impl Fn(&mut DefineTable)) -> DefineTable {
         DefineTable::create(table_name, callback)
     }
     pub fn rename_table(old_table_name: &str, new_table_name: &str) -> DefineTable {
         DefineTable::rename(old_table_name, new_table_name)
     }
-    pub fn alter_table(table_name: &str, callback: impl Fn(&mut DefineTable) -> ()) -> DefineTable {
+    pub fn alter_table(table_name: &str, callback: impl Fn(&mut DefineTable)) -> DefineTable {
         DefineTable::alter(table_name, callback)
     }
     pub fn drop_table(table_name: &str) -> DefineTable {
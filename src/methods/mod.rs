@@ -5,7 +5,7 @@ use regex::Regex;
 pub fn type_to_pluralize_string<M>() -> String where M: ?Sized {
   // eg: xxx::UserTable
   let full_namespace = type_name::<M>();
-  let full_namespace = Regex::new(r"<.*>$").unwrap().replace(&full_namespace, "").to_string();
+  let full_namespace = Regex::new(r"<.*>$").unwrap().replace(full_namespace, "").to_string();
   // eg: UserTable
   let struct_name = demodulize::demodulize(&full_namespace);
   // eg: user_table
@@ -15,14 +15,33 @@ pub fn type_to_pluralize_string<M>() -> String where M: ?Sized {
   pluralize::to_plural(&snake_struct_name)
 }
 
-pub fn json_value_to_string(value: &serde_json::Value) -> Result<String, crate::error::SqlError> {
-  let string = match value {
-    serde_json::Value::String(string) => format!("'{}'", string),
-    serde_json::Value::Bool(boolean) => format!("{}", if *boolean {1} else {0}),
+pub fn json_value_to_sql_value(value: &serde_json::Value) -> Result<crate::collectors::SqlValue, crate::error::SqlError> {
+  match value {
     serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
-      return Err(crate::error::SqlError::Message(format!("Error: json_value_to_string value type not support! {:?}", value)));
+      Err(crate::error::SqlError::UnsupportedValue { clause: "SQL", value: value.to_string() })
     },
-    _ => value.to_string(),
-  };
-  Ok(string)
-}
\ No newline at end of file
+    _ => Ok(crate::collectors::SqlValue::from(value)),
+  }
+}
+
+pub fn json_value_to_string(value: &serde_json::Value, dialect: crate::Dialect) -> Result<String, crate::error::SqlError> {
+  json_value_to_sql_value(value)?.to_sql_literal(dialect)
+}
+
+// 手写的 sql 片段
+// 位置参数: ["a = ? AND b = ?", 1, 2]
+// 命名参数: ["a = :a AND b = :b", {"a": 1, "b": 2}]
+pub fn raw_condition_to_sql(condition: &[serde_json::Value]) -> Result<crate::collectors::Sql, crate::error::SqlError> {
+  let mut sql = crate::collectors::Sql::default();
+  if let Some((raw, values)) = condition.split_first() {
+    let raw = raw.as_str().ok_or_else(|| crate::error::SqlError::UnsupportedValue { clause: "SQL", value: raw.to_string() })?;
+    if let [serde_json::Value::Object(obj)] = values {
+      let values = obj.iter().map(|(name, value)| Ok((name.to_owned(), json_value_to_sql_value(value)?))).collect::<Result<std::collections::HashMap<String, crate::collectors::SqlValue>, crate::error::SqlError>>()?;
+      sql.push_raw_named(raw, &values)?;
+    } else {
+      let values = values.iter().map(json_value_to_sql_value).collect::<Result<Vec<crate::collectors::SqlValue>, crate::error::SqlError>>()?;
+      sql.push_raw(raw, values)?;
+    }
+  }
+  Ok(sql)
+}
@@ -1,10 +1,13 @@
 pub use crate::error::SqlError;
+pub use crate::dialect::Dialect;
+pub use crate::collectors::{Sql, SqlValue, PlaceholderStyle};
 pub use crate::database::{define_database::{self, DefineDatabase}};
 pub use crate::table::{
     column::{self, Column, column_type::{self, ColumnType, ColumnTypeable}},
-    define_table::{self, DefineTable}, manager::{self, Manageable},
+    define_table::{self, DefineTable}, manager::{self, Manageable}, statements::{JoinType, Expr},
 };
 pub use crate::SqlGen;
+pub use sql_gen_derive::Manageable;
 
 pub use thiserror;
 pub use once_cell;
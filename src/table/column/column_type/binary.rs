@@ -52,11 +52,26 @@ impl ColumnTypeable for Binary {
     self
   }
 
+  fn comment(&self) -> Option<&'static str> { self.comment }
   fn set_comment(&mut self, comment: &'static str) -> &mut Self {
     self.comment = Some(comment);
     self
   }
 
+  fn default_value(&self, dialect: crate::Dialect) -> Result<Option<String>, crate::SqlError> {
+    Ok(self.default.as_ref().map(|default| {
+      match dialect {
+        crate::Dialect::Mysql => format!("{:?}", default),
+        // postgresql: bytea hex format, eg: '\x01ff'
+        crate::Dialect::Postgresql => format!("'\\x{}'", default.iter().map(|byte| format!("{:02x}", byte)).collect::<String>()),
+        // sqlite: blob literal, eg: X'01ff'
+        crate::Dialect::Sqlite => format!("X'{}'", default.iter().map(|byte| format!("{:02x}", byte)).collect::<String>()),
+        // mssql: binary constant, eg: 0x01ff
+        crate::Dialect::Mssql => format!("0x{}", default.iter().map(|byte| format!("{:02x}", byte)).collect::<String>()),
+      }
+    }))
+  }
+
   fn index(&self) -> Option<bool> { self.index }
   fn set_index(&mut self, index: bool) -> &mut Self { self.index = Some(index); self }
 
@@ -69,32 +84,36 @@ impl ColumnTypeable for Binary {
     self
   }
 
-  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable) -> Option<Sql> {
-    let mut sql = Sql::default();
-
-    let mut r#type = format!("{}({})", crate::const_data::GLOBAL_DB_KEY_MAPPING.get("binary").unwrap(), self.byte.unwrap());
-    if let Some(is_binary) = self.is_binary {
-      if is_binary {
+  fn to_type_sql(&self, _column: &crate::Column, _table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
+    // postgresql: BYTEA 没有长度, sqlite: BLOB 没有长度
+    if dialect == crate::Dialect::Postgresql || dialect == crate::Dialect::Sqlite {
+      return Some(Sql::new(crate::const_data::db_key_mapping(dialect).get("binary").unwrap().to_owned()));
+    }
+    let mut r#type = format!("{}({})", crate::const_data::db_key_mapping(dialect).get("binary").unwrap(), self.byte.unwrap());
+    if let Some(is_binary) = self.is_binary
+      && is_binary {
         r#type = format!("BINARY({})", self.byte.unwrap());
       }
-    }
-    sql.push_value(&r#type);
+    Some(Sql::new(r#type))
+  }
+
+  fn to_sql(&self, column: &crate::Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
+    let Some(mut sql) = self.to_type_sql(column, table, dialect) else { return Ok(None) };
 
     if let Some(null) = self.null {
       sql.push_value(&format!(" {}", if null { "NULL" } else { "NOT NULL" }));
     }
-    if let Some(default) = &self.default {
-      sql.push_value(&format!(" DEFAULT {:?}", default));
+    if let Some(default) = self.default_value(dialect)? {
+      sql.push_value(&format!(" DEFAULT {}", default));
     }
-    if let Some(primary_key) = self.primary_key {
-      if primary_key {
-        sql.push_value(&format!(" PRIMARY KEY"));
+    if let Some(primary_key) = self.primary_key
+      && primary_key {
+        sql.push_value(" PRIMARY KEY");
       }
+    if let Some(comment) = self.comment && dialect == crate::Dialect::Mysql {
+      sql.push_value(&format!(" COMMENT {}", dialect.quote_string(comment)?));
     }
-    if let Some(comment) = self.comment {
-      sql.push_value(&format!(" COMMENT '{}'", comment));
-    }
-    Some(sql)
+    Ok(Some(sql))
   }
 }
 
@@ -106,12 +125,11 @@ impl From<Binary> for ColumnType {
 }
 
 #[cfg(test)]
-
 mod tests {
   use crate::prelude::*;
     #[test]
     fn to_sql() {
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         // create table
         let sql: String = crate::SqlGen::create_table("users", |table| {
@@ -119,10 +137,10 @@ mod tests {
           table.add_column_binary("binary_b", |binary| {
             binary.set_default(vec![1]).set_comment("my comment");
           });
-        }).try_into().unwrap();
-        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS users (
-binary_a VARBINARY(255),
-binary_b VARBINARY(255) DEFAULT [1] COMMENT 'my comment'
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS `users` (
+`binary_a` VARBINARY(255),
+`binary_b` VARBINARY(255) DEFAULT [1] COMMENT 'my comment'
 );".to_owned());
 
         // update table
@@ -138,14 +156,28 @@ binary_b VARBINARY(255) DEFAULT [1] COMMENT 'my comment'
             binary.set_binary_type(200).set_comment("rename to is_b");
           });
           table.drop_column_binary("binary_d");
-        }).try_into().unwrap();
-        assert_eq!(sql, "ALTER TABLE users
-ADD COLUMN binary_a VARBINARY(255),
-ADD COLUMN binary_b VARBINARY(255) DEFAULT [1] COMMENT 'my comment',
-MODIFY COLUMN binary_c VARBINARY(255) NOT NULL,
-CHANGE COLUMN is_a is_b BINARY(200) COMMENT 'rename to is_b',
-DROP COLUMN binary_d;".to_owned());
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE `users`
+ADD COLUMN `binary_a` VARBINARY(255),
+ADD COLUMN `binary_b` VARBINARY(255) DEFAULT [1] COMMENT 'my comment',
+MODIFY COLUMN `binary_c` VARBINARY(255) NOT NULL,
+CHANGE COLUMN `is_a` `is_b` BINARY(200) COMMENT 'rename to is_b',
+DROP COLUMN `binary_d`;".to_owned());
 
       }
+      // postgresql
+      {
+        // create table
+        let sql: String = crate::SqlGen::create_table("users", |table| {
+          table.add_column_binary("binary_a", |_binary| {});
+          table.add_column_binary("binary_b", |binary| {
+            binary.set_default(vec![1, 255]);
+          });
+        }).to_sql(crate::Dialect::Postgresql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS \"users\" (
+\"binary_a\" BYTEA,
+\"binary_b\" BYTEA DEFAULT '\\x01ff'
+);".to_owned());
+      }
     }
 }
\ No newline at end of file
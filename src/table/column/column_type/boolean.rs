@@ -1,7 +1,7 @@
 use crate::collectors::Sql;
 use crate::{column::column_type::{ColumnType, ColumnTypeable}};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Boolean {
   default: Option<bool>,
   null: Option<bool>,
@@ -11,19 +11,6 @@ pub struct Boolean {
   primary_key: Option<bool>,
 }
 
-impl Default for Boolean {
-  fn default() -> Boolean {
-    Boolean {
-      default: None,
-      null: None,
-      comment: None,
-      index: None,
-      unique: None,
-      primary_key: None,
-    }
-  }
-}
-
 impl Boolean {
     pub fn set_default(&mut self, default: bool) -> &mut Self {
       self.default = Some(default);
@@ -35,8 +22,16 @@ impl ColumnTypeable for Boolean {
   fn null(&self) -> Option<bool> { self.null }
   fn set_null(&mut self, null: bool) -> &mut Self { self.null = Some(null); self }
 
+  fn comment(&self) -> Option<&'static str> { self.comment }
   fn set_comment(&mut self, comment: &'static str) -> &mut Self { self.comment = Some(comment); self }
 
+  fn default_value(&self, dialect: crate::Dialect) -> Result<Option<String>, crate::SqlError> {
+    Ok(self.default.map(|default| {
+      // mssql: BIT 只能是 1 或 0
+      if dialect == crate::Dialect::Mssql { format!("{}", if default {1} else {0}) } else { default.to_string() }
+    }))
+  }
+
   fn index(&self) -> Option<bool> { self.index }
   fn set_index(&mut self, index: bool) -> &mut Self { self.index = Some(index); self }
 
@@ -46,24 +41,28 @@ impl ColumnTypeable for Boolean {
   fn primary_key(&self) -> Option<bool> { self.primary_key }
   fn set_primary_key(&mut self, primary_key: bool) -> &mut Self { self.primary_key = Some(primary_key); self }
 
-  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable) -> Option<Sql> {
+  fn to_type_sql(&self, _column: &crate::Column, _table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
     let mut sql = Sql::default();
-    sql.push_value(crate::GLOBAL_DB_KEY_MAPPING.get("boolean").unwrap());
+    sql.push_value(crate::const_data::db_key_mapping(dialect).get("boolean").unwrap());
+    Some(sql)
+  }
+
+  fn to_sql(&self, column: &crate::Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
+    let Some(mut sql) = self.to_type_sql(column, table, dialect) else { return Ok(None) };
     if let Some(null) = self.null {
       sql.push_value(&format!(" {}", if null { "NULL" } else { "NOT NULL" }));
     }
-    if let Some(default) = self.default {
+    if let Some(default) = self.default_value(dialect)? {
       sql.push_value(&format!(" DEFAULT {}", default));
     }
-    if let Some(primary_key) = self.primary_key {
-      if primary_key {
-        sql.push_value(&format!(" PRIMARY KEY"));
+    if let Some(primary_key) = self.primary_key
+      && primary_key {
+        sql.push_value(" PRIMARY KEY");
       }
+    if let Some(comment) = self.comment && dialect == crate::Dialect::Mysql {
+      sql.push_value(&format!(" COMMENT {}", dialect.quote_string(comment)?));
     }
-    if let Some(comment) = self.comment {
-      sql.push_value(&format!(" COMMENT '{}'", comment));
-    }
-    Some(sql)
+    Ok(Some(sql))
   }
 }
 
@@ -75,12 +74,11 @@ impl From<Boolean> for ColumnType {
 }
 
 #[cfg(test)]
-
 mod tests {
   use crate::prelude::*;
     #[test]
     fn to_sql() {
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         // create table
         let sql: String = crate::SqlGen::create_table("users", |table| {
@@ -88,10 +86,10 @@ mod tests {
           table.add_column_boolean("boolean_b", |boolean| {
             boolean.set_default(true).set_comment("my comment");
           });
-        }).try_into().unwrap();
-        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS users (
-boolean_a BOOLEAN,
-boolean_b BOOLEAN DEFAULT true COMMENT 'my comment'
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS `users` (
+`boolean_a` BOOLEAN,
+`boolean_b` BOOLEAN DEFAULT true COMMENT 'my comment'
 );".to_owned());
 
         // update table
@@ -107,13 +105,13 @@ boolean_b BOOLEAN DEFAULT true COMMENT 'my comment'
             boolean.set_comment("rename to is_b");
           });
           table.drop_column_boolean("boolean_d");
-        }).try_into().unwrap();
-        assert_eq!(sql, "ALTER TABLE users
-ADD COLUMN boolean_a BOOLEAN,
-ADD COLUMN boolean_b BOOLEAN DEFAULT true COMMENT 'my comment',
-MODIFY COLUMN boolean_c BOOLEAN NOT NULL,
-CHANGE COLUMN is_a is_b BOOLEAN COMMENT 'rename to is_b',
-DROP COLUMN boolean_d;".to_owned());
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE `users`
+ADD COLUMN `boolean_a` BOOLEAN,
+ADD COLUMN `boolean_b` BOOLEAN DEFAULT true COMMENT 'my comment',
+MODIFY COLUMN `boolean_c` BOOLEAN NOT NULL,
+CHANGE COLUMN `is_a` `is_b` BOOLEAN COMMENT 'rename to is_b',
+DROP COLUMN `boolean_d`;".to_owned());
 
       }
     }
@@ -47,11 +47,14 @@ impl ColumnTypeable for Decimal {
     self
   }
 
+  fn comment(&self) -> Option<&'static str> { self.comment }
   fn set_comment(&mut self, comment: &'static str) -> &mut Self {
     self.comment = Some(comment);
     self
   }
 
+  fn default_value(&self, _dialect: crate::Dialect) -> Result<Option<String>, crate::SqlError> { Ok(self.default.map(|default| default.to_string())) }
+
   fn index(&self) -> Option<bool> { self.index }
   fn set_index(&mut self, index: bool) -> &mut Self { self.index = Some(index); self }
 
@@ -64,27 +67,33 @@ impl ColumnTypeable for Decimal {
     self
   }
 
-  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable) -> Option<Sql> {
+  fn to_type_sql(&self, _column: &crate::Column, _table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
     let mut sql = Sql::default();
-    let r#type = crate::const_data::GLOBAL_DB_KEY_MAPPING.get("decimal").unwrap();
+    let r#type = crate::const_data::db_key_mapping(dialect).get("decimal").unwrap();
     sql.push_value(r#type);
-    sql.push_value(&format!("({}, {})", self.precision.unwrap(), self.scale.unwrap()));
+    // sqlite: NUMERIC 亲和类型没有精度
+    if dialect != crate::Dialect::Sqlite {
+      sql.push_value(&format!("({}, {})", self.precision.unwrap(), self.scale.unwrap()));
+    }
+    Some(sql)
+  }
 
+  fn to_sql(&self, column: &crate::Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
+    let Some(mut sql) = self.to_type_sql(column, table, dialect) else { return Ok(None) };
     if let Some(null) = self.null {
       sql.push_value(&format!(" {}", if null { "NULL" } else { "NOT NULL" }));
     }
-    if let Some(default) = self.default {
+    if let Some(default) = self.default_value(dialect)? {
       sql.push_value(&format!(" DEFAULT {}", default));
     }
-    if let Some(primary_key) = self.primary_key {
-      if primary_key {
-        sql.push_value(&format!(" PRIMARY KEY"));
+    if let Some(primary_key) = self.primary_key
+      && primary_key {
+        sql.push_value(" PRIMARY KEY");
       }
+    if let Some(comment) = self.comment && dialect == crate::Dialect::Mysql {
+      sql.push_value(&format!(" COMMENT {}", dialect.quote_string(comment)?));
     }
-    if let Some(comment) = self.comment {
-      sql.push_value(&format!(" COMMENT '{}'", comment));
-    }
-    Some(sql)
+    Ok(Some(sql))
   }
 }
 
@@ -95,12 +104,11 @@ impl From<Decimal> for ColumnType {
 }
 
 #[cfg(test)]
-
 mod tests {
   use crate::prelude::*;
     #[test]
     fn to_sql() {
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         // create table
         let sql: String = crate::SqlGen::create_table("users", |table| {
@@ -108,10 +116,10 @@ mod tests {
           table.add_column_decimal("decimal_b", |decimal| {
             decimal.set_precision_scale(10, 5).set_default(1.0).set_comment("my comment");
           });
-        }).try_into().unwrap();
-        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS users (
-decimal_a DECIMAL(30, 6),
-decimal_b DECIMAL(10, 5) DEFAULT 1 COMMENT 'my comment'
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS `users` (
+`decimal_a` DECIMAL(30, 6),
+`decimal_b` DECIMAL(10, 5) DEFAULT 1 COMMENT 'my comment'
 );".to_owned());
 
         // update table
@@ -127,13 +135,13 @@ decimal_b DECIMAL(10, 5) DEFAULT 1 COMMENT 'my comment'
             decimal.set_comment("rename to is_b");
           });
           table.drop_column_boolean("decimal_d");
-        }).try_into().unwrap();
-        assert_eq!(sql, "ALTER TABLE users
-ADD COLUMN decimal_a DECIMAL(30, 6),
-ADD COLUMN decimal_b DECIMAL(30, 6) DEFAULT 0.5 COMMENT 'my comment',
-MODIFY COLUMN decimal_c DECIMAL(30, 6) NOT NULL DEFAULT 0.5,
-CHANGE COLUMN is_a is_b BOOLEAN COMMENT 'rename to is_b',
-DROP COLUMN decimal_d;".to_owned());
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE `users`
+ADD COLUMN `decimal_a` DECIMAL(30, 6),
+ADD COLUMN `decimal_b` DECIMAL(30, 6) DEFAULT 0.5 COMMENT 'my comment',
+MODIFY COLUMN `decimal_c` DECIMAL(30, 6) NOT NULL DEFAULT 0.5,
+CHANGE COLUMN `is_a` `is_b` BOOLEAN COMMENT 'rename to is_b',
+DROP COLUMN `decimal_d`;".to_owned());
 
       }
     }
@@ -1,7 +1,7 @@
 use crate::collectors::Sql;
 use crate::{column::column_type::{ColumnType, ColumnTypeable}};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Double {
   default: Option<f64>,
   null: Option<bool>,
@@ -11,19 +11,6 @@ pub struct Double {
   primary_key: Option<bool>,
 }
 
-impl Default for Double {
-  fn default() -> Double {
-    Double {
-      default: None,
-      null: None,
-      comment: None,
-      index: None,
-      unique: None,
-      primary_key: None,
-    }
-  }
-}
-
 impl Double {
   pub fn set_default(&mut self, default: f64) -> &mut Self {
     self.default = Some(default);
@@ -38,11 +25,14 @@ impl ColumnTypeable for Double {
     self
   }
 
+  fn comment(&self) -> Option<&'static str> { self.comment }
   fn set_comment(&mut self, comment: &'static str) -> &mut Self {
     self.comment = Some(comment);
     self
   }
 
+  fn default_value(&self, _dialect: crate::Dialect) -> Result<Option<String>, crate::SqlError> { Ok(self.default.map(|default| default.to_string())) }
+
   fn index(&self) -> Option<bool> { self.index }
   fn set_index(&mut self, index: bool) -> &mut Self { self.index = Some(index); self }
 
@@ -55,25 +45,29 @@ impl ColumnTypeable for Double {
     self
   }
 
-  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable) -> Option<Sql> {
+  fn to_type_sql(&self, _column: &crate::Column, _table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
     let mut sql = Sql::default();
-    let r#type = crate::const_data::GLOBAL_DB_KEY_MAPPING.get("double").unwrap();
+    let r#type = crate::const_data::db_key_mapping(dialect).get("double").unwrap();
     sql.push_value(r#type);
+    Some(sql)
+  }
+
+  fn to_sql(&self, column: &crate::Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
+    let Some(mut sql) = self.to_type_sql(column, table, dialect) else { return Ok(None) };
     if let Some(null) = self.null {
       sql.push_value(&format!(" {}", if null { "NULL" } else { "NOT NULL" }));
     }
-    if let Some(default) = self.default {
+    if let Some(default) = self.default_value(dialect)? {
       sql.push_value(&format!(" DEFAULT {}", default));
     }
-    if let Some(primary_key) = self.primary_key {
-      if primary_key {
-        sql.push_value(&format!(" PRIMARY KEY"));
+    if let Some(primary_key) = self.primary_key
+      && primary_key {
+        sql.push_value(" PRIMARY KEY");
       }
+    if let Some(comment) = self.comment && dialect == crate::Dialect::Mysql {
+      sql.push_value(&format!(" COMMENT {}", dialect.quote_string(comment)?));
     }
-    if let Some(comment) = self.comment {
-      sql.push_value(&format!(" COMMENT '{}'", comment));
-    }
-    Some(sql)
+    Ok(Some(sql))
   }
 }
 
@@ -84,12 +78,11 @@ impl From<Double> for ColumnType {
 }
 
 #[cfg(test)]
-
 mod tests {
   use crate::prelude::*;
     #[test]
     fn to_sql() {
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         // create table
         let sql: String = crate::SqlGen::create_table("users", |table| {
@@ -97,10 +90,10 @@ mod tests {
           table.add_column_double("double_b", |double| {
             double.set_default(1.0).set_comment("my comment");
           });
-        }).try_into().unwrap();
-        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS users (
-double_a DOUBLE,
-double_b DOUBLE DEFAULT 1 COMMENT 'my comment'
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS `users` (
+`double_a` DOUBLE,
+`double_b` DOUBLE DEFAULT 1 COMMENT 'my comment'
 );".to_owned());
 
         // update table
@@ -116,13 +109,13 @@ double_b DOUBLE DEFAULT 1 COMMENT 'my comment'
             double.set_comment("rename to is_b");
           });
           table.drop_column_double("double_d");
-        }).try_into().unwrap();
-        assert_eq!(sql, "ALTER TABLE users
-ADD COLUMN double_a DOUBLE,
-ADD COLUMN double_b DOUBLE DEFAULT 1 COMMENT 'my comment',
-MODIFY COLUMN boolean_c DOUBLE NOT NULL,
-CHANGE COLUMN is_a is_b DOUBLE COMMENT 'rename to is_b',
-DROP COLUMN double_d;".to_owned());
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE `users`
+ADD COLUMN `double_a` DOUBLE,
+ADD COLUMN `double_b` DOUBLE DEFAULT 1 COMMENT 'my comment',
+MODIFY COLUMN `boolean_c` DOUBLE NOT NULL,
+CHANGE COLUMN `is_a` `is_b` DOUBLE COMMENT 'rename to is_b',
+DROP COLUMN `double_d`;".to_owned());
 
       }
     }
@@ -1,19 +1,11 @@
 use crate::collectors::Sql;
 use crate::{column::column_type::{ColumnType, ColumnTypeable}};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct ForeignKey {
   foreign_key: Option<crate::define_table::ForeignKey>,
 }
 
-impl Default for ForeignKey {
-  fn default() -> ForeignKey {
-    ForeignKey {
-      foreign_key: None,
-    }
-  }
-}
-
 impl ForeignKey {
 }
 
@@ -46,9 +38,13 @@ impl ColumnTypeable for ForeignKey {
     self.foreign_key = Some(foreign_key);
     self
   }
+  fn remove_foreign_key(&mut self) -> &mut Self {
+    self.foreign_key = None;
+    self
+  }
 
-  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable) -> Option<Sql> {
-    None
+  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable, _dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
+    Ok(None)
   }
 }
 
@@ -60,20 +56,30 @@ impl From<ForeignKey> for ColumnType {
 
 
 #[cfg(test)]
-
 mod tests {
   // use crate::prelude::*;
     #[test]
     fn to_sql() {
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         // update table
         let sql: String = crate::SqlGen::alter_table("users", |table| {
           table.add_foreign_key("user_id", |_foreign_key| {});
-        }).try_into().unwrap();
-        assert_eq!(sql, "ALTER TABLE users
-ADD CONSTRAINT FOREIGN KEY fk_on_user_id (user_id) REFERENCES users (id);".to_owned());
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE `users`
+ADD CONSTRAINT FOREIGN KEY `fk_on_user_id` (`user_id`) REFERENCES `users` (`id`);".to_owned());
 
       }
+      // postgresql
+      {
+        // update table
+        let sql: String = crate::SqlGen::alter_table("users", |table| {
+          table.add_foreign_key("user_id", |_foreign_key| {});
+          table.drop_foreign_key("order_id");
+        }).to_sql(crate::Dialect::Postgresql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE \"users\"
+ADD CONSTRAINT \"fk_on_user_id\" FOREIGN KEY (\"user_id\") REFERENCES \"users\" (\"id\"),
+DROP CONSTRAINT \"fk_on_order_id\";".to_owned());
+      }
     }
 }
\ No newline at end of file
@@ -56,8 +56,8 @@ impl ColumnTypeable for Index {
   //   self
   // }
 
-  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable) -> Option<Sql> {
-    None
+  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable, _dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
+    Ok(None)
   }
 }
 
@@ -69,12 +69,11 @@ impl From<Index> for ColumnType {
 
 
 #[cfg(test)]
-
 mod tests {
   use crate::prelude::*;
   #[test]
   fn to_sql() {
-    #[cfg(feature = "mysql")]
+    // mysql
     {
       // update table
       let sql: String = crate::SqlGen::alter_table("users", |table| {
@@ -83,12 +82,52 @@ mod tests {
           index.set_unique(true);
         });
         table.drop_index("index_on_index_c");
-      }).try_into().unwrap();
-      assert_eq!(sql, "ALTER TABLE users
-ADD CONSTRAINT INDEX index_on_index_a (index_a)
-ADD CONSTRAINT UNIQUE INDEX unique_index_on_index_b (index_b)
-DROP INDEX index_on_index_c;".to_owned());
+      }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+      assert_eq!(sql, "ALTER TABLE `users`
+ADD CONSTRAINT INDEX `index_on_index_a` (`index_a`)
+ADD CONSTRAINT UNIQUE INDEX `unique_index_on_index_b` (`index_b`)
+DROP INDEX `index_on_index_c`;".to_owned());
 
     }
+    // postgresql
+    {
+      // update table
+      let sql: String = crate::SqlGen::alter_table("users", |table| {
+        table.add_index(vec!["index_a"], |_index| {});
+        table.add_index(vec!["index_b"], |index| {
+          index.set_unique(true);
+        });
+        table.drop_index("index_on_index_c");
+      }).to_sql(crate::Dialect::Postgresql).unwrap().to_sql_string().unwrap();
+      assert_eq!(sql, "CREATE INDEX \"index_on_index_a\" ON \"users\" (\"index_a\");
+CREATE UNIQUE INDEX \"unique_index_on_index_b\" ON \"users\" (\"index_b\");
+DROP INDEX \"index_on_index_c\";".to_owned());
+    }
+    // sqlite
+    {
+      // update table
+      let sql: String = crate::SqlGen::alter_table("users", |table| {
+        table.add_index(vec!["index_a", "index_b"], |_index| {});
+        table.add_unique(vec!["index_c"], |_unique| {});
+        table.drop_index("index_on_index_d");
+      }).to_sql(crate::Dialect::Sqlite).unwrap().to_sql_string().unwrap();
+      assert_eq!(sql, "CREATE INDEX \"index_on_index_a_and_index_b\" ON \"users\" (\"index_a\",\"index_b\");
+CREATE UNIQUE INDEX \"unique_on_index_c\" ON \"users\" (\"index_c\");
+DROP INDEX \"index_on_index_d\";".to_owned());
+    }
+    // mssql
+    {
+      // update table
+      let sql: String = crate::SqlGen::alter_table("users", |table| {
+        table.add_index(vec!["index_a", "index_b"], |_index| {});
+        table.add_unique(vec!["index_c"], |_unique| {});
+        table.rename_index("index_on_index_d", "idx_on_index_d");
+        table.drop_index("index_on_index_e");
+      }).to_sql(crate::Dialect::Mssql).unwrap().to_sql_string().unwrap();
+      assert_eq!(sql, "ALTER TABLE [users] ADD CONSTRAINT [unique_on_index_c] UNIQUE ([index_c]);
+CREATE INDEX [index_on_index_a_and_index_b] ON [users] ([index_a],[index_b]);
+EXEC sp_rename N'users.index_on_index_d', N'idx_on_index_d', N'INDEX';
+DROP INDEX [index_on_index_e] ON [users];".to_owned());
+    }
   }
-}
\ No newline at end of file
+}
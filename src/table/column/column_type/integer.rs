@@ -2,7 +2,7 @@
 use crate::collectors::Sql;
 use crate::{column::column_type::{ColumnType, ColumnTypeable}};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Integer {
   limit: Option<u8>,
   // mysql: m => 1..=64, postgresql: width => 1...
@@ -21,25 +21,6 @@ pub struct Integer {
   foreign_key: Option<crate::define_table::ForeignKey>,
 }
 
-impl Default for Integer {
-  fn default() -> Integer {
-    Integer {
-      limit: None,
-      bit_type: None,
-      unsigned: None,
-      zerofill: None,
-      default: None,
-      null: None,
-      comment: None,
-      index: None,
-      unique: None,
-      auto_increment: None,
-      primary_key: None,
-      foreign_key: None,
-    }
-  }
-}
-
 impl Integer {
   pub fn set_limit(&mut self, limit: u8) -> &mut Self {
     self.limit = Some(limit);
@@ -59,7 +40,7 @@ impl Integer {
     self.unsigned = Some(unsigned);
     self
   }
-  #[cfg(feature = "mysql")]
+  // mysql: 其他数据库忽略
   pub fn set_zerofill(&mut self, zerofill: bool) -> &mut Self {
     self.zerofill = Some(zerofill);
     self
@@ -81,11 +62,14 @@ impl ColumnTypeable for Integer {
     self
   }
 
+  fn comment(&self) -> Option<&'static str> { self.comment }
   fn set_comment(&mut self, comment: &'static str) -> &mut Self {
     self.comment = Some(comment);
     self
   }
 
+  fn default_value(&self, _dialect: crate::Dialect) -> Result<Option<String>, crate::SqlError> { Ok(self.default.map(|default| default.to_string())) }
+
   fn index(&self) -> Option<bool> { self.index }
   fn set_index(&mut self, index: bool) -> &mut Self { self.index = Some(index); self }
 
@@ -103,53 +87,65 @@ impl ColumnTypeable for Integer {
     self.foreign_key = Some(foreign_key);
     self
   }
+  fn remove_foreign_key(&mut self) -> &mut Self {
+    self.foreign_key = None;
+    self
+  }
 
-  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable) -> Option<Sql> {
-    let mut sql = Sql::default();
-    let mut r#type = crate::const_data::GLOBAL_DB_KEY_MAPPING.get("integer").unwrap().to_owned();
+  fn auto_increment(&self) -> Option<bool> { self.auto_increment }
+
+  fn to_type_sql(&self, _column: &crate::Column, _table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
+    // sqlite: 只有 INTEGER PRIMARY KEY 才是 rowid 的别名
+    if dialect == crate::Dialect::Sqlite {
+      return Some(Sql::new(crate::const_data::db_key_mapping(dialect).get("integer").unwrap().to_owned()));
+    }
+    let mut r#type = crate::const_data::db_key_mapping(dialect).get("integer").unwrap().to_owned();
     if let Some(limit) = self.limit {
       if limit == 1 {
-        r#type = "TINYINT".to_owned();
+        // postgresql: 没有 TINYINT
+        r#type = if dialect == crate::Dialect::Postgresql { "SMALLINT".to_owned() } else { "TINYINT".to_owned() };
       }
       else if limit == 2 { r#type = "SMALLINT".to_owned(); }
-      else if limit == 4 { r#type = "INT".to_owned(); }
+      else if limit == 4 { r#type = crate::const_data::db_key_mapping(dialect).get("integer").unwrap().to_owned(); }
       else if limit == 8 { r#type = "BIGINT".to_owned(); }
     }
     if let Some(bit_width) = self.bit_type {
-      r#type = format!("BIT({})", bit_width);
+      // mssql: BIT 没有宽度
+      r#type = if dialect == crate::Dialect::Mssql { "BIT".to_owned() } else { format!("BIT({})", bit_width) };
     }
-    sql.push_value(&r#type);
-    if let Some(unsigned) = self.unsigned {
-      if unsigned {
+    Some(Sql::new(r#type))
+  }
+
+  fn to_sql(&self, column: &crate::Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
+    let Some(mut sql) = self.to_type_sql(column, table, dialect) else { return Ok(None) };
+    // postgresql: 没有无符号整数
+    if let Some(unsigned) = self.unsigned && dialect == crate::Dialect::Mysql
+      && unsigned {
         sql.push_value(" UNSIGNED");
-        #[cfg(feature = "mysql")]
-        if let Some(zerofill) = self.zerofill {
-          if zerofill {
+        if let Some(zerofill) = self.zerofill
+          && zerofill {
             sql.push_value(" ZEROFILL");
           }
-        }
       }
-    }
     if let Some(null) = self.null {
       sql.push_value(&format!(" {}", if null { "NULL" } else { "NOT NULL" }));
     }
-    if let Some(default) = self.default {
+    if let Some(default) = self.default_value(dialect)? {
       sql.push_value(&format!(" DEFAULT {}", default));
     }
-    if let Some(auto_increment) = self.auto_increment {
-      if auto_increment {
-        sql.push_value(&format!(" {}", crate::GLOBAL_DB_KEY_MAPPING.get("auto_increment").unwrap()));
+    if let Some(auto_increment) = self.auto_increment
+      && auto_increment {
+        sql.push_value(&format!(" {}", crate::const_data::db_key_mapping(dialect).get("auto_increment").unwrap()));
       }
-    }
     // if let Some(primary_key) = self.primary_key {
     //   if primary_key {
     //     sql.push_value(&format!(" PRIMARY KEY"));
     //   }
     // }
-    if let Some(comment) = self.comment {
-      sql.push_value(&format!(" COMMENT '{}'", comment));
+    if let Some(comment) = self.comment && dialect == crate::Dialect::Mysql {
+      sql.push_value(&format!(" COMMENT {}", dialect.quote_string(comment)?));
     }
-    Some(sql)
+    Ok(Some(sql))
   }
 }
 
@@ -160,12 +156,11 @@ impl From<Integer> for ColumnType {
 }
 
 #[cfg(test)]
-
 mod tests {
   use crate::prelude::*;
     #[test]
     fn to_sql() {
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         // create table
         let sql: String = crate::SqlGen::create_table("users", |table| {
@@ -176,12 +171,12 @@ mod tests {
           table.add_column_integer("integer_b", |integer| {
             integer.set_default(1).set_comment("my comment");
           });
-        }).try_into().unwrap();
-        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS users (
-id INT NOT NULL AUTO_INCREMENT,
-integer_a INT,
-integer_b INT DEFAULT 1 COMMENT 'my comment',
-PRIMARY KEY pk_on_id (id)
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS `users` (
+`id` INT NOT NULL AUTO_INCREMENT,
+`integer_a` INT,
+`integer_b` INT DEFAULT 1 COMMENT 'my comment',
+PRIMARY KEY `pk_on_id` (`id`)
 );".to_owned());
 
         // update table
@@ -197,14 +192,161 @@ PRIMARY KEY pk_on_id (id)
             integer.set_comment("rename to is_b");
           });
           table.drop_column_integer("integer_d");
-        }).try_into().unwrap();
-        assert_eq!(sql, "ALTER TABLE users
-ADD COLUMN integer_a INT,
-ADD COLUMN integer_b INT DEFAULT 1 COMMENT 'my comment',
-MODIFY COLUMN integer_c BIGINT UNSIGNED NOT NULL,
-CHANGE COLUMN is_a is_b INT COMMENT 'rename to is_b',
-DROP COLUMN integer_d;".to_owned());
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE `users`
+ADD COLUMN `integer_a` INT,
+ADD COLUMN `integer_b` INT DEFAULT 1 COMMENT 'my comment',
+MODIFY COLUMN `integer_c` BIGINT UNSIGNED NOT NULL,
+CHANGE COLUMN `is_a` `is_b` INT COMMENT 'rename to is_b',
+DROP COLUMN `integer_d`;".to_owned());
+
+      }
+      // postgresql
+      {
+        // create table
+        let sql: String = crate::SqlGen::create_table("users", |table| {
+          table.add_column_integer("id", |integer| {
+            integer.set_null(false).set_primary_key(true).set_auto_increment(true);
+          });
+          table.add_column_integer("integer_a", |integer| {
+            integer.set_limit(1).set_unsigned(true);
+          });
+          table.add_column_integer("integer_b", |integer| {
+            integer.set_default(1).set_comment("my comment");
+          });
+        }).to_sql(crate::Dialect::Postgresql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS \"users\" (
+\"id\" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+\"integer_a\" SMALLINT,
+\"integer_b\" INTEGER DEFAULT 1,
+CONSTRAINT \"pk_on_id\" PRIMARY KEY (\"id\")
+);
+COMMENT ON COLUMN \"users\".\"integer_b\" IS 'my comment';".to_owned());
 
+        // update table
+        let sql: String = crate::SqlGen::alter_table("users", |table| {
+          table.add_column_integer("integer_a", |_integer| {});
+          table.modify_column_integer("integer_c", |integer| {
+            integer.set_null(false).set_index(true).set_unsigned(true).set_limit(8);
+          });
+          table.change_column_integer("is_a", "is_b", |integer| {
+            integer.set_default(0);
+          });
+          table.drop_column_integer("integer_d");
+        }).to_sql(crate::Dialect::Postgresql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE \"users\"
+ADD COLUMN \"integer_a\" INTEGER,
+ALTER COLUMN \"integer_c\" TYPE BIGINT,
+ALTER COLUMN \"integer_c\" SET NOT NULL,
+ALTER COLUMN \"is_a\" TYPE INTEGER,
+ALTER COLUMN \"is_a\" SET DEFAULT 0,
+DROP COLUMN \"integer_d\";
+ALTER TABLE \"users\" RENAME COLUMN \"is_a\" TO \"is_b\";".to_owned());
       }
+      // sqlite
+      {
+        // create table
+        let sql: String = crate::SqlGen::create_table("users", |table| {
+          table.add_column_integer("id", |integer| {
+            integer.set_null(false).set_primary_key(true).set_auto_increment(true);
+          });
+          table.add_column_integer("integer_a", |integer| {
+            integer.set_limit(8).set_unsigned(true).set_index(true);
+          });
+          table.add_column_integer("integer_b", |integer| {
+            integer.set_default(1).set_comment("my comment");
+          });
+        }).to_sql(crate::Dialect::Sqlite).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS \"users\" (
+\"id\" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+\"integer_a\" INTEGER,
+\"integer_b\" INTEGER DEFAULT 1
+);
+CREATE INDEX \"index_on_integer_a\" ON \"users\" (\"integer_a\");".to_owned());
+      }
+      // mssql
+      {
+        // create table
+        let sql: String = crate::SqlGen::create_table("users", |table| {
+          table.add_column_integer("id", |integer| {
+            integer.set_null(false).set_primary_key(true).set_auto_increment(true);
+          });
+          table.add_column_integer("integer_a", |integer| {
+            integer.set_limit(8).set_unsigned(true);
+          });
+          table.add_column_integer("integer_b", |integer| {
+            integer.set_bit_type(1).set_default(1);
+          });
+        }).to_sql(crate::Dialect::Mssql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "IF OBJECT_ID(N'users', N'U') IS NULL CREATE TABLE [users] (
+[id] INT NOT NULL IDENTITY(1,1),
+[integer_a] BIGINT,
+[integer_b] BIT DEFAULT 1,
+CONSTRAINT [pk_on_id] PRIMARY KEY ([id])
+);".to_owned());
+
+        // update table
+        let sql: String = crate::SqlGen::alter_table("users", |table| {
+          table.add_column_integer("integer_a", |_integer| {});
+          table.modify_column_integer("integer_c", |integer| {
+            integer.set_null(false).set_limit(8).set_default(0);
+          });
+          table.change_column_integer("is_a", "is_b", |_integer| {});
+          table.drop_column_integer("integer_d");
+        }).to_sql(crate::Dialect::Mssql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE [users] ADD [integer_a] INT;
+ALTER TABLE [users] ALTER COLUMN [integer_c] BIGINT NOT NULL;
+ALTER TABLE [users] ADD CONSTRAINT [df_users_integer_c] DEFAULT 0 FOR [integer_c];
+ALTER TABLE [users] ALTER COLUMN [is_a] INT;
+ALTER TABLE [users] DROP COLUMN [integer_d];
+EXEC sp_rename N'users.is_a', N'is_b', N'COLUMN';".to_owned());
+      }
+    }
+    #[test]
+    fn to_sql_with_dialect() {
+      // zerofill 只有 mysql 支持
+      let table = crate::SqlGen::create_table("users", |table| {
+        table.add_column_integer("integer_a", |integer| {
+          integer.set_unsigned(true).set_zerofill(true).set_default(1);
+        });
+      });
+      assert_eq!(table.to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap(), "CREATE TABLE IF NOT EXISTS `users` (
+`integer_a` INT UNSIGNED ZEROFILL DEFAULT 1
+);");
+      assert_eq!(table.to_sql(crate::Dialect::Postgresql).unwrap().to_sql_string().unwrap(), "CREATE TABLE IF NOT EXISTS \"users\" (
+\"integer_a\" INTEGER DEFAULT 1
+);");
+      assert_eq!(table.to_sql(crate::Dialect::Sqlite).unwrap().to_sql_string().unwrap(), "CREATE TABLE IF NOT EXISTS \"users\" (
+\"integer_a\" INTEGER DEFAULT 1
+);");
+      assert_eq!(table.to_sql(crate::Dialect::Mssql).unwrap().to_sql_string().unwrap(), "IF OBJECT_ID(N'users', N'U') IS NULL CREATE TABLE [users] (
+[integer_a] INT DEFAULT 1
+);");
+
+      let table = crate::SqlGen::create_table("users", |table| {
+        table.add_column_integer("integer_a", |integer| {
+          integer.set_comment("it's \\ ok");
+        });
+      });
+      assert_eq!(table.to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap(), "CREATE TABLE IF NOT EXISTS `users` (
+`integer_a` INT COMMENT 'it''s \\\\ ok'
+);");
+      assert_eq!(table.to_sql(crate::Dialect::Postgresql).unwrap().to_sql_string().unwrap(), "CREATE TABLE IF NOT EXISTS \"users\" (
+\"integer_a\" INTEGER
+);
+COMMENT ON COLUMN \"users\".\"integer_a\" IS E'it''s \\\\ ok';");
     }
-}
\ No newline at end of file
+    #[test]
+    fn try_to_sql() {
+      let table = crate::SqlGen::alter_table("users", |table| {
+        table.set_column_integer("age", crate::column::ColumnTypeAction::AddColumn { position: Some("AFTER id".to_owned()) }, |_| {});
+      });
+      assert_eq!(table.try_to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap(), "ALTER TABLE `users`\nADD COLUMN `age` INT AFTER id;");
+      assert_eq!(table.try_to_sql(crate::Dialect::Postgresql).unwrap_err(), crate::SqlError::DialectUnsupported { dialect: crate::Dialect::Postgresql, feature: "column position of age".to_owned() });
+
+      let table = crate::SqlGen::alter_table("users", |table| {
+        table.rename_column_integer("age", "a\u{1}ge");
+      });
+      assert!(matches!(table.try_to_sql(crate::Dialect::Mysql), Err(crate::SqlError::InvalidIdentifier { reason: "control character", .. })));
+    }
+}
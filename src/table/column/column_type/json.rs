@@ -1,7 +1,7 @@
 use crate::collectors::Sql;
 use crate::{column::column_type::{ColumnType, ColumnTypeable}};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Json {
   default: Option<String>,
   null: Option<bool>,
@@ -11,19 +11,6 @@ pub struct Json {
   primary_key: Option<bool>,
 }
 
-impl Default for Json {
-  fn default() -> Json {
-    Json {
-      default: None,
-      null: None,
-      comment: None,
-      index: None,
-      unique: None,
-      primary_key: None,
-    }
-  }
-}
-
 impl Json {
     pub fn set_default(&mut self, default: &str) -> &mut Self {
       self.default = Some(default.to_string());
@@ -35,8 +22,18 @@ impl ColumnTypeable for Json {
   fn null(&self) -> Option<bool> { self.null }
   fn set_null(&mut self, null: bool) -> &mut Self { self.null = Some(null); self }
 
+  fn comment(&self) -> Option<&'static str> { self.comment }
   fn set_comment(&mut self, comment: &'static str) -> &mut Self { self.comment = Some(comment); self }
 
+  fn default_value(&self, dialect: crate::Dialect) -> Result<Option<String>, crate::SqlError> {
+    self.default.as_ref().map(|default| {
+      match dialect {
+        crate::Dialect::Mysql => Ok(format!("({})", default)),
+        crate::Dialect::Postgresql | crate::Dialect::Sqlite | crate::Dialect::Mssql => dialect.quote_string(default),
+      }
+    }).transpose()
+  }
+
   fn index(&self) -> Option<bool> { self.index }
   fn set_index(&mut self, index: bool) -> &mut Self { self.index = Some(index); self }
 
@@ -46,24 +43,28 @@ impl ColumnTypeable for Json {
   fn primary_key(&self) -> Option<bool> { self.primary_key }
   fn set_primary_key(&mut self, primary_key: bool) -> &mut Self { self.primary_key = Some(primary_key); self }
 
-  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable) -> Option<Sql> {
+  fn to_type_sql(&self, _column: &crate::Column, _table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
     let mut sql = Sql::default();
-    sql.push_value(crate::GLOBAL_DB_KEY_MAPPING.get("json").unwrap());
+    sql.push_value(crate::const_data::db_key_mapping(dialect).get("json").unwrap());
+    Some(sql)
+  }
+
+  fn to_sql(&self, column: &crate::Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
+    let Some(mut sql) = self.to_type_sql(column, table, dialect) else { return Ok(None) };
     if let Some(null) = self.null {
       sql.push_value(&format!(" {}", if null { "NULL" } else { "NOT NULL" }));
     }
-    if let Some(ref default) = self.default {
-      sql.push_value(&format!(" DEFAULT ({})", default));
+    if let Some(default) = self.default_value(dialect)? {
+      sql.push_value(&format!(" DEFAULT {}", default));
     }
-    if let Some(primary_key) = self.primary_key {
-      if primary_key {
-        sql.push_value(&format!(" PRIMARY KEY"));
+    if let Some(primary_key) = self.primary_key
+      && primary_key {
+        sql.push_value(" PRIMARY KEY");
       }
+    if let Some(comment) = self.comment && dialect == crate::Dialect::Mysql {
+      sql.push_value(&format!(" COMMENT {}", dialect.quote_string(comment)?));
     }
-    if let Some(comment) = self.comment {
-      sql.push_value(&format!(" COMMENT '{}'", comment));
-    }
-    Some(sql)
+    Ok(Some(sql))
   }
 }
 
@@ -75,12 +76,11 @@ impl From<Json> for ColumnType {
 }
 
 #[cfg(test)]
-
 mod tests {
   use crate::prelude::*;
     #[test]
     fn to_sql() {
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         // create table
         let sql: String = crate::SqlGen::create_table("users", |table| {
@@ -88,10 +88,10 @@ mod tests {
           table.add_column_json("json_b", |json| {
             json.set_default("{}").set_comment("my comment");
           });
-        }).try_into().unwrap();
-        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS users (
-json_a JSON,
-json_b JSON DEFAULT ({}) COMMENT 'my comment'
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS `users` (
+`json_a` JSON,
+`json_b` JSON DEFAULT ({}) COMMENT 'my comment'
 );".to_owned());
 
         // update table
@@ -107,14 +107,41 @@ json_b JSON DEFAULT ({}) COMMENT 'my comment'
             json.set_comment("rename to is_b");
           });
           table.drop_column_json("json_d");
-        }).try_into().unwrap();
-        assert_eq!(sql, "ALTER TABLE users
-ADD COLUMN json_a JSON,
-ADD COLUMN json_b JSON DEFAULT ({}) COMMENT 'my comment',
-MODIFY COLUMN json_c JSON NOT NULL,
-CHANGE COLUMN is_a is_b JSON COMMENT 'rename to is_b',
-DROP COLUMN json_d;".to_owned());
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE `users`
+ADD COLUMN `json_a` JSON,
+ADD COLUMN `json_b` JSON DEFAULT ({}) COMMENT 'my comment',
+MODIFY COLUMN `json_c` JSON NOT NULL,
+CHANGE COLUMN `is_a` `is_b` JSON COMMENT 'rename to is_b',
+DROP COLUMN `json_d`;".to_owned());
+
+      }
+      // postgresql
+      {
+        // create table
+        let sql: String = crate::SqlGen::create_table("users", |table| {
+          table.add_column_json("json_a", |json| {
+            json.set_default(r#"{"name": "O'Brien"}"#).set_comment("my comment");
+          });
+        }).to_sql(crate::Dialect::Postgresql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS \"users\" (
+\"json_a\" JSONB DEFAULT '{\"name\": \"O''Brien\"}'
+);
+COMMENT ON COLUMN \"users\".\"json_a\" IS 'my comment';".to_owned());
 
+        // 默认值和注释中的 NUL 返回错误
+        let sql: Result<String, crate::SqlError> = crate::SqlGen::create_table("users", |table| {
+          table.add_column_json("json_a", |json| {
+            json.set_default("\"\0\"");
+          });
+        }).to_sql(crate::Dialect::Postgresql).and_then(|sql| sql.to_sql_string());
+        assert!(matches!(sql, Err(crate::SqlError::DialectUnsupported { dialect: crate::Dialect::Postgresql, .. })));
+        let sql: Result<String, crate::SqlError> = crate::SqlGen::alter_table("users", |table| {
+          table.add_column_json("json_a", |json| {
+            json.set_comment("a\0b");
+          });
+        }).to_sql(crate::Dialect::Postgresql).and_then(|sql| sql.to_sql_string());
+        assert!(matches!(sql, Err(crate::SqlError::DialectUnsupported { dialect: crate::Dialect::Postgresql, .. })));
       }
     }
 }
\ No newline at end of file
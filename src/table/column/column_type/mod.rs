@@ -77,126 +77,194 @@ pub enum ColumnType {
 }
 
 impl ColumnType {
-  pub fn to_sql(&self, column: &crate::Column, table: &crate::DefineTable) -> Option<Sql> {
+  pub fn to_sql(&self, column: &crate::Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
     match self {
       ColumnType::Boolean(boolean) => {
-        return boolean.to_sql(column, table)
+        boolean.to_sql(column, table, dialect)
       },
       ColumnType::Integer(integer) => {
-        return integer.to_sql(column, table)
+        integer.to_sql(column, table, dialect)
       },
       ColumnType::Float(float) => {
-        return float.to_sql(column, table)
+        float.to_sql(column, table, dialect)
       },
       ColumnType::Double(double) => {
-        return double.to_sql(column, table)
+        double.to_sql(column, table, dialect)
       },
       ColumnType::Decimal(decimal) => {
-        return decimal.to_sql(column, table)
+        decimal.to_sql(column, table, dialect)
       },
       ColumnType::String(string) => {
-        return string.to_sql(column, table)
+        string.to_sql(column, table, dialect)
       },
       ColumnType::Text(text) => {
-        return text.to_sql(column, table)
+        text.to_sql(column, table, dialect)
       },
       ColumnType::Time(time) => {
-        return time.to_sql(column, table)
+        time.to_sql(column, table, dialect)
       },
       ColumnType::Date(date) => {
-        return date.to_sql(column, table)
+        date.to_sql(column, table, dialect)
       },
       ColumnType::Datetime(datetime) => {
-        return datetime.to_sql(column, table)
+        datetime.to_sql(column, table, dialect)
       },
       ColumnType::Timestamp(timestamp) => {
-        return timestamp.to_sql(column, table)
+        timestamp.to_sql(column, table, dialect)
       },
       ColumnType::Json(json) => {
-        return json.to_sql(column, table)
+        json.to_sql(column, table, dialect)
       },
       ColumnType::Blob(blob) => {
-        return blob.to_sql(column, table)
+        blob.to_sql(column, table, dialect)
       },
       ColumnType::Binary(binary) => {
-        return binary.to_sql(column, table)
+        binary.to_sql(column, table, dialect)
       },
       ColumnType::Index(index) => {
-        return index.to_sql(column, table) // None
+        index.to_sql(column, table, dialect) // None
       },
       ColumnType::Unique(unique) => {
-        return unique.to_sql(column, table) // None
+        unique.to_sql(column, table, dialect) // None
       },
       ColumnType::PrimaryKey(primary_key) => {
-        return primary_key.to_sql(column, table) // None
+        primary_key.to_sql(column, table, dialect) // None
       },
       ColumnType::ForeignKey(foreign_key) => {
-        return foreign_key.to_sql(column, table) // None
+        foreign_key.to_sql(column, table, dialect) // None
       },
       // _ => None,
     }
   }
-  pub fn to_constraint_sql(&self, column: &Column, table: &crate::DefineTable) -> Option<Sql> {
+  pub fn to_constraint_sql(&self, column: &Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
     match self {
       ColumnType::Boolean(boolean) => {
-        return boolean.to_constraint_sql(column, table)
+        boolean.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Integer(integer) => {
-        return integer.to_constraint_sql(column, table)
+        integer.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Float(float) => {
-        return float.to_constraint_sql(column, table)
+        float.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Double(double) => {
-        return double.to_constraint_sql(column, table)
+        double.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Decimal(decimal) => {
-        return decimal.to_constraint_sql(column, table)
+        decimal.to_constraint_sql(column, table, dialect)
       },
       ColumnType::String(string) => {
-        return string.to_constraint_sql(column, table)
+        string.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Text(text) => {
-        return text.to_constraint_sql(column, table)
+        text.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Time(time) => {
-        return time.to_constraint_sql(column, table)
+        time.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Date(date) => {
-        return date.to_constraint_sql(column, table)
+        date.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Datetime(datetime) => {
-        return datetime.to_constraint_sql(column, table)
+        datetime.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Json(json) => {
-        return json.to_constraint_sql(column, table)
+        json.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Blob(blob) => {
-        return blob.to_constraint_sql(column, table)
+        blob.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Binary(binary) => {
-        return binary.to_constraint_sql(column, table)
+        binary.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Timestamp(timestamp) => {
-        return timestamp.to_constraint_sql(column, table)
+        timestamp.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Index(index) => {
-        return index.to_constraint_sql(column, table)
+        index.to_constraint_sql(column, table, dialect)
       },
       ColumnType::Unique(unique) => {
-        return unique.to_constraint_sql(column, table)
+        unique.to_constraint_sql(column, table, dialect)
       },
       ColumnType::PrimaryKey(primary_key) => {
-        return primary_key.to_constraint_sql(column, table)
+        primary_key.to_constraint_sql(column, table, dialect)
       },
       ColumnType::ForeignKey(foreign_key) => {
-        return foreign_key.to_constraint_sql(column, table)
+        foreign_key.to_constraint_sql(column, table, dialect)
       },
       // _ => None,
     }
   }
+  pub fn to_type_sql(&self, column: &Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
+    column_type_dispatch!(self, column_type => column_type.to_type_sql(column, table, dialect))
+  }
+  pub fn to_statement_sql(&self, column: &Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
+    column_type_dispatch!(self, column_type => column_type.to_statement_sql(column, table, dialect))
+  }
+  pub fn null(&self) -> Option<bool> {
+    column_type_dispatch!(self, column_type => column_type.null())
+  }
+  pub fn default_value(&self, dialect: crate::Dialect) -> Result<Option<String>, crate::SqlError> {
+    column_type_dispatch!(self, column_type => column_type.default_value(dialect))
+  }
+  pub fn comment(&self) -> Option<&'static str> {
+    column_type_dispatch!(self, column_type => column_type.comment())
+  }
+  pub fn index(&self) -> Option<bool> {
+    column_type_dispatch!(self, column_type => column_type.index())
+  }
+  pub fn set_index(&mut self, index: bool) {
+    column_type_dispatch!(self, column_type => { column_type.set_index(index); })
+  }
+  pub fn unique(&self) -> Option<bool> {
+    column_type_dispatch!(self, column_type => column_type.unique())
+  }
+  pub fn set_unique(&mut self, unique: bool) {
+    column_type_dispatch!(self, column_type => { column_type.set_unique(unique); })
+  }
+  pub fn primary_key(&self) -> Option<bool> {
+    column_type_dispatch!(self, column_type => column_type.primary_key())
+  }
+  pub fn set_primary_key(&mut self, primary_key: bool) {
+    column_type_dispatch!(self, column_type => { column_type.set_primary_key(primary_key); })
+  }
+  pub fn foreign_key(&self) -> Option<&crate::define_table::ForeignKey> {
+    column_type_dispatch!(self, column_type => column_type.foreign_key())
+  }
+  pub fn remove_foreign_key(&mut self) {
+    column_type_dispatch!(self, column_type => { column_type.remove_foreign_key(); })
+  }
+  pub fn auto_increment(&self) -> Option<bool> {
+    column_type_dispatch!(self, column_type => column_type.auto_increment())
+  }
 }
 
+macro_rules! column_type_dispatch {
+  ($value:expr, $column_type:ident => $call:expr) => {
+    match $value {
+      ColumnType::Boolean($column_type) => $call,
+      ColumnType::Integer($column_type) => $call,
+      ColumnType::Float($column_type) => $call,
+      ColumnType::Double($column_type) => $call,
+      ColumnType::Decimal($column_type) => $call,
+      ColumnType::String($column_type) => $call,
+      ColumnType::Text($column_type) => $call,
+      ColumnType::Time($column_type) => $call,
+      ColumnType::Date($column_type) => $call,
+      ColumnType::Datetime($column_type) => $call,
+      ColumnType::Timestamp($column_type) => $call,
+      ColumnType::Json($column_type) => $call,
+      ColumnType::Blob($column_type) => $call,
+      ColumnType::Binary($column_type) => $call,
+      ColumnType::Index($column_type) => $call,
+      ColumnType::Unique($column_type) => $call,
+      ColumnType::PrimaryKey($column_type) => $call,
+      ColumnType::ForeignKey($column_type) => $call,
+    }
+  };
+}
+use column_type_dispatch;
+
 pub trait ColumnTypeable {
   fn null(&self) -> Option<bool> { None }
   fn set_null(&mut self, _null: bool) -> &mut Self { self }
@@ -204,8 +272,11 @@ pub trait ColumnTypeable {
     self.set_null(!not_null)
   }
 
+  fn comment(&self) -> Option<&'static str> { None }
   fn set_comment(&mut self, _column_name: &'static str) -> &mut Self { self }
 
+  fn default_value(&self, _dialect: crate::Dialect) -> Result<Option<String>, crate::SqlError> { Ok(None) }
+
   fn index(&self) -> Option<bool> { None }
   fn set_index(&mut self, _index: bool) -> &mut Self { self }
 
@@ -217,24 +288,36 @@ pub trait ColumnTypeable {
 
   fn foreign_key(&self) -> Option<&crate::define_table::ForeignKey> { None }
   fn set_foreign_key(&mut self, _foreign_key: crate::define_table::ForeignKey) -> &mut Self { self }
+  fn remove_foreign_key(&mut self) -> &mut Self { self }
+
+  fn auto_increment(&self) -> Option<bool> { None }
 
-  fn to_sql(&self, column: &Column, table: &crate::DefineTable) -> Option<Sql>;
-  fn to_constraint_sql(&self, column: &Column, _table: &crate::DefineTable) -> Option<Sql> {
+  // 只包含类型, eg: VARCHAR(255)
+  fn to_type_sql(&self, _column: &Column, _table: &crate::DefineTable, _dialect: crate::Dialect) -> Option<Sql> { None }
+  fn to_sql(&self, column: &Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError>;
+  fn to_constraint_sql(&self, column: &Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
+    match dialect {
+      crate::Dialect::Postgresql => return postgresql_constraint_sql(self, column, table, dialect),
+      crate::Dialect::Sqlite => return sqlite_constraint_sql(self, column, dialect),
+      crate::Dialect::Mssql => return mssql_constraint_sql(self, column, dialect),
+      crate::Dialect::Mysql => (),
+    }
+    let column_name = dialect.quote_identifier(column.column_name());
+    let column_names = dialect.quote_identifiers(&column.column_names).join(",");
     let mut sql = Sql::default();
-    if let Some(primary_key) = self.primary_key() {
-      if primary_key {
-        sql.push_value(&format!("PRIMARY KEY pk_on_{}", column.column_name()));
+    if let Some(primary_key) = self.primary_key()
+      && primary_key {
+        sql.push_value(&format!("PRIMARY KEY {}", dialect.quote_identifier(&format!("pk_on_{}", column.column_name()))));
         match column.column_type_action {
           ColumnTypeAction::AddColumn {..} | ColumnTypeAction::AddConstraint => {
-            sql.push_value(&format!(" ({})", column.column_name()));
+            sql.push_value(&format!(" ({})", column_name));
           },
           ColumnTypeAction::DropConstraint => {
-            sql.push_value(&format!("DROP INDEX {}", column.column_name()));
+            sql.push_value(&format!("DROP INDEX {}", column_name));
           },
           _ => ()
         }
       }
-    }
     let mut index = false;
     let mut unique = false;
     if let Some(_unique) = self.unique() { unique = _unique; }
@@ -243,41 +326,41 @@ pub trait ColumnTypeable {
       if unique && index {
         match column.column_type_action {
           ColumnTypeAction::AddColumn {..} | ColumnTypeAction::AddConstraint => {
-            sql.push_value(&format!("UNIQUE INDEX unique_index_on_{}", column.column_names.join("_and_")));
-            sql.push_value(&format!(" ({})", column.column_names.join(",")));
+            sql.push_value(&format!("UNIQUE INDEX {}", dialect.quote_identifier(&index_name(unique, index, column))));
+            sql.push_value(&format!(" ({})", column_names));
           },
           ColumnTypeAction::RenameIndex { ref new_name } => {
-            sql.push_value(&format!("RENAME INDEX {} TO {}", column.column_name(), new_name));
+            sql.push_value(&format!("RENAME INDEX {} TO {}", column_name, dialect.quote_identifier(new_name)));
           },
           ColumnTypeAction::DropConstraint => {
-            sql.push_value(&format!("DROP INDEX {}", column.column_name()));
+            sql.push_value(&format!("DROP INDEX {}", column_name));
           },
           _ => ()
         }
       } else if unique && !index {
         match column.column_type_action {
           ColumnTypeAction::AddColumn {..} | ColumnTypeAction::AddConstraint => {
-            sql.push_value(&format!("UNIQUE unique_on_{}", column.column_names.join("_and_")));
-            sql.push_value(&format!(" ({})", column.column_names.join(",")));
+            sql.push_value(&format!("UNIQUE {}", dialect.quote_identifier(&index_name(unique, index, column))));
+            sql.push_value(&format!(" ({})", column_names));
           },
           ColumnTypeAction::RenameIndex { ref new_name } => {
-            sql.push_value(&format!("RENAME INDEX {} TO {}", column.column_name(), new_name));
+            sql.push_value(&format!("RENAME INDEX {} TO {}", column_name, dialect.quote_identifier(new_name)));
           },
            ColumnTypeAction::DropConstraint => {
-              sql.push_value(&format!("DROP INDEX {}", column.column_name()));
+              sql.push_value(&format!("DROP INDEX {}", column_name));
             },
           _ => ()
         }
       } else if !unique && index {
         match column.column_type_action {
           ColumnTypeAction::AddColumn {..} | ColumnTypeAction::AddConstraint => {
-            sql.push_value(&format!("INDEX index_on_{} ({})", column.column_names.join("_and_"), column.column_names.join(",")));
+            sql.push_value(&format!("INDEX {} ({})", dialect.quote_identifier(&index_name(unique, index, column)), column_names));
           },
           ColumnTypeAction::RenameIndex { ref new_name } => {
-            sql.push_value(&format!("RENAME INDEX {} TO {}", column.column_name(), new_name));
+            sql.push_value(&format!("RENAME INDEX {} TO {}", column_name, dialect.quote_identifier(new_name)));
           },
           ColumnTypeAction::DropConstraint => {
-            sql.push_value(&format!("DROP INDEX {}", column.column_name()));
+            sql.push_value(&format!("DROP INDEX {}", column_name));
           },
           _ => ()
         }
@@ -286,8 +369,196 @@ pub trait ColumnTypeable {
 
     if let Some(foreign_key) = self.foreign_key() {
       if !sql.is_empty() { sql.push_value(",\n"); }
-      sql.push_sql(&foreign_key.to_sql(&column));
+      sql.push_sql(&foreign_key.to_sql(column, dialect));
     }
     if sql.is_empty() { None } else { Some(sql) }
   }
-}
\ No newline at end of file
+  // 表语句之外单独执行的语句, eg: postgresql 的 CREATE INDEX
+  fn to_statement_sql(&self, column: &Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
+    match dialect {
+      crate::Dialect::Postgresql => postgresql_index_statement_sql(self, column, table, dialect),
+      crate::Dialect::Sqlite => sqlite_index_statement_sql(self, column, table, dialect),
+      crate::Dialect::Mssql => mssql_index_statement_sql(self, column, table, dialect),
+      crate::Dialect::Mysql => None,
+    }
+  }
+}
+
+// postgresql: https://www.postgresql.org/docs/current/ddl-constraints.html
+fn postgresql_constraint_sql<T: ColumnTypeable + ?Sized>(column_type: &T, column: &Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
+  let constraint_prefix = if table.is_alter() { "ADD CONSTRAINT" } else { "CONSTRAINT" };
+  let column_names = dialect.quote_identifiers(&column.column_names).join(",");
+  let mut sqls = vec![];
+  if let Some(primary_key) = column_type.primary_key() && primary_key {
+    let constraint_name = dialect.quote_identifier(&format!("pk_on_{}", column.column_name()));
+    match column.column_type_action {
+      ColumnTypeAction::AddColumn {..} | ColumnTypeAction::AddConstraint => {
+        sqls.push(Sql::new(format!("{} {} PRIMARY KEY ({})", constraint_prefix, constraint_name, dialect.quote_identifier(column.column_name()))));
+      },
+      ColumnTypeAction::DropConstraint => {
+        sqls.push(Sql::new(format!("DROP CONSTRAINT {}", constraint_name)));
+      },
+      _ => ()
+    }
+  }
+  // 带索引的唯一约束由 CREATE UNIQUE INDEX 生成
+  let unique = column_type.unique().unwrap_or(false);
+  let index = column_type.index().unwrap_or(false);
+  if unique && !index {
+    match column.column_type_action {
+      ColumnTypeAction::AddColumn {..} | ColumnTypeAction::AddConstraint => {
+        sqls.push(Sql::new(format!("{} {} UNIQUE ({})", constraint_prefix, dialect.quote_identifier(&index_name(unique, index, column)), column_names)));
+      },
+      ColumnTypeAction::DropConstraint => {
+        sqls.push(Sql::new(format!("DROP CONSTRAINT {}", dialect.quote_identifier(column.column_name()))));
+      },
+      _ => ()
+    }
+  }
+  if let Some(foreign_key) = column_type.foreign_key() {
+    match column.column_type_action {
+      ColumnTypeAction::DropConstraint => {
+        sqls.push(foreign_key.to_sql(column, dialect));
+      },
+      _ => {
+        let mut sql = Sql::new(if table.is_alter() { "ADD ".to_owned() } else { "".to_owned() });
+        sql.push_sql(&foreign_key.to_sql(column, dialect));
+        sqls.push(sql);
+      }
+    }
+  }
+  if sqls.is_empty() { return None }
+  let mut sql = Sql::default();
+  sql.push_sqls(&sqls, ",\n");
+  Some(sql)
+}
+
+fn postgresql_index_statement_sql<T: ColumnTypeable + ?Sized>(column_type: &T, column: &Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
+  let unique = column_type.unique().unwrap_or(false);
+  let index = column_type.index().unwrap_or(false);
+  if !index { return None }
+  match column.column_type_action {
+    ColumnTypeAction::AddColumn {..} | ColumnTypeAction::AddConstraint => {
+      let column_names = dialect.quote_identifiers(&column.column_names).join(",");
+      Some(Sql::new(format!("CREATE {}INDEX {} ON {} ({})", if unique { "UNIQUE " } else { "" }, dialect.quote_identifier(&index_name(unique, index, column)), dialect.quote_identifier(&table.name), column_names)))
+    },
+    ColumnTypeAction::RenameIndex { ref new_name } => {
+      Some(Sql::new(format!("ALTER INDEX {} RENAME TO {}", dialect.quote_identifier(column.column_name()), dialect.quote_identifier(new_name))))
+    },
+    ColumnTypeAction::DropConstraint => {
+      Some(Sql::new(format!("DROP INDEX {}", dialect.quote_identifier(column.column_name()))))
+    },
+    _ => None
+  }
+}
+
+// sqlite: https://www.sqlite.org/lang_createtable.html
+// 只用于 CREATE TABLE, ALTER TABLE 不支持修改约束, 需要重建表
+fn sqlite_constraint_sql<T: ColumnTypeable + ?Sized>(column_type: &T, column: &Column, dialect: crate::Dialect) -> Option<Sql> {
+  match column.column_type_action {
+    ColumnTypeAction::AddColumn {..} | ColumnTypeAction::AddConstraint => (),
+    _ => return None
+  }
+  let mut sqls = vec![];
+  // INTEGER PRIMARY KEY AUTOINCREMENT 已经写在列定义中
+  if let Some(primary_key) = column_type.primary_key() && primary_key && column_type.auto_increment() != Some(true) {
+    let column_name = dialect.quote_identifier(column.column_name());
+    sqls.push(Sql::new(format!("CONSTRAINT {} PRIMARY KEY ({})", dialect.quote_identifier(&format!("pk_on_{}", column.column_name())), column_name)));
+  }
+  if let Some(foreign_key) = column_type.foreign_key() {
+    sqls.push(foreign_key.to_sql(column, dialect));
+  }
+  if sqls.is_empty() { return None }
+  let mut sql = Sql::default();
+  sql.push_sqls(&sqls, ",\n");
+  Some(sql)
+}
+
+// sqlite: 唯一约束也使用 CREATE UNIQUE INDEX
+fn sqlite_index_statement_sql<T: ColumnTypeable + ?Sized>(column_type: &T, column: &Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
+  let unique = column_type.unique().unwrap_or(false);
+  let index = column_type.index().unwrap_or(false);
+  if !unique && !index { return None }
+  match column.column_type_action {
+    ColumnTypeAction::AddColumn {..} | ColumnTypeAction::AddConstraint => {
+      let column_names = dialect.quote_identifiers(&column.column_names).join(",");
+      Some(Sql::new(format!("CREATE {}INDEX {} ON {} ({})", if unique { "UNIQUE " } else { "" }, table.sqlite_index_name(&index_name(unique, index, column), dialect), dialect.quote_identifier(table.schema_and_table_name().1), column_names)))
+    },
+    ColumnTypeAction::DropConstraint => {
+      Some(Sql::new(format!("DROP INDEX {}", table.sqlite_index_name(column.column_name(), dialect))))
+    },
+    // RenameIndex 需要原表结构, 由 DefineTable 生成
+    _ => None
+  }
+}
+
+// mssql: https://learn.microsoft.com/en-us/sql/t-sql/statements/alter-table-table-constraint-transact-sql
+// ALTER TABLE 时由 DefineTable 加上 ADD
+fn mssql_constraint_sql<T: ColumnTypeable + ?Sized>(column_type: &T, column: &Column, dialect: crate::Dialect) -> Option<Sql> {
+  let column_names = dialect.quote_identifiers(&column.column_names).join(",");
+  let mut sqls = vec![];
+  if let Some(primary_key) = column_type.primary_key() && primary_key {
+    let constraint_name = dialect.quote_identifier(&format!("pk_on_{}", column.column_name()));
+    match column.column_type_action {
+      ColumnTypeAction::AddColumn {..} | ColumnTypeAction::AddConstraint => {
+        sqls.push(Sql::new(format!("CONSTRAINT {} PRIMARY KEY ({})", constraint_name, column_names)));
+      },
+      ColumnTypeAction::DropConstraint => {
+        sqls.push(Sql::new(format!("DROP CONSTRAINT {}", constraint_name)));
+      },
+      _ => ()
+    }
+  }
+  // 带索引的唯一约束由 CREATE UNIQUE INDEX 生成
+  let unique = column_type.unique().unwrap_or(false);
+  let index = column_type.index().unwrap_or(false);
+  if unique && !index {
+    match column.column_type_action {
+      ColumnTypeAction::AddColumn {..} | ColumnTypeAction::AddConstraint => {
+        sqls.push(Sql::new(format!("CONSTRAINT {} UNIQUE ({})", dialect.quote_identifier(&index_name(unique, index, column)), column_names)));
+      },
+      ColumnTypeAction::DropConstraint => {
+        sqls.push(Sql::new(format!("DROP CONSTRAINT {}", dialect.quote_identifier(column.column_name()))));
+      },
+      _ => ()
+    }
+  }
+  if let Some(foreign_key) = column_type.foreign_key() {
+    match column.column_type_action {
+      ColumnTypeAction::AddColumn {..} | ColumnTypeAction::AddConstraint | ColumnTypeAction::DropConstraint => {
+        sqls.push(foreign_key.to_sql(column, dialect));
+      },
+      _ => ()
+    }
+  }
+  if sqls.is_empty() { return None }
+  let mut sql = Sql::default();
+  sql.push_sqls(&sqls, ",\n");
+  Some(sql)
+}
+
+fn mssql_index_statement_sql<T: ColumnTypeable + ?Sized>(column_type: &T, column: &Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
+  let unique = column_type.unique().unwrap_or(false);
+  let index = column_type.index().unwrap_or(false);
+  if !index { return None }
+  match column.column_type_action {
+    ColumnTypeAction::AddColumn {..} | ColumnTypeAction::AddConstraint => {
+      let column_names = dialect.quote_identifiers(&column.column_names).join(",");
+      Some(Sql::new(format!("CREATE {}INDEX {} ON {} ({})", if unique { "UNIQUE " } else { "" }, dialect.quote_identifier(&index_name(unique, index, column)), dialect.quote_identifier(&table.name), column_names)))
+    },
+    // mssql: 没有 RENAME INDEX, 使用 sp_rename
+    ColumnTypeAction::RenameIndex { ref new_name } => {
+      Some(Sql::new(format!("EXEC sp_rename N'{}.{}', N'{}', N'INDEX'", table.name.replace('\'', "''"), column.column_name().replace('\'', "''"), new_name.replace('\'', "''"))))
+    },
+    ColumnTypeAction::DropConstraint => {
+      Some(Sql::new(format!("DROP INDEX {} ON {}", dialect.quote_identifier(column.column_name()), dialect.quote_identifier(&table.name))))
+    },
+    _ => None
+  }
+}
+
+// eg: unique_index_on_a_and_b, unique_on_a, index_on_a
+pub(crate) fn index_name(unique: bool, index: bool, column: &Column) -> String {
+  let prefix = if unique && index { "unique_index_on" } else if unique { "unique_on" } else { "index_on" };
+  format!("{}_{}", prefix, column.column_names.join("_and_"))
+}
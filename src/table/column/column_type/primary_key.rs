@@ -54,8 +54,8 @@ impl ColumnTypeable for PrimaryKey {
   //   self
   // }
 
-  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable) -> Option<Sql> {
-    None
+  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable, _dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
+    Ok(None)
   }
 }
 
@@ -67,20 +67,28 @@ impl From<PrimaryKey> for ColumnType {
 
 
 #[cfg(test)]
-
 mod tests {
   // use crate::prelude::*;
     #[test]
     fn to_sql() {
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         // update table
         let sql: String = crate::SqlGen::alter_table("users", |table| {
           table.add_primary_key("id", |_primary_key| {});
-        }).try_into().unwrap();
-        assert_eq!(sql, "ALTER TABLE users
-ADD CONSTRAINT PRIMARY KEY pk_on_id (id);".to_owned());
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE `users`
+ADD CONSTRAINT PRIMARY KEY `pk_on_id` (`id`);".to_owned());
 
       }
+      // postgresql
+      {
+        // update table
+        let sql: String = crate::SqlGen::alter_table("users", |table| {
+          table.add_primary_key("id", |_primary_key| {});
+        }).to_sql(crate::Dialect::Postgresql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE \"users\"
+ADD CONSTRAINT \"pk_on_id\" PRIMARY KEY (\"id\");".to_owned());
+      }
     }
 }
\ No newline at end of file
@@ -52,11 +52,14 @@ impl ColumnTypeable for ColumnString {
     self
   }
 
+  fn comment(&self) -> Option<&'static str> { self.comment }
   fn set_comment(&mut self, comment: &'static str) -> &mut Self {
     self.comment = Some(comment);
     self
   }
 
+  fn default_value(&self, _dialect: crate::Dialect) -> Result<Option<String>, crate::SqlError> { Ok(self.default.clone()) }
+
   fn index(&self) -> Option<bool> { self.index }
   fn set_index(&mut self, index: bool) -> &mut Self { self.index = Some(index); self }
 
@@ -69,32 +72,40 @@ impl ColumnTypeable for ColumnString {
     self
   }
 
-  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable) -> Option<Sql> {
-    let mut sql = Sql::default();
-
-    let mut r#type = format!("{}({})", crate::const_data::GLOBAL_DB_KEY_MAPPING.get("string").unwrap(), self.length.unwrap());
-    if let Some(is_char) = self.is_char {
-      if is_char {
-        r#type = format!("CHAR({})", self.length.unwrap());
-      }
+  fn to_type_sql(&self, _column: &crate::Column, _table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
+    // sqlite: 不限制长度
+    if dialect == crate::Dialect::Sqlite {
+      return Some(Sql::new(crate::const_data::db_key_mapping(dialect).get("string").unwrap().to_owned()));
     }
-    sql.push_value(&r#type);
+    let mut r#type = format!("{}({})", crate::const_data::db_key_mapping(dialect).get("string").unwrap(), self.length.unwrap());
+    // mssql: NVARCHAR 最大 4000, 超出使用 NVARCHAR(MAX)
+    if dialect == crate::Dialect::Mssql && self.length.unwrap() > 4000 {
+      r#type = format!("{}(MAX)", crate::const_data::db_key_mapping(dialect).get("string").unwrap());
+    }
+    if let Some(is_char) = self.is_char
+      && is_char {
+        r#type = format!("{}({})", if dialect == crate::Dialect::Mssql { "NCHAR" } else { "CHAR" }, self.length.unwrap());
+      }
+    Some(Sql::new(r#type))
+  }
+
+  fn to_sql(&self, column: &crate::Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
+    let Some(mut sql) = self.to_type_sql(column, table, dialect) else { return Ok(None) };
 
     if let Some(null) = self.null {
       sql.push_value(&format!(" {}", if null { "NULL" } else { "NOT NULL" }));
     }
-    if let Some(default) = &self.default {
+    if let Some(default) = self.default_value(dialect)? {
       sql.push_value(&format!(" DEFAULT {}", default));
     }
-    if let Some(primary_key) = self.primary_key {
-      if primary_key {
-        sql.push_value(&format!(" PRIMARY KEY"));
+    if let Some(primary_key) = self.primary_key
+      && primary_key {
+        sql.push_value(" PRIMARY KEY");
       }
+    if let Some(comment) = self.comment && dialect == crate::Dialect::Mysql {
+      sql.push_value(&format!(" COMMENT {}", dialect.quote_string(comment)?));
     }
-    if let Some(comment) = self.comment {
-      sql.push_value(&format!(" COMMENT '{}'", comment));
-    }
-    Some(sql)
+    Ok(Some(sql))
   }
 }
 
@@ -106,12 +117,11 @@ impl From<ColumnString> for ColumnType {
 }
 
 #[cfg(test)]
-
 mod tests {
   use crate::prelude::*;
     #[test]
     fn to_sql() {
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         // create table
         let sql: String = crate::SqlGen::create_table("users", |table| {
@@ -119,10 +129,10 @@ mod tests {
           table.add_column_string("string_b", |string| {
             string.set_default("1").set_comment("my comment");
           });
-        }).try_into().unwrap();
-        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS users (
-string_a VARCHAR(255),
-string_b VARCHAR(255) DEFAULT 1 COMMENT 'my comment'
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS `users` (
+`string_a` VARCHAR(255),
+`string_b` VARCHAR(255) DEFAULT 1 COMMENT 'my comment'
 );".to_owned());
 
         // update table
@@ -138,14 +148,32 @@ string_b VARCHAR(255) DEFAULT 1 COMMENT 'my comment'
             string.set_comment("rename to is_b");
           });
           table.drop_column_string("string_d");
-        }).try_into().unwrap();
-        assert_eq!(sql, "ALTER TABLE users
-ADD COLUMN string_a VARCHAR(255),
-ADD COLUMN string_b VARCHAR(255) DEFAULT 1 COMMENT 'my comment',
-MODIFY COLUMN string_c VARCHAR(255) NOT NULL,
-CHANGE COLUMN is_a is_b VARCHAR(255) COMMENT 'rename to is_b',
-DROP COLUMN string_d;".to_owned());
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE `users`
+ADD COLUMN `string_a` VARCHAR(255),
+ADD COLUMN `string_b` VARCHAR(255) DEFAULT 1 COMMENT 'my comment',
+MODIFY COLUMN `string_c` VARCHAR(255) NOT NULL,
+CHANGE COLUMN `is_a` `is_b` VARCHAR(255) COMMENT 'rename to is_b',
+DROP COLUMN `string_d`;".to_owned());
 
       }
+      // mssql
+      {
+        // create table
+        let sql: String = crate::SqlGen::create_table("users", |table| {
+          table.add_column_string("string_a", |_string| {});
+          table.add_column_string("string_b", |string| {
+            string.set_length(8000);
+          });
+          table.add_column_string("string_c", |string| {
+            string.set_char_type(10).set_default("'a'");
+          });
+        }).to_sql(crate::Dialect::Mssql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "IF OBJECT_ID(N'users', N'U') IS NULL CREATE TABLE [users] (
+[string_a] NVARCHAR(255),
+[string_b] NVARCHAR(MAX),
+[string_c] NCHAR(10) DEFAULT 'a'
+);".to_owned());
+      }
     }
-}
\ No newline at end of file
+}
@@ -4,7 +4,7 @@
 use crate::collectors::Sql;
 use crate::{column::column_type::{ColumnType, ColumnTypeable}};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Time {
   // default: Option<chrono::NaiveTime>,
   default: Option<String>,
@@ -15,19 +15,6 @@ pub struct Time {
   primary_key: Option<bool>,
 }
 
-impl Default for Time {
-  fn default() -> Time {
-    Time {
-      default: None,
-      null: None,
-      comment: None,
-      index: None,
-      unique: None,
-      primary_key: None,
-    }
-  }
-}
-
 impl Time {
   pub fn set_default(&mut self, default: &str) -> &mut Self {
     self.default = Some(default.to_owned());
@@ -42,11 +29,14 @@ impl ColumnTypeable for Time {
     self
   }
 
+  fn comment(&self) -> Option<&'static str> { self.comment }
   fn set_comment(&mut self, comment: &'static str) -> &mut Self {
     self.comment = Some(comment);
     self
   }
 
+  fn default_value(&self, _dialect: crate::Dialect) -> Result<Option<String>, crate::SqlError> { Ok(self.default.clone()) }
+
   fn index(&self) -> Option<bool> { self.index }
   fn set_index(&mut self, index: bool) -> &mut Self { self.index = Some(index); self }
 
@@ -59,25 +49,29 @@ impl ColumnTypeable for Time {
     self
   }
 
-  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable) -> Option<Sql> {
+  fn to_type_sql(&self, _column: &crate::Column, _table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
     let mut sql = Sql::default();
-    let r#type = crate::const_data::GLOBAL_DB_KEY_MAPPING.get("time").unwrap();
+    let r#type = crate::const_data::db_key_mapping(dialect).get("time").unwrap();
     sql.push_value(r#type);
+    Some(sql)
+  }
+
+  fn to_sql(&self, column: &crate::Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
+    let Some(mut sql) = self.to_type_sql(column, table, dialect) else { return Ok(None) };
     if let Some(null) = self.null {
       sql.push_value(&format!(" {}", if null { "NULL" } else { "NOT NULL" }));
     }
-    if let Some(ref default) = self.default {
+    if let Some(default) = self.default_value(dialect)? {
       sql.push_value(&format!(" DEFAULT {}", default));
     }
-    if let Some(primary_key) = self.primary_key {
-      if primary_key {
-        sql.push_value(&format!(" PRIMARY KEY"));
+    if let Some(primary_key) = self.primary_key
+      && primary_key {
+        sql.push_value(" PRIMARY KEY");
       }
+    if let Some(comment) = self.comment && dialect == crate::Dialect::Mysql {
+      sql.push_value(&format!(" COMMENT {}", dialect.quote_string(comment)?));
     }
-    if let Some(comment) = self.comment {
-      sql.push_value(&format!(" COMMENT '{}'", comment));
-    }
-    Some(sql)
+    Ok(Some(sql))
   }
 }
 
@@ -88,12 +82,11 @@ impl From<Time> for ColumnType {
 }
 
 #[cfg(test)]
-
 mod tests {
   use crate::prelude::*;
     #[test]
     fn to_sql() {
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         // create table
         let sql: String = crate::SqlGen::create_table("users", |table| {
@@ -101,10 +94,10 @@ mod tests {
           table.add_column_time("time_at_b", |time| {
             time.set_default("00:00:00").set_comment("my comment");
           });
-        }).try_into().unwrap();
-        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS users (
-time_at_a TIME,
-time_at_b TIME DEFAULT 00:00:00 COMMENT 'my comment'
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS `users` (
+`time_at_a` TIME,
+`time_at_b` TIME DEFAULT 00:00:00 COMMENT 'my comment'
 );".to_owned());
 
         // update table
@@ -120,13 +113,13 @@ time_at_b TIME DEFAULT 00:00:00 COMMENT 'my comment'
             time.set_comment("rename to is_b");
           });
           table.drop_column_time("time_at_c");
-        }).try_into().unwrap();
-        assert_eq!(sql, "ALTER TABLE users
-ADD COLUMN time_at_a TIME,
-ADD COLUMN time_at_b TIME DEFAULT 00:00:00 COMMENT 'my comment',
-MODIFY COLUMN  TIME NOT NULL,
-CHANGE COLUMN is_a is_b TIME COMMENT 'rename to is_b',
-DROP COLUMN time_at_c;".to_owned());
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE `users`
+ADD COLUMN `time_at_a` TIME,
+ADD COLUMN `time_at_b` TIME DEFAULT 00:00:00 COMMENT 'my comment',
+MODIFY COLUMN `` TIME NOT NULL,
+CHANGE COLUMN `is_a` `is_b` TIME COMMENT 'rename to is_b',
+DROP COLUMN `time_at_c`;".to_owned());
 
       }
     }
@@ -4,7 +4,7 @@
 use crate::collectors::Sql;
 use crate::{column::column_type::{ColumnType, ColumnTypeable}};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Timestamp {
   // default_utc: Option<chrono::DateTime<chrono::Utc>>,
   // default_local: Option<chrono::DateTime<chrono::Local>>,
@@ -18,21 +18,6 @@ pub struct Timestamp {
   on_update_current_timestamp: Option<bool>,
 }
 
-impl Default for Timestamp {
-  fn default() -> Timestamp {
-    Timestamp {
-      default: None,
-      null: None,
-      comment: None,
-      index: None,
-      unique: None,
-      primary_key: None,
-      on_create_current_timestamp: None,
-      on_update_current_timestamp: None,
-    }
-  }
-}
-
 impl Timestamp {
   pub fn set_default(&mut self, default: &str) -> &mut Self {
     self.default = Some(default.to_owned());
@@ -59,11 +44,20 @@ impl ColumnTypeable for Timestamp {
     self
   }
 
+  fn comment(&self) -> Option<&'static str> { self.comment }
   fn set_comment(&mut self, comment: &'static str) -> &mut Self {
     self.comment = Some(comment);
     self
   }
 
+  fn default_value(&self, _dialect: crate::Dialect) -> Result<Option<String>, crate::SqlError> {
+    if let Some(on_create_current_timestamp) = self.on_create_current_timestamp {
+      Ok(if on_create_current_timestamp { Some("CURRENT_TIMESTAMP".to_owned()) } else { None })
+    } else {
+      Ok(self.default.clone())
+    }
+  }
+
   fn index(&self) -> Option<bool> { self.index }
   fn set_index(&mut self, index: bool) -> &mut Self { self.index = Some(index); self }
 
@@ -76,10 +70,15 @@ impl ColumnTypeable for Timestamp {
     self
   }
 
-  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable) -> Option<Sql> {
+  fn to_type_sql(&self, _column: &crate::Column, _table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
     let mut sql = Sql::default();
-    let r#type = crate::const_data::GLOBAL_DB_KEY_MAPPING.get("timestamp").unwrap();
+    let r#type = crate::const_data::db_key_mapping(dialect).get("timestamp").unwrap();
     sql.push_value(r#type);
+    Some(sql)
+  }
+
+  fn to_sql(&self, column: &crate::Column, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
+    let Some(mut sql) = self.to_type_sql(column, table, dialect) else { return Ok(None) };
     if let Some(null) = self.null {
       sql.push_value(&format!(" {}", if null { "NULL" } else { "NOT NULL" }));
     }
@@ -88,28 +87,23 @@ impl ColumnTypeable for Timestamp {
     // } else if let Some(default) = self.default_local {
     //   sql.push_value(&format!(" DEFAULT {}", default));
     // }
-    if let Some(on_create_current_timestamp) = self.on_create_current_timestamp {
-      if on_create_current_timestamp {
-        sql.push_value(&format!(" DEFAULT {}", "CURRENT_TIMESTAMP"));
-      }
-    } else if let Some(ref default) = self.default {
+    if let Some(default) = self.default_value(dialect)? {
       sql.push_value(&format!(" DEFAULT {}", default));
     }
 
-    if let Some(primary_key) = self.primary_key {
-      if primary_key {
-        sql.push_value(&format!(" PRIMARY KEY"));
+    if let Some(primary_key) = self.primary_key
+      && primary_key {
+        sql.push_value(" PRIMARY KEY");
       }
-    }
-    if let Some(on_update_current_timestamp) = self.on_update_current_timestamp {
-      if on_update_current_timestamp {
-        sql.push_value(&format!(" ON UPDATE CURRENT_TIMESTAMP"));
+    // postgresql: 没有 ON UPDATE, 需要触发器实现
+    if let Some(on_update_current_timestamp) = self.on_update_current_timestamp && dialect == crate::Dialect::Mysql
+      && on_update_current_timestamp {
+        sql.push_value(" ON UPDATE CURRENT_TIMESTAMP");
       }
+    if let Some(comment) = self.comment && dialect == crate::Dialect::Mysql {
+      sql.push_value(&format!(" COMMENT {}", dialect.quote_string(comment)?));
     }
-    if let Some(comment) = self.comment {
-      sql.push_value(&format!(" COMMENT '{}'", comment));
-    }
-    Some(sql)
+    Ok(Some(sql))
   }
 }
 
@@ -121,13 +115,12 @@ impl From<Timestamp> for ColumnType {
 
 
 #[cfg(test)]
-
 mod tests {
   use crate::prelude::*;
   // use chrono::TimeZone;
   #[test]
   fn to_sql() {
-    #[cfg(feature = "mysql")]
+    // mysql
     {
       // create table
       let sql: String = crate::SqlGen::create_table("users", |table| {
@@ -141,12 +134,12 @@ mod tests {
         table.add_column_timestamp("timestamp_at_d", |timestamp| {
           timestamp.set_on_create_current_timestamp(true).set_on_update_current_timestamp(true);
         });
-      }).try_into().unwrap();
-      assert_eq!(sql, "CREATE TABLE IF NOT EXISTS users (
-timestamp_at_a TIMESTAMP,
-timestamp_at_b TIMESTAMP DEFAULT 2022-01-01 00:00:00 +08:00 COMMENT 'my comment',
-timestamp_at_c TIMESTAMP DEFAULT 2022-01-01 00:00:00 UTC,
-timestamp_at_d TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+      }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+      assert_eq!(sql, "CREATE TABLE IF NOT EXISTS `users` (
+`timestamp_at_a` TIMESTAMP,
+`timestamp_at_b` TIMESTAMP DEFAULT 2022-01-01 00:00:00 +08:00 COMMENT 'my comment',
+`timestamp_at_c` TIMESTAMP DEFAULT 2022-01-01 00:00:00 UTC,
+`timestamp_at_d` TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
 );".to_owned());
 
       // update table
@@ -162,13 +155,13 @@ timestamp_at_d TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
           timestamp.set_comment("rename to is_b");
         });
         table.drop_column_timestamp("timestamp_at_c");
-      }).try_into().unwrap();
-      assert_eq!(sql, "ALTER TABLE users
-ADD COLUMN timestamp_at_a TIMESTAMP,
-ADD COLUMN timestamp_at_b TIMESTAMP DEFAULT 2022-01-01 00:00:00 +08:00 COMMENT 'my comment',
-MODIFY COLUMN  TIMESTAMP NOT NULL,
-CHANGE COLUMN is_a is_b TIMESTAMP COMMENT 'rename to is_b',
-DROP COLUMN timestamp_at_c;".to_owned());
+      }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+      assert_eq!(sql, "ALTER TABLE `users`
+ADD COLUMN `timestamp_at_a` TIMESTAMP,
+ADD COLUMN `timestamp_at_b` TIMESTAMP DEFAULT 2022-01-01 00:00:00 +08:00 COMMENT 'my comment',
+MODIFY COLUMN `` TIMESTAMP NOT NULL,
+CHANGE COLUMN `is_a` `is_b` TIMESTAMP COMMENT 'rename to is_b',
+DROP COLUMN `timestamp_at_c`;".to_owned());
 
     }
   }
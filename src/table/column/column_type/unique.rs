@@ -56,8 +56,8 @@ impl ColumnTypeable for Unique {
   //   self
   // }
 
-  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable) -> Option<Sql> {
-    None
+  fn to_sql(&self, _column: &crate::Column, _table: &crate::DefineTable, _dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
+    Ok(None)
   }
 }
 
@@ -69,23 +69,22 @@ impl From<Unique> for ColumnType {
 
 
 #[cfg(test)]
-
 mod tests {
     use crate::ColumnTypeable;
 
   // use crate::prelude::*;
     #[test]
     fn to_sql() {
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         // update table
         let sql: String = crate::SqlGen::alter_table("users", |table| {
           table.add_unique(vec!["user_id", "order_id"], |unique| {
             unique.set_index(true);
           });
-        }).try_into().unwrap();
-        assert_eq!(sql, "ALTER TABLE users
-ADD CONSTRAINT UNIQUE INDEX unique_index_on_user_id_and_order_id (user_id,order_id);".to_owned());
+        }).to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap();
+        assert_eq!(sql, "ALTER TABLE `users`
+ADD CONSTRAINT UNIQUE INDEX `unique_index_on_user_id_and_order_id` (`user_id`,`order_id`);".to_owned());
 
       }
     }
@@ -24,60 +24,167 @@ impl Column {
     }
   }
   pub fn column_name(&self) -> &str {
-    self.column_names.get(0).unwrap()
+    self.column_names.first().unwrap()
   }
-  pub fn to_sql(&self, table: &crate::DefineTable) -> Option<Sql> {
+  pub fn validate(&self, dialect: crate::Dialect) -> Result<(), crate::SqlError> {
+    for column_name in self.column_names.iter() {
+      dialect.validate_identifier(column_name)?;
+    }
+    match &self.column_type_action {
+      ColumnTypeAction::ChangeColumn { new_name, .. } | ColumnTypeAction::RenameColumn { new_name, .. } | ColumnTypeAction::RenameIndex { new_name } => {
+        dialect.validate_identifier(new_name)?;
+      },
+      _ => (),
+    }
+    // mysql: FIRST | AFTER column_name
+    match &self.column_type_action {
+      ColumnTypeAction::AddColumn { position: Some(_) } | ColumnTypeAction::ModifyColumn { position: Some(_) } | ColumnTypeAction::ChangeColumn { position: Some(_), .. } | ColumnTypeAction::RenameColumn { position: Some(_), .. } if dialect != crate::Dialect::Mysql => {
+        Err(crate::SqlError::DialectUnsupported { dialect, feature: format!("column position of {}", self.column_name()) })
+      },
+      _ => Ok(()),
+    }
+  }
+  pub fn to_sql(&self, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Option<Sql>, crate::SqlError> {
     let mut final_ret = None;
     match &self.column_type_action {
       ColumnTypeAction::AddColumn { position } | ColumnTypeAction::ModifyColumn { position } => {
-        if let Some(type_sql) = self.column_type.to_sql(self, table) {
-          let mut sql = Sql::new(format!("{}", self.column_name()));
+        if let Some(type_sql) = self.column_type.to_sql(self, table, dialect)? {
+          let mut sql = Sql::new(dialect.quote_identifier(self.column_name()));
           sql.push(' ').push_sql(&type_sql);
           if let Some(position) = position {
-            sql.push(' ').push_value(&position);
+            sql.push(' ').push_value(position);
           }
           final_ret =Some(sql)
         }
       },
       ColumnTypeAction::ChangeColumn { new_name, position, } => {
-        if let Some(type_sql) = self.column_type.to_sql(self, table) {
-          let mut sql = Sql::new(format!("{}", self.column_name()));
-          sql.push_value(&format!(" {}", new_name));
+        if let Some(type_sql) = self.column_type.to_sql(self, table, dialect)? {
+          let mut sql = Sql::new(dialect.quote_identifier(self.column_name()));
+          sql.push_value(&format!(" {}", dialect.quote_identifier(new_name)));
           sql.push(' ').push_sql(&type_sql);
           if let Some(position) = position {
-            sql.push(' ').push_value(&position);
+            sql.push(' ').push_value(position);
           }
           final_ret =Some(sql)
         }
       },
       ColumnTypeAction::RenameColumn { new_name, position, } => {
-        let mut sql = Sql::new(format!("{}", self.column_name()));
-        sql.push_value(&format!(" TO {}", new_name));
+        let mut sql = Sql::new(dialect.quote_identifier(self.column_name()));
+        sql.push_value(&format!(" TO {}", dialect.quote_identifier(new_name)));
         if let Some(position) = position {
-          sql.push(' ').push_value(&position);
+          sql.push(' ').push_value(position);
         }
         final_ret =Some(sql)
       },
       ColumnTypeAction::AddConstraint => {
-        if let Some(type_sql) = self.column_type.to_sql(self, table) {
-          let mut sql = Sql::new(format!("{}", self.column_name()));
+        if let Some(type_sql) = self.column_type.to_sql(self, table, dialect)? {
+          let mut sql = Sql::new(dialect.quote_identifier(self.column_name()));
           sql.push(' ').push_sql(&type_sql);
           final_ret =Some(sql)
         }
       },
       ColumnTypeAction::DropColumn | ColumnTypeAction::DropConstraint => {
-        let sql = Sql::new(format!("{}", self.column_name()));
+        let sql = Sql::new(dialect.quote_identifier(self.column_name()));
         final_ret =Some(sql)
       },
       ColumnTypeAction::RenameIndex { new_name, } => {
-        let mut sql = Sql::new(format!("{}", self.column_name()));
-        sql.push_value(&format!(" TO {}", new_name));
+        let mut sql = Sql::new(dialect.quote_identifier(self.column_name()));
+        sql.push_value(&format!(" TO {}", dialect.quote_identifier(new_name)));
         final_ret =Some(sql)
       },
     }
-    final_ret
+    Ok(final_ret)
   }
-  pub fn to_constraint_sql(&self, table: &crate::DefineTable) -> Option<Sql> {
-    self.column_type.to_constraint_sql(self, table)
+  pub fn column_type(&self) -> &ColumnType {
+    &self.column_type
   }
-}
\ No newline at end of file
+  // Index, Unique, PrimaryKey, ForeignKey
+  pub fn is_constraint(&self) -> bool {
+    matches!(self.column_type, ColumnType::Index(_) | ColumnType::Unique(_) | ColumnType::PrimaryKey(_) | ColumnType::ForeignKey(_))
+  }
+  pub fn index_name(&self) -> Option<String> {
+    let unique = self.column_type.unique().unwrap_or(false);
+    let index = self.column_type.index().unwrap_or(false);
+    if unique || index { Some(column_type::index_name(unique, index, self)) } else { None }
+  }
+  // MODIFY COLUMN 时保留原列的索引
+  pub(crate) fn inherit_index(&mut self, origin_column: &Column) {
+    if self.column_type.index().is_none() && origin_column.column_type.index() == Some(true) {
+      self.column_type.set_index(true);
+    }
+    if self.column_type.unique().is_none() && origin_column.column_type.unique() == Some(true) {
+      self.column_type.set_unique(true);
+    }
+  }
+  // 删除 constraint 对应的约束, 返回 false 时当前列已经没有意义, 需要移除
+  pub(crate) fn drop_constraint(&mut self, constraint: &Column) -> bool {
+    match constraint.column_type {
+      ColumnType::PrimaryKey(_) => {
+        if self.column_type.primary_key() == Some(true) && self.column_name() == constraint.column_name() {
+          if self.is_constraint() { return false }
+          self.column_type.set_primary_key(false);
+        }
+      },
+      ColumnType::ForeignKey(_) => {
+        let foreign_key_name = constraint.column_type.foreign_key().map(|foreign_key| foreign_key.name(constraint).to_owned());
+        if let Some(self_foreign_key) = self.column_type.foreign_key() && Some(self_foreign_key.name(self)) == foreign_key_name.as_deref() {
+          if self.is_constraint() { return false }
+          self.column_type.remove_foreign_key();
+        }
+      },
+      _ => {
+        if self.index_name().as_deref() == Some(constraint.column_name()) {
+          if self.is_constraint() { return false }
+          self.column_type.set_index(false);
+          self.column_type.set_unique(false);
+        }
+      }
+    }
+    true
+  }
+  pub fn to_constraint_sql(&self, table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
+    self.column_type.to_constraint_sql(self, table, dialect)
+  }
+  pub fn to_statement_sql(&self, table: &crate::DefineTable, dialect: crate::Dialect) -> Option<Sql> {
+    self.column_type.to_statement_sql(self, table, dialect)
+  }
+  pub fn comment(&self) -> Option<&'static str> {
+    self.column_type.comment()
+  }
+  // postgresql: 类型, NULL, DEFAULT 需要分别修改
+  pub fn to_postgresql_alter_column_sqls(&self, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Vec<Sql>, crate::SqlError> {
+    let column_name = dialect.quote_identifier(self.column_name());
+    let mut sqls = vec![];
+    if let Some(type_sql) = self.column_type.to_type_sql(self, table, dialect) {
+      let mut sql = Sql::new(format!("ALTER COLUMN {} TYPE ", column_name));
+      sql.push_sql(&type_sql);
+      sqls.push(sql);
+    }
+    if let Some(null) = self.column_type.null() {
+      sqls.push(Sql::new(format!("ALTER COLUMN {} {} NOT NULL", column_name, if null { "DROP" } else { "SET" })));
+    }
+    if let Some(default) = self.column_type.default_value(dialect)? {
+      sqls.push(Sql::new(format!("ALTER COLUMN {} SET DEFAULT {}", column_name, default)));
+    }
+    Ok(sqls)
+  }
+  // mssql: ALTER COLUMN 必须带类型, DEFAULT 需要单独的约束
+  pub fn to_mssql_alter_column_sqls(&self, table: &crate::DefineTable, dialect: crate::Dialect) -> Result<Vec<Sql>, crate::SqlError> {
+    let table_name = dialect.quote_identifier(&table.name);
+    let column_name = dialect.quote_identifier(self.column_name());
+    let mut sqls = vec![];
+    if let Some(type_sql) = self.column_type.to_type_sql(self, table, dialect) {
+      let mut sql = Sql::new(format!("ALTER TABLE {} ALTER COLUMN {} ", table_name, column_name));
+      sql.push_sql(&type_sql);
+      if let Some(null) = self.column_type.null() {
+        sql.push_value(if null { " NULL" } else { " NOT NULL" });
+      }
+      sqls.push(sql);
+    }
+    if let Some(default) = self.column_type.default_value(dialect)? {
+      let constraint_name = dialect.quote_identifier(&format!("df_{}_{}", table.schema_and_table_name().1, self.column_name()));
+      sqls.push(Sql::new(format!("ALTER TABLE {} ADD CONSTRAINT {} DEFAULT {} FOR {}", table_name, constraint_name, default, column_name)));
+    }
+    Ok(sqls)
+  }
+}
@@ -1,6 +1,7 @@
 use crate::table::column::{self, column_type::{self, ColumnTypeable}, Column};
 use crate::collectors::Sql;
 
+#[allow(clippy::enum_variant_names)]
 enum ActionTable {
   CreateTable,
   AltertTable { new_name: Option<String> },
@@ -18,6 +19,8 @@ pub struct DefineTable {
   // foreign key checks
   pub foreign_key_constraint_attribute: Option<ReferenceOption>,
   pub columns: Vec<Column>,
+  // sqlite: 重建表时使用的原表结构
+  origin_columns: Option<Vec<Column>>,
 }
 
 impl Default for DefineTable {
@@ -34,25 +37,30 @@ impl Default for DefineTable {
       comment: None,
       foreign_key_constraint_attribute: None, // Some("ON UPDATE CASCADE".to_owned()),
       columns: vec![],
+      origin_columns: None,
     }
   }
 }
 
 impl DefineTable {
   // mysql: https://dev.mysql.com/doc/refman/8.0/en/create-table.html
-  pub fn create(name: &str, callback: impl Fn(&mut Self) -> ()) -> Self {
-    let mut table = DefineTable::default();
-    table.name = name.to_owned();
-    table.action = Some(ActionTable::CreateTable);
-    // 父表更新时子表也更新，父表删除时如果子表有匹配的项，删除失败
-    table.foreign_key_constraint_attribute = Some(ReferenceOption::Custom("ON UPDATE CASCADE ON DELETE RESTRICT".to_owned()));
+  pub fn create(name: &str, callback: impl Fn(&mut Self)) -> Self {
+    let mut table = DefineTable {
+      name: name.to_owned(),
+      action: Some(ActionTable::CreateTable),
+      // 父表更新时子表也更新，父表删除时如果子表有匹配的项，删除失败
+      foreign_key_constraint_attribute: Some(ReferenceOption::Custom("ON UPDATE CASCADE ON DELETE RESTRICT".to_owned())),
+      ..DefineTable::default()
+    };
     callback(&mut table);
     table
   }
   // mysql: https://dev.mysql.com/doc/refman/8.0/en/alter-table.html
-  fn _alter(name: &str, new_name: Option<&str>, callback: impl Fn(&mut Self) -> ()) -> Self {
-    let mut table = DefineTable::default();
-    table.name = name.to_owned();
+  fn _alter(name: &str, new_name: Option<&str>, callback: impl Fn(&mut Self)) -> Self {
+    let mut table = DefineTable {
+      name: name.to_owned(),
+      ..DefineTable::default()
+    };
     if let Some(new_name) = new_name {
       table.action = Some(ActionTable::AltertTable { new_name: Some(new_name.to_owned()) });
     } else {
@@ -64,29 +72,35 @@ impl DefineTable {
   pub fn rename(name: &str, new_name: &str) -> Self {
     Self::_alter(name, Some(new_name), |_| {})
   }
-  pub fn alter(name: &str, callback: impl Fn(&mut Self) -> ()) -> Self {
+  pub fn alter(name: &str, callback: impl Fn(&mut Self)) -> Self {
     Self::_alter(name, None, callback)
   }
   // mysql: https://dev.mysql.com/doc/refman/8.0/en/drop-database.html
   pub fn drop(name: &str) -> Self {
-    let mut table = DefineTable::default();
-    table.name = name.to_owned();
-    table.action = Some(ActionTable::DropTable);
-    table
+    DefineTable {
+      name: name.to_owned(),
+      action: Some(ActionTable::DropTable),
+      ..DefineTable::default()
+    }
+  }
+
+  // sqlite: ALTER TABLE 不支持的修改需要重建表, eg: table.set_origin_table(SqlGen::create_table("users", |table| { ... }))
+  pub fn set_origin_table(&mut self, origin_table: DefineTable) {
+    self.origin_columns = Some(origin_table.columns);
   }
 
-  pub fn set_column_boolean(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, boolean_callback: impl Fn(&mut column_type::Boolean) -> ()) {
+  pub fn set_column_boolean(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, boolean_callback: impl Fn(&mut column_type::Boolean)) {
     let mut boolean = column_type::Boolean::default();
     boolean_callback(&mut boolean);
     self.columns.push(column::Column::new(column_name, column_type_action, boolean.into()));
   }
-  pub fn add_column_boolean(&mut self, column_name: &str, boolean_callback: impl Fn(&mut column_type::Boolean) -> ()) {
+  pub fn add_column_boolean(&mut self, column_name: &str, boolean_callback: impl Fn(&mut column_type::Boolean)) {
     self.set_column_boolean(column_name, column::ColumnTypeAction::AddColumn { position: None }, boolean_callback)
   }
-  pub fn modify_column_boolean(&mut self, column_name: &str, boolean_callback: impl Fn(&mut column_type::Boolean) -> ()) {
+  pub fn modify_column_boolean(&mut self, column_name: &str, boolean_callback: impl Fn(&mut column_type::Boolean)) {
     self.set_column_boolean(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, boolean_callback)
   }
-  pub fn change_column_boolean(&mut self, column_name: &str, new_column_name: &str, boolean_callback: impl Fn(&mut column_type::Boolean) -> ()) {
+  pub fn change_column_boolean(&mut self, column_name: &str, new_column_name: &str, boolean_callback: impl Fn(&mut column_type::Boolean)) {
     self.set_column_boolean(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None }, boolean_callback)
   }
   pub fn rename_column_boolean(&mut self, column_name: &str, new_column_name: &str) {
@@ -97,18 +111,18 @@ impl DefineTable {
   }
 
 
-  pub fn set_column_integer(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, integer_callback: impl Fn(&mut column_type::Integer) -> ()) {
+  pub fn set_column_integer(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, integer_callback: impl Fn(&mut column_type::Integer)) {
     let mut integer = column_type::Integer::default();
     integer_callback(&mut integer);
     self.columns.push(column::Column::new(column_name, column_type_action, integer.into()));
   }
-  pub fn add_column_integer(&mut self, column_name: &str, integer_callback: impl Fn(&mut column_type::Integer) -> ()) {
+  pub fn add_column_integer(&mut self, column_name: &str, integer_callback: impl Fn(&mut column_type::Integer)) {
     self.set_column_integer(column_name, column::ColumnTypeAction::AddColumn { position: None, }, integer_callback)
   }
-  pub fn modify_column_integer(&mut self, column_name: &str, integer_callback: impl Fn(&mut column_type::Integer) -> ()) {
+  pub fn modify_column_integer(&mut self, column_name: &str, integer_callback: impl Fn(&mut column_type::Integer)) {
     self.set_column_integer(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, integer_callback)
   }
-  pub fn change_column_integer(&mut self, column_name: &str, new_column_name: &str, integer_callback: impl Fn(&mut column_type::Integer) -> ()) {
+  pub fn change_column_integer(&mut self, column_name: &str, new_column_name: &str, integer_callback: impl Fn(&mut column_type::Integer)) {
     self.set_column_integer(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None }, integer_callback)
   }
   pub fn rename_column_integer(&mut self, column_name: &str, new_column_name: &str) {
@@ -119,18 +133,18 @@ impl DefineTable {
   }
 
 
-  pub fn set_column_float(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, float_callback: impl Fn(&mut column_type::Float) -> ()) {
+  pub fn set_column_float(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, float_callback: impl Fn(&mut column_type::Float)) {
     let mut float = column_type::Float::default();
     float_callback(&mut float);
     self.columns.push(column::Column::new(column_name, column_type_action, float.into()));
   }
-  pub fn add_column_float(&mut self, column_name: &str, float_callback: impl Fn(&mut column_type::Float) -> ()) {
+  pub fn add_column_float(&mut self, column_name: &str, float_callback: impl Fn(&mut column_type::Float)) {
     self.set_column_float(column_name, column::ColumnTypeAction::AddColumn { position: None }, float_callback);
   }
-  pub fn modify_column_float(&mut self, column_name: &str, float_callback: impl Fn(&mut column_type::Float) -> ()) {
+  pub fn modify_column_float(&mut self, column_name: &str, float_callback: impl Fn(&mut column_type::Float)) {
     self.set_column_float(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, float_callback);
   }
-  pub fn change_column_float(&mut self, column_name: &str, new_column_name: &str, float_callback: impl Fn(&mut column_type::Float) -> ()) {
+  pub fn change_column_float(&mut self, column_name: &str, new_column_name: &str, float_callback: impl Fn(&mut column_type::Float)) {
     self.set_column_float(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None }, float_callback);
   }
   pub fn rename_column_float(&mut self, column_name: &str, new_column_name: &str) {
@@ -140,18 +154,18 @@ impl DefineTable {
     self.set_column_float(column_name, column::ColumnTypeAction::DropColumn, |_| {})
   }
 
-  pub fn set_column_double(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, double_callback: impl Fn(&mut column_type::Double) -> ()) {
+  pub fn set_column_double(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, double_callback: impl Fn(&mut column_type::Double)) {
     let mut double = column_type::Double::default();
     double_callback(&mut double);
     self.columns.push(column::Column::new(column_name, column_type_action, double.into()));
   }
-  pub fn add_column_double(&mut self, column_name: &str, double_callback: impl Fn(&mut column_type::Double) -> ()) {
+  pub fn add_column_double(&mut self, column_name: &str, double_callback: impl Fn(&mut column_type::Double)) {
     self.set_column_double(column_name, column::ColumnTypeAction::AddColumn { position: None }, double_callback)
   }
-  pub fn modify_column_double(&mut self, column_name: &str, double_callback: impl Fn(&mut column_type::Double) -> ()) {
+  pub fn modify_column_double(&mut self, column_name: &str, double_callback: impl Fn(&mut column_type::Double)) {
     self.set_column_double(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, double_callback);
   }
-  pub fn change_column_double(&mut self, column_name: &str, new_column_name: &str, double_callback: impl Fn(&mut column_type::Double) -> ()) {
+  pub fn change_column_double(&mut self, column_name: &str, new_column_name: &str, double_callback: impl Fn(&mut column_type::Double)) {
     self.set_column_double(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None }, double_callback);
   }
   pub fn rename_column_double(&mut self, column_name: &str, new_column_name: &str) {
@@ -161,18 +175,18 @@ impl DefineTable {
     self.set_column_double(column_name, column::ColumnTypeAction::DropColumn, |_| {})
   }
 
-  pub fn set_column_decimal(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, decimal_callback: impl Fn(&mut column_type::Decimal) -> ()) {
+  pub fn set_column_decimal(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, decimal_callback: impl Fn(&mut column_type::Decimal)) {
     let mut decimal = column_type::Decimal::default();
     decimal_callback(&mut decimal);
     self.columns.push(column::Column::new(column_name, column_type_action, decimal.into()));
   }
-  pub fn add_column_decimal(&mut self, column_name: &str, decimal_callback: impl Fn(&mut column_type::Decimal) -> ()) {
+  pub fn add_column_decimal(&mut self, column_name: &str, decimal_callback: impl Fn(&mut column_type::Decimal)) {
     self.set_column_decimal(column_name, column::ColumnTypeAction::AddColumn { position: None }, decimal_callback)
   }
-  pub fn modify_column_decimal(&mut self, column_name: &str, decimal_callback: impl Fn(&mut column_type::Decimal) -> ()) {
+  pub fn modify_column_decimal(&mut self, column_name: &str, decimal_callback: impl Fn(&mut column_type::Decimal)) {
     self.set_column_decimal(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, decimal_callback)
   }
-  pub fn change_column_decimal(&mut self, column_name: &str, new_column_name: &str, decimal_callback: impl Fn(&mut column_type::Decimal) -> ()) {
+  pub fn change_column_decimal(&mut self, column_name: &str, new_column_name: &str, decimal_callback: impl Fn(&mut column_type::Decimal)) {
     self.set_column_decimal(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None }, decimal_callback)
   }
   pub fn rename_column_decimal(&mut self, column_name: &str, new_column_name: &str) {
@@ -182,18 +196,18 @@ impl DefineTable {
     self.set_column_decimal(column_name, column::ColumnTypeAction::DropColumn, |_| {})
   }
 
-  pub fn set_column_string(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, string_callback: impl Fn(&mut column_type::ColumnString) -> ()) {
+  pub fn set_column_string(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, string_callback: impl Fn(&mut column_type::ColumnString)) {
     let mut string = column_type::ColumnString::default();
     string_callback(&mut string);
     self.columns.push(column::Column::new(column_name, column_type_action, string.into()));
   }
-  pub fn add_column_string(&mut self, column_name: &str, string_callback: impl Fn(&mut column_type::ColumnString) -> ()) {
+  pub fn add_column_string(&mut self, column_name: &str, string_callback: impl Fn(&mut column_type::ColumnString)) {
     self.set_column_string(column_name, column::ColumnTypeAction::AddColumn { position: None }, string_callback)
   }
-  pub fn modify_column_string(&mut self, column_name: &str, string_callback: impl Fn(&mut column_type::ColumnString) -> ()) {
+  pub fn modify_column_string(&mut self, column_name: &str, string_callback: impl Fn(&mut column_type::ColumnString)) {
     self.set_column_string(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, string_callback)
   }
-  pub fn change_column_string(&mut self, column_name: &str, new_column_name: &str, string_callback: impl Fn(&mut column_type::ColumnString) -> ()) {
+  pub fn change_column_string(&mut self, column_name: &str, new_column_name: &str, string_callback: impl Fn(&mut column_type::ColumnString)) {
     self.set_column_string(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None}, string_callback)
   }
   pub fn rename_column_string(&mut self, column_name: &str, new_column_name: &str) {
@@ -203,18 +217,18 @@ impl DefineTable {
     self.set_column_string(column_name, column::ColumnTypeAction::DropColumn, |_| {})
   }
 
-  pub fn set_column_text(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, text_callback: impl Fn(&mut column_type::Text) -> ()) {
+  pub fn set_column_text(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, text_callback: impl Fn(&mut column_type::Text)) {
     let mut text = column_type::Text::default();
     text_callback(&mut text);
     self.columns.push(column::Column::new(column_name, column_type_action, text.into()));
   }
-  pub fn add_column_text(&mut self, column_name: &str, text_callback: impl Fn(&mut column_type::Text) -> ()) {
+  pub fn add_column_text(&mut self, column_name: &str, text_callback: impl Fn(&mut column_type::Text)) {
     self.set_column_text(column_name, column::ColumnTypeAction::AddColumn { position: None }, text_callback)
   }
-  pub fn modify_column_text(&mut self, column_name: &str, text_callback: impl Fn(&mut column_type::Text) -> ()) {
+  pub fn modify_column_text(&mut self, column_name: &str, text_callback: impl Fn(&mut column_type::Text)) {
     self.set_column_text(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, text_callback)
   }
-  pub fn change_column_text(&mut self, column_name: &str, new_column_name: &str, text_callback: impl Fn(&mut column_type::Text) -> ()) {
+  pub fn change_column_text(&mut self, column_name: &str, new_column_name: &str, text_callback: impl Fn(&mut column_type::Text)) {
     self.set_column_text(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None }, text_callback)
   }
   pub fn rename_column_text(&mut self, column_name: &str, new_column_name: &str) {
@@ -224,18 +238,18 @@ impl DefineTable {
     self.set_column_text(column_name, column::ColumnTypeAction::DropColumn, |_| {})
   }
 
-  pub fn set_column_time(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, time_callback: impl Fn(&mut column_type::Time) -> ()) {
+  pub fn set_column_time(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, time_callback: impl Fn(&mut column_type::Time)) {
     let mut time = column_type::Time::default();
     time_callback(&mut time);
     self.columns.push(column::Column::new(column_name, column_type_action, time.into()));
   }
-  pub fn add_column_time(&mut self, column_name: &str, time_callback: impl Fn(&mut column_type::Time) -> ()) {
+  pub fn add_column_time(&mut self, column_name: &str, time_callback: impl Fn(&mut column_type::Time)) {
     self.set_column_time(column_name, column::ColumnTypeAction::AddColumn { position: None }, time_callback)
   }
-  pub fn modify_column_time(&mut self, column_name: &str, time_callback: impl Fn(&mut column_type::Time) -> ()) {
+  pub fn modify_column_time(&mut self, column_name: &str, time_callback: impl Fn(&mut column_type::Time)) {
     self.set_column_time(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, time_callback)
   }
-  pub fn change_column_time(&mut self, column_name: &str, new_column_name: &str, time_callback: impl Fn(&mut column_type::Time) -> ()) {
+  pub fn change_column_time(&mut self, column_name: &str, new_column_name: &str, time_callback: impl Fn(&mut column_type::Time)) {
     self.set_column_time(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None }, time_callback)
   }
   pub fn rename_column_time(&mut self, column_name: &str, new_column_name: &str) {
@@ -245,18 +259,18 @@ impl DefineTable {
     self.set_column_time(column_name, column::ColumnTypeAction::DropColumn, |_| {})
   }
 
-  pub fn set_column_date(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, date_callback: impl Fn(&mut column_type::Date) -> ()) {
+  pub fn set_column_date(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, date_callback: impl Fn(&mut column_type::Date)) {
     let mut date = column_type::Date::default();
     date_callback(&mut date);
     self.columns.push(column::Column::new(column_name, column_type_action, date.into()));
   }
-  pub fn add_column_date(&mut self, column_name: &str, date_callback: impl Fn(&mut column_type::Date) -> ()) {
+  pub fn add_column_date(&mut self, column_name: &str, date_callback: impl Fn(&mut column_type::Date)) {
     self.set_column_date(column_name, column::ColumnTypeAction::AddColumn { position: None }, date_callback)
   }
-  pub fn modify_column_date(&mut self, column_name: &str, date_callback: impl Fn(&mut column_type::Date) -> ()) {
+  pub fn modify_column_date(&mut self, column_name: &str, date_callback: impl Fn(&mut column_type::Date)) {
     self.set_column_date(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, date_callback)
   }
-  pub fn change_column_date(&mut self, column_name: &str, new_column_name: &str, date_callback: impl Fn(&mut column_type::Date) -> ()) {
+  pub fn change_column_date(&mut self, column_name: &str, new_column_name: &str, date_callback: impl Fn(&mut column_type::Date)) {
     self.set_column_date(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None }, date_callback)
   }
   pub fn rename_column_date(&mut self, column_name: &str, new_column_name: &str) {
@@ -266,18 +280,18 @@ impl DefineTable {
     self.set_column_date(column_name, column::ColumnTypeAction::DropColumn, |_| {})
   }
 
-  pub fn set_column_datetime(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, datetime_callback: impl Fn(&mut column_type::Datetime) -> ()) {
+  pub fn set_column_datetime(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, datetime_callback: impl Fn(&mut column_type::Datetime)) {
     let mut datetime = column_type::Datetime::default();
     datetime_callback(&mut datetime);
     self.columns.push(column::Column::new(column_name, column_type_action, datetime.into()));
   }
-  pub fn add_column_datetime(&mut self, column_name: &str, datetime_callback: impl Fn(&mut column_type::Datetime) -> ()) {
+  pub fn add_column_datetime(&mut self, column_name: &str, datetime_callback: impl Fn(&mut column_type::Datetime)) {
     self.set_column_datetime(column_name, column::ColumnTypeAction::AddColumn { position: None }, datetime_callback)
   }
-  pub fn modify_column_datetime(&mut self, column_name: &str, datetime_callback: impl Fn(&mut column_type::Datetime) -> ()) {
+  pub fn modify_column_datetime(&mut self, column_name: &str, datetime_callback: impl Fn(&mut column_type::Datetime)) {
     self.set_column_datetime(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, datetime_callback)
   }
-  pub fn change_column_datetime(&mut self, column_name: &str, new_column_name: &str, datetime_callback: impl Fn(&mut column_type::Datetime) -> ()) {
+  pub fn change_column_datetime(&mut self, column_name: &str, new_column_name: &str, datetime_callback: impl Fn(&mut column_type::Datetime)) {
     self.set_column_datetime(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None }, datetime_callback)
   }
   pub fn rename_column_datetime(&mut self, column_name: &str, new_column_name: &str) {
@@ -287,18 +301,18 @@ impl DefineTable {
     self.set_column_datetime(column_name, column::ColumnTypeAction::DropColumn, |_| {})
   }
 
-  pub fn set_column_timestamp(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, timestamp_callback: impl Fn(&mut column_type::Timestamp) -> ()) {
+  pub fn set_column_timestamp(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, timestamp_callback: impl Fn(&mut column_type::Timestamp)) {
     let mut timestamp = column_type::Timestamp::default();
     timestamp_callback(&mut timestamp);
     self.columns.push(column::Column::new(column_name, column_type_action, timestamp.into()));
   }
-  pub fn add_column_timestamp(&mut self, column_name: &str, timestamp_callback: impl Fn(&mut column_type::Timestamp) -> ()) {
+  pub fn add_column_timestamp(&mut self, column_name: &str, timestamp_callback: impl Fn(&mut column_type::Timestamp)) {
     self.set_column_timestamp(column_name, column::ColumnTypeAction::AddColumn { position: None }, timestamp_callback)
   }
-  pub fn modify_column_timestamp(&mut self, column_name: &str, timestamp_callback: impl Fn(&mut column_type::Timestamp) -> ()) {
+  pub fn modify_column_timestamp(&mut self, column_name: &str, timestamp_callback: impl Fn(&mut column_type::Timestamp)) {
     self.set_column_timestamp(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, timestamp_callback)
   }
-  pub fn change_column_timestamp(&mut self, column_name: &str, new_column_name: &str, timestamp_callback: impl Fn(&mut column_type::Timestamp) -> ()) {
+  pub fn change_column_timestamp(&mut self, column_name: &str, new_column_name: &str, timestamp_callback: impl Fn(&mut column_type::Timestamp)) {
     self.set_column_timestamp(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None }, timestamp_callback)
   }
   pub fn rename_column_timestamp(&mut self, column_name: &str, new_column_name: &str) {
@@ -308,18 +322,18 @@ impl DefineTable {
     self.set_column_timestamp(column_name, column::ColumnTypeAction::DropColumn, |_| {})
   }
 
-  pub fn set_column_json(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, json_callback: impl Fn(&mut column_type::Json) -> ()) {
+  pub fn set_column_json(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, json_callback: impl Fn(&mut column_type::Json)) {
     let mut json = column_type::Json::default();
     json_callback(&mut json);
     self.columns.push(column::Column::new(column_name, column_type_action, json.into()));
   }
-  pub fn add_column_json(&mut self, column_name: &str, json_callback: impl Fn(&mut column_type::Json) -> ()) {
+  pub fn add_column_json(&mut self, column_name: &str, json_callback: impl Fn(&mut column_type::Json)) {
     self.set_column_json(column_name, column::ColumnTypeAction::AddColumn { position: None }, json_callback)
   }
-  pub fn modify_column_json(&mut self, column_name: &str, json_callback: impl Fn(&mut column_type::Json) -> ()) {
+  pub fn modify_column_json(&mut self, column_name: &str, json_callback: impl Fn(&mut column_type::Json)) {
     self.set_column_json(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, json_callback)
   }
-  pub fn change_column_json(&mut self, column_name: &str, new_column_name: &str, json_callback: impl Fn(&mut column_type::Json) -> ()) {
+  pub fn change_column_json(&mut self, column_name: &str, new_column_name: &str, json_callback: impl Fn(&mut column_type::Json)) {
     self.set_column_json(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None }, json_callback)
   }
   pub fn rename_column_json(&mut self, column_name: &str, new_column_name: &str) {
@@ -329,18 +343,18 @@ impl DefineTable {
     self.set_column_json(column_name, column::ColumnTypeAction::DropColumn, |_| {})
   }
 
-  pub fn set_column_blob(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, blob_callback: impl Fn(&mut column_type::Blob) -> ()) {
+  pub fn set_column_blob(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, blob_callback: impl Fn(&mut column_type::Blob)) {
     let mut blob = column_type::Blob::default();
     blob_callback(&mut blob);
     self.columns.push(column::Column::new(column_name, column_type_action, blob.into()));
   }
-  pub fn add_column_blob(&mut self, column_name: &str, blob_callback: impl Fn(&mut column_type::Blob) -> ()) {
+  pub fn add_column_blob(&mut self, column_name: &str, blob_callback: impl Fn(&mut column_type::Blob)) {
     self.set_column_blob(column_name, column::ColumnTypeAction::AddColumn { position: None }, blob_callback)
   }
-  pub fn modify_column_blob(&mut self, column_name: &str, blob_callback: impl Fn(&mut column_type::Blob) -> ()) {
+  pub fn modify_column_blob(&mut self, column_name: &str, blob_callback: impl Fn(&mut column_type::Blob)) {
     self.set_column_blob(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, blob_callback)
   }
-  pub fn change_column_blob(&mut self, column_name: &str, new_column_name: &str, blob_callback: impl Fn(&mut column_type::Blob) -> ()) {
+  pub fn change_column_blob(&mut self, column_name: &str, new_column_name: &str, blob_callback: impl Fn(&mut column_type::Blob)) {
     self.set_column_blob(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None }, blob_callback)
   }
   pub fn rename_column_blob(&mut self, column_name: &str, new_column_name: &str) {
@@ -350,18 +364,18 @@ impl DefineTable {
     self.set_column_blob(column_name, column::ColumnTypeAction::DropColumn, |_| {})
   }
 
-  pub fn set_column_binary(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, binary_callback: impl Fn(&mut column_type::Binary) -> ()) {
+  pub fn set_column_binary(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, binary_callback: impl Fn(&mut column_type::Binary)) {
     let mut binary = column_type::Binary::default();
     binary_callback(&mut binary);
     self.columns.push(column::Column::new(column_name, column_type_action, binary.into()));
   }
-  pub fn add_column_binary(&mut self, column_name: &str, binary_callback: impl Fn(&mut column_type::Binary) -> ()) {
+  pub fn add_column_binary(&mut self, column_name: &str, binary_callback: impl Fn(&mut column_type::Binary)) {
     self.set_column_binary(column_name, column::ColumnTypeAction::AddColumn { position: None }, binary_callback)
   }
-  pub fn modify_column_binary(&mut self, column_name: &str, binary_callback: impl Fn(&mut column_type::Binary) -> ()) {
+  pub fn modify_column_binary(&mut self, column_name: &str, binary_callback: impl Fn(&mut column_type::Binary)) {
     self.set_column_binary(column_name, column::ColumnTypeAction::ModifyColumn { position: None }, binary_callback)
   }
-  pub fn change_column_binary(&mut self, column_name: &str, new_column_name: &str, binary_callback: impl Fn(&mut column_type::Binary) -> ()) {
+  pub fn change_column_binary(&mut self, column_name: &str, new_column_name: &str, binary_callback: impl Fn(&mut column_type::Binary)) {
     self.set_column_binary(column_name, column::ColumnTypeAction::ChangeColumn { new_name: new_column_name.into(), position: None}, binary_callback)
   }
   pub fn rename_column_binary(&mut self, column_name: &str, new_column_name: &str) {
@@ -371,7 +385,7 @@ impl DefineTable {
     self.set_column_binary(column_name, column::ColumnTypeAction::DropColumn, |_| {})
   }
 
-  pub fn set_index(&mut self, column_names: Vec<&str>, column_type_action: column::ColumnTypeAction, index_callback: impl Fn(&mut column_type::Index) -> ()) {
+  pub fn set_index(&mut self, column_names: Vec<&str>, column_type_action: column::ColumnTypeAction, index_callback: impl Fn(&mut column_type::Index)) {
     let mut index = column_type::Index::default();
     index_callback(&mut index);
 
@@ -379,8 +393,8 @@ impl DefineTable {
     column.column_names = column_names.into_iter().map(|name| name.into()).collect::<Vec<String>>();
     self.columns.push(column);
   }
-  pub fn add_index(&mut self, column_names: Vec<&str>, index_callback: impl Fn(&mut column_type::Index) -> ()) {
-    assert!(column_names.len() >= 1);
+  pub fn add_index(&mut self, column_names: Vec<&str>, index_callback: impl Fn(&mut column_type::Index)) {
+    assert!(!column_names.is_empty());
     self.set_index(column_names, column::ColumnTypeAction::AddConstraint, index_callback);
   }
   pub fn rename_index(&mut self, index_name: &str, new_index_name: &str) {
@@ -390,7 +404,7 @@ impl DefineTable {
     self.set_index(vec![index_name], column::ColumnTypeAction::DropConstraint, |_| {});
   }
 
-  pub fn set_unique(&mut self, column_names: Vec<&str>, column_type_action: column::ColumnTypeAction, unique_callback: impl Fn(&mut column_type::Unique) -> ()) {
+  pub fn set_unique(&mut self, column_names: Vec<&str>, column_type_action: column::ColumnTypeAction, unique_callback: impl Fn(&mut column_type::Unique)) {
     let mut unique = column_type::Unique::default();
     unique_callback(&mut unique);
 
@@ -398,49 +412,76 @@ impl DefineTable {
     column.column_names = column_names.into_iter().map(|name| name.into()).collect::<Vec<String>>();
     self.columns.push(column);
   }
-  pub fn add_unique(&mut self, column_names: Vec<&str>, unique_callback: impl Fn(&mut column_type::Unique) -> ()) {
+  pub fn add_unique(&mut self, column_names: Vec<&str>, unique_callback: impl Fn(&mut column_type::Unique)) {
     self.set_unique(column_names, column::ColumnTypeAction::AddConstraint, unique_callback);
   }
   pub fn drop_unique(&mut self, unique_name: &str) {
     self.set_unique(vec![unique_name], column::ColumnTypeAction::DropConstraint, |_| {});
   }
 
-  pub fn set_primary_key(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, primary_key_callback: impl Fn(&mut column_type::PrimaryKey) -> ()) {
+  pub fn set_primary_key(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, primary_key_callback: impl Fn(&mut column_type::PrimaryKey)) {
     let mut primary_key = column_type::PrimaryKey::default();
     primary_key.set_foreign_key(column_name.into());
     primary_key_callback(&mut primary_key);
     self.columns.push(column::Column::new(column_name, column_type_action, primary_key.into()));
   }
-  pub fn add_primary_key(&mut self, column_name: &str, primary_key_callback: impl Fn(&mut column_type::PrimaryKey) -> ()) {
+  pub fn add_primary_key(&mut self, column_name: &str, primary_key_callback: impl Fn(&mut column_type::PrimaryKey)) {
     self.set_primary_key(column_name, column::ColumnTypeAction::AddConstraint, primary_key_callback);
   }
   pub fn drop_primary_key(&mut self, column_name: &str) {
     self.set_primary_key(column_name, column::ColumnTypeAction::DropConstraint, |_| {});
   }
 
-  pub fn set_foreign_key(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, foreign_key_callback: impl Fn(&mut column_type::ForeignKey) -> ()) {
+  pub fn set_foreign_key(&mut self, column_name: &str, column_type_action: column::ColumnTypeAction, foreign_key_callback: impl Fn(&mut column_type::ForeignKey)) {
     let mut foreign_key = column_type::ForeignKey::default();
     foreign_key.set_foreign_key(column_name.into());
     foreign_key_callback(&mut foreign_key);
     self.columns.push(column::Column::new(column_name, column_type_action, foreign_key.into()));
   }
-  pub fn add_foreign_key(&mut self, column_name: &str, foreign_key_callback: impl Fn(&mut column_type::ForeignKey) -> ()) {
+  pub fn add_foreign_key(&mut self, column_name: &str, foreign_key_callback: impl Fn(&mut column_type::ForeignKey)) {
     self.set_foreign_key(column_name, column::ColumnTypeAction::AddConstraint, foreign_key_callback);
   }
   pub fn drop_foreign_key(&mut self, foreign_key_name: &str) {
     self.set_foreign_key(foreign_key_name, column::ColumnTypeAction::DropConstraint, |_| {});
   }
 
-  pub fn to_sql(&self) -> Sql {
+  pub(crate) fn is_alter(&self) -> bool {
+    matches!(self.action, Some(ActionTable::AltertTable { .. }))
+  }
+  pub(crate) fn schema_and_table_name(&self) -> (Option<&str>, &str) {
+    split_schema_name(&self.name)
+  }
+
+  // 先校验表名, 列名等, 再生成 sql
+  pub fn try_to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::SqlError> {
+    dialect.validate_identifier(&self.name)?;
+    if let Some(ActionTable::AltertTable { new_name: Some(new_name) }) = &self.action {
+      dialect.validate_identifier(new_name)?;
+    }
+    for column in self.columns.iter() {
+      column.validate(dialect)?;
+    }
+    self.to_sql(dialect)
+  }
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::SqlError> {
+    match dialect {
+      crate::Dialect::Mysql => self.to_mysql_sql(dialect),
+      crate::Dialect::Postgresql => self.to_postgresql_sql(dialect),
+      crate::Dialect::Sqlite => self.to_sqlite_sql(dialect),
+      crate::Dialect::Mssql => self.to_mssql_sql(dialect),
+    }
+  }
+
+  fn to_mysql_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::SqlError> {
     match &self.action {
       Some(ActionTable::CreateTable) => {
-        let mut sql = Sql::new(format!("CREATE TABLE IF NOT EXISTS {}", self.name));
+        let mut sql = Sql::new(format!("CREATE TABLE IF NOT EXISTS {}", dialect.quote_identifier(&self.name)));
         sql.push(' ').push('(').push('\n');
         // to_sql
         {
           let mut column_append_idx = -1;
           for column in self.columns.iter() {
-            if let Some(column_sql) = column.to_sql(&self) {
+            if let Some(column_sql) = column.to_sql(self, dialect)? {
               if column_append_idx >= 0 {
                 sql.push(',').push('\n');
                }
@@ -452,7 +493,7 @@ impl DefineTable {
         // to_constraint_sql
         {
           for column in self.columns.iter() {
-            let ret = column.to_constraint_sql(self);
+            let ret = column.to_constraint_sql(self, dialect);
             if let Some(constraint_sql) = ret {
               sql.push(',').push('\n').push_sql(&constraint_sql);
             }
@@ -472,10 +513,10 @@ impl DefineTable {
           sql.push_value(&format!(" COLLATE {}", comment));
         }
         sql.push(';');
-        sql
+        Ok(sql)
       },
       Some(ActionTable::AltertTable { new_name}) => {
-        let mut sql = Sql::new(format!("ALTER TABLE {}", self.name));
+        let mut sql = Sql::new(format!("ALTER TABLE {}", dialect.quote_identifier(&self.name)));
         let mut column_append_idx = -1;
         // to_sql
         {
@@ -483,35 +524,35 @@ impl DefineTable {
           for column in self.columns.iter() {
             match &column.column_type_action {
               column_type::ColumnTypeAction::AddColumn { .. } => {
-                if let Some(column_sql) = column.to_sql(self) {
+                if let Some(column_sql) = column.to_sql(self, dialect)? {
                   if column_append_idx >= 0 { sql.push_value(",\n"); } else { sql.push('\n'); }
                   sql.push_value("ADD COLUMN ").push_sql(&column_sql);
                   column_append_idx += 1;
                 }
               },
               column_type::ColumnTypeAction::ModifyColumn { .. } => {
-                if let Some(column_sql) = column.to_sql(self) {
+                if let Some(column_sql) = column.to_sql(self, dialect)? {
                   if column_append_idx >= 0 { sql.push_value(",\n"); } else { sql.push('\n'); }
                   sql.push_value("MODIFY COLUMN ").push_sql(&column_sql);
                   column_append_idx += 1;
                 }
               },
               column_type::ColumnTypeAction::ChangeColumn { .. } => {
-                if let Some(column_sql) = column.to_sql(self) {
+                if let Some(column_sql) = column.to_sql(self, dialect)? {
                   if column_append_idx >= 0 { sql.push_value(",\n"); } else { sql.push('\n'); }
                   sql.push_value("CHANGE COLUMN ").push_sql(&column_sql);
                   column_append_idx += 1;
                 }
               },
               column_type::ColumnTypeAction::RenameColumn { .. } => {
-                if let Some(column_sql) = column.to_sql(self) {
+                if let Some(column_sql) = column.to_sql(self, dialect)? {
                   if column_append_idx >= 0 { sql.push_value(",\n"); } else { sql.push('\n'); }
                   sql.push_value("RENAME COLUMN ").push_sql(&column_sql);
                   column_append_idx += 1;
                 }
               },
               column_type::ColumnTypeAction::DropColumn => {
-                if let Some(column_sql) = column.to_sql(self) {
+                if let Some(column_sql) = column.to_sql(self, dialect)? {
                   if column_append_idx >= 0 { sql.push_value(",\n"); } else { sql.push('\n'); }
                   sql.push_value("DROP COLUMN ").push_sql(&column_sql);
                   column_append_idx += 1;
@@ -526,7 +567,7 @@ impl DefineTable {
         // to_constraint_sql
         {
           for column in self.columns.iter() {
-            let ret = column.to_constraint_sql(self);
+            let ret = column.to_constraint_sql(self, dialect);
             if let Some(constraint_sql) = ret {
               if column_append_idx >= 0 {
                 sql.push(',');
@@ -544,7 +585,7 @@ impl DefineTable {
           }
         }
         if let Some(new_name) = new_name {
-          sql.push_value(&format!(" RENAME TO {}", new_name));
+          sql.push_value(&format!(" RENAME TO {}", dialect.quote_identifier(new_name)));
         }
         if let Some(engine) = &self.engine {
           sql.push_value(&format!(" ENGINE = {}", engine));
@@ -556,22 +597,464 @@ impl DefineTable {
           sql.push_value(&format!(" COLLATE {}", collation));
         }
         sql.push(';');
-        sql
+        Ok(sql)
+      },
+      Some(ActionTable::DropTable) => {
+        Ok(Sql::new(format!("DROP TABLE IF EXISTS {};", dialect.quote_identifier(&self.name))))
+      },
+      None => {
+        Ok(Sql::default())
+      }
+    }
+  }
+
+  // postgresql: https://www.postgresql.org/docs/current/sql-createtable.html
+  // postgresql: https://www.postgresql.org/docs/current/sql-altertable.html
+  fn to_postgresql_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::SqlError> {
+    match &self.action {
+      Some(ActionTable::CreateTable) => {
+        let mut definition_sqls = vec![];
+        for column in self.columns.iter() {
+          if let Some(column_sql) = column.to_sql(self, dialect)? {
+            definition_sqls.push(column_sql);
+          }
+        }
+        for column in self.columns.iter() {
+          if let Some(constraint_sql) = column.to_constraint_sql(self, dialect) {
+            definition_sqls.push(constraint_sql);
+          }
+        }
+        let mut sql = Sql::new(format!("CREATE TABLE IF NOT EXISTS {} (\n", dialect.quote_identifier(&self.name)));
+        sql.push_sqls(&definition_sqls, ",\n").push('\n').push(')').push(';');
+        for statement_sql in self.to_postgresql_statement_sqls(dialect)?.iter() {
+          sql.push('\n').push_sql(statement_sql).push(';');
+        }
+        Ok(sql)
+      },
+      Some(ActionTable::AltertTable { new_name }) => {
+        let mut action_sqls = vec![];
+        let mut rename_sqls = vec![];
+        for column in self.columns.iter() {
+          match &column.column_type_action {
+            column_type::ColumnTypeAction::AddColumn { .. } => {
+              if let Some(column_sql) = column.to_sql(self, dialect)? {
+                let mut sql = Sql::new("ADD COLUMN ".to_owned());
+                sql.push_sql(&column_sql);
+                action_sqls.push(sql);
+              }
+            },
+            column_type::ColumnTypeAction::ModifyColumn { .. } => {
+              action_sqls.extend(column.to_postgresql_alter_column_sqls(self, dialect)?);
+            },
+            // 先修改类型, 再重命名
+            column_type::ColumnTypeAction::ChangeColumn { new_name, .. } => {
+              action_sqls.extend(column.to_postgresql_alter_column_sqls(self, dialect)?);
+              rename_sqls.push(Sql::new(format!("ALTER TABLE {} RENAME COLUMN {} TO {}", dialect.quote_identifier(&self.name), dialect.quote_identifier(column.column_name()), dialect.quote_identifier(new_name))));
+            },
+            column_type::ColumnTypeAction::RenameColumn { new_name, .. } => {
+              rename_sqls.push(Sql::new(format!("ALTER TABLE {} RENAME COLUMN {} TO {}", dialect.quote_identifier(&self.name), dialect.quote_identifier(column.column_name()), dialect.quote_identifier(new_name))));
+            },
+            column_type::ColumnTypeAction::DropColumn => {
+              action_sqls.push(Sql::new(format!("DROP COLUMN {}", dialect.quote_identifier(column.column_name()))));
+            },
+            column_type::ColumnTypeAction::AddConstraint |
+            column_type::ColumnTypeAction::DropConstraint |
+            column_type::ColumnTypeAction::RenameIndex { .. } => ()
+          }
+        }
+        for column in self.columns.iter() {
+          if let Some(constraint_sql) = column.to_constraint_sql(self, dialect) {
+            action_sqls.push(constraint_sql);
+          }
+        }
+
+        let mut sqls = vec![];
+        if !action_sqls.is_empty() {
+          let mut sql = Sql::new(format!("ALTER TABLE {}\n", dialect.quote_identifier(&self.name)));
+          sql.push_sqls(&action_sqls, ",\n");
+          sqls.push(sql);
+        }
+        sqls.extend(rename_sqls);
+        sqls.extend(self.to_postgresql_statement_sqls(dialect)?);
+        // RENAME TO 只能是表名, 不能移动到其它 schema
+        if let Some(new_name) = new_name {
+          sqls.push(Sql::new(format!("ALTER TABLE {} RENAME TO {}", dialect.quote_identifier(&self.name), dialect.quote_identifier(split_schema_name(new_name).1))));
+        }
+        Ok(Self::join_statement_sqls(&sqls))
+      },
+      Some(ActionTable::DropTable) => {
+        Ok(Sql::new(format!("DROP TABLE IF EXISTS {};", dialect.quote_identifier(&self.name))))
+      },
+      None => {
+        Ok(Sql::default())
+      }
+    }
+  }
+  // postgresql: 索引和注释不能写在表语句中
+  fn to_postgresql_statement_sqls(&self, dialect: crate::Dialect) -> Result<Vec<Sql>, crate::SqlError> {
+    let mut sqls = vec![];
+    for column in self.columns.iter() {
+      if let Some(statement_sql) = column.to_statement_sql(self, dialect) {
+        sqls.push(statement_sql);
+      }
+    }
+    for column in self.columns.iter() {
+      let column_name = match &column.column_type_action {
+        column_type::ColumnTypeAction::AddColumn { .. } | column_type::ColumnTypeAction::ModifyColumn { .. } => column.column_name(),
+        column_type::ColumnTypeAction::ChangeColumn { new_name, .. } => new_name,
+        _ => continue,
+      };
+      if let Some(comment) = column.comment() {
+        sqls.push(Sql::new(format!("COMMENT ON COLUMN {}.{} IS {}", dialect.quote_identifier(&self.name), dialect.quote_identifier(column_name), dialect.quote_string(comment)?)));
+      }
+    }
+    Ok(sqls)
+  }
+
+  // sqlite: https://www.sqlite.org/lang_createtable.html
+  // sqlite: https://www.sqlite.org/lang_altertable.html
+  fn to_sqlite_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::SqlError> {
+    match &self.action {
+      Some(ActionTable::CreateTable) => {
+        let mut sqls = vec![self.to_sqlite_create_table_sql(&format!("IF NOT EXISTS {}", dialect.quote_identifier(&self.name)), dialect)?];
+        sqls.extend(self.to_sqlite_statement_sqls(dialect));
+        Ok(Self::join_statement_sqls(&sqls))
+      },
+      Some(ActionTable::AltertTable { new_name }) => {
+        let rebuild = self.is_sqlite_rebuild_required();
+        let mut sqls = if rebuild {
+          let (rebuild_table, copy_column_names) = self.to_sqlite_rebuild_table()?;
+          let mut sqls = self.to_sqlite_rebuild_sqls(&rebuild_table, copy_column_names, dialect)?;
+          sqls.extend(self.to_sqlite_rename_index_sqls(&rebuild_table.columns, dialect)?);
+          sqls
+        } else {
+          let mut sqls = self.to_sqlite_alter_sqls(dialect)?;
+          sqls.extend(self.to_sqlite_rename_index_sqls(self.origin_columns.as_deref().unwrap_or_default(), dialect)?);
+          sqls
+        };
+        if let Some(new_name) = new_name {
+          sqls.push(Sql::new(format!("ALTER TABLE {} RENAME TO {}", dialect.quote_identifier(&self.name), dialect.quote_identifier(split_schema_name(new_name).1))));
+        }
+        // https://www.sqlite.org/lang_altertable.html#otheralter
+        if rebuild {
+          sqls.insert(0, Sql::new("PRAGMA foreign_keys = OFF".to_owned()));
+          sqls.insert(1, Sql::new("BEGIN TRANSACTION".to_owned()));
+          sqls.push(Sql::new("PRAGMA foreign_key_check".to_owned()));
+          sqls.push(Sql::new("COMMIT".to_owned()));
+          sqls.push(Sql::new("PRAGMA foreign_keys = ON".to_owned()));
+        }
+        Ok(Self::join_statement_sqls(&sqls))
+      },
+      Some(ActionTable::DropTable) => {
+        Ok(Sql::new(format!("DROP TABLE IF EXISTS {};", dialect.quote_identifier(&self.name))))
+      },
+      None => {
+        Ok(Sql::default())
+      }
+    }
+  }
+  fn to_sqlite_create_table_sql(&self, table_name: &str, dialect: crate::Dialect) -> Result<Sql, crate::SqlError> {
+    let mut definition_sqls = vec![];
+    for column in self.columns.iter() {
+      if let Some(column_sql) = column.to_sql(self, dialect)? {
+        definition_sqls.push(column_sql);
+      }
+    }
+    for column in self.columns.iter() {
+      if let Some(constraint_sql) = column.to_constraint_sql(self, dialect) {
+        definition_sqls.push(constraint_sql);
+      }
+    }
+    let mut sql = Sql::new(format!("CREATE TABLE {} (\n", table_name));
+    sql.push_sqls(&definition_sqls, ",\n").push('\n').push(')');
+    Ok(sql)
+  }
+  fn to_sqlite_statement_sqls(&self, dialect: crate::Dialect) -> Vec<Sql> {
+    self.columns.iter().filter_map(|column| column.to_statement_sql(self, dialect)).collect()
+  }
+  // sqlite: ALTER TABLE 只支持 RENAME TO, RENAME COLUMN, ADD COLUMN, DROP COLUMN
+  fn is_sqlite_rebuild_required(&self) -> bool {
+    self.columns.iter().any(|column| {
+      match column.column_type_action {
+        column_type::ColumnTypeAction::ModifyColumn { .. } |
+        column_type::ColumnTypeAction::ChangeColumn { .. } => true,
+        column_type::ColumnTypeAction::AddColumn { .. } |
+        column_type::ColumnTypeAction::AddConstraint |
+        column_type::ColumnTypeAction::DropConstraint => {
+          column.column_type().primary_key() == Some(true) || column.column_type().auto_increment() == Some(true) || column.column_type().foreign_key().is_some()
+        },
+        _ => false
+      }
+    })
+  }
+  fn to_sqlite_alter_sqls(&self, dialect: crate::Dialect) -> Result<Vec<Sql>, crate::SqlError> {
+    let mut sqls = vec![];
+    for column in self.columns.iter() {
+      match &column.column_type_action {
+        column_type::ColumnTypeAction::AddColumn { .. } => {
+          if let Some(column_sql) = column.to_sql(self, dialect)? {
+            let mut sql = Sql::new(format!("ALTER TABLE {} ADD COLUMN ", dialect.quote_identifier(&self.name)));
+            sql.push_sql(&column_sql);
+            sqls.push(sql);
+          }
+        },
+        column_type::ColumnTypeAction::RenameColumn { new_name, .. } => {
+          sqls.push(Sql::new(format!("ALTER TABLE {} RENAME COLUMN {} TO {}", dialect.quote_identifier(&self.name), dialect.quote_identifier(column.column_name()), dialect.quote_identifier(new_name))));
+        },
+        column_type::ColumnTypeAction::DropColumn => {
+          sqls.push(Sql::new(format!("ALTER TABLE {} DROP COLUMN {}", dialect.quote_identifier(&self.name), dialect.quote_identifier(column.column_name()))));
+        },
+        _ => ()
+      }
+    }
+    sqls.extend(self.to_sqlite_statement_sqls(dialect));
+    Ok(sqls)
+  }
+  // 按原表结构和修改生成新表, 以及需要复制的 (原列名, 新列名)
+  // 没有通过 set_origin_table 提供原表结构时无法重建
+  fn to_sqlite_rebuild_table(&self) -> Result<(DefineTable, Vec<(String, String)>), crate::SqlError> {
+    let mut columns = self.origin_columns.clone().ok_or_else(|| crate::SqlError::DialectUnsupported { dialect: crate::Dialect::Sqlite, feature: "ALTER without origin table".to_owned() })?;
+    // (原列名, 新列名)
+    let mut copy_column_names: Vec<(String, String)> = columns.iter().filter(|column| !column.is_constraint()).map(|column| (column.column_name().to_owned(), column.column_name().to_owned())).collect();
+    for column in self.columns.iter() {
+      match &column.column_type_action {
+        column_type::ColumnTypeAction::AddColumn { .. } | column_type::ColumnTypeAction::AddConstraint => {
+          columns.push(column.clone());
+        },
+        column_type::ColumnTypeAction::ModifyColumn { .. } | column_type::ColumnTypeAction::ChangeColumn { .. } => {
+          let new_name = match &column.column_type_action {
+            column_type::ColumnTypeAction::ChangeColumn { new_name, .. } => new_name.to_owned(),
+            _ => column.column_name().to_owned(),
+          };
+          Self::rename_sqlite_rebuild_column(&mut columns, &mut copy_column_names, column.column_name(), &new_name);
+          let mut new_column = column.clone();
+          new_column.column_names = vec![new_name.clone()];
+          new_column.column_type_action = column_type::ColumnTypeAction::AddColumn { position: None };
+          if let Some(idx) = columns.iter().position(|origin_column| !origin_column.is_constraint() && origin_column.column_name() == new_name) {
+            new_column.inherit_index(&columns[idx]);
+            columns[idx] = new_column;
+          } else {
+            columns.push(new_column);
+          }
+        },
+        column_type::ColumnTypeAction::RenameColumn { new_name, .. } => {
+          Self::rename_sqlite_rebuild_column(&mut columns, &mut copy_column_names, column.column_name(), new_name);
+        },
+        column_type::ColumnTypeAction::DropColumn => {
+          columns.retain(|origin_column| origin_column.is_constraint() || origin_column.column_name() != column.column_name());
+          copy_column_names.retain(|(_, new_name)| new_name != column.column_name());
+        },
+        column_type::ColumnTypeAction::DropConstraint => {
+          columns.retain_mut(|origin_column| origin_column.drop_constraint(column));
+        },
+        column_type::ColumnTypeAction::RenameIndex { .. } => ()
+      }
+    }
+
+    let rebuild_table = DefineTable {
+      action: Some(ActionTable::CreateTable),
+      name: self.name.clone(),
+      columns,
+      ..Default::default()
+    };
+    Ok((rebuild_table, copy_column_names))
+  }
+  // 1. 创建新表 2. 复制数据 3. 删除原表 4. 新表重命名 5. 重建索引
+  fn to_sqlite_rebuild_sqls(&self, rebuild_table: &DefineTable, copy_column_names: Vec<(String, String)>, dialect: crate::Dialect) -> Result<Vec<Sql>, crate::SqlError> {
+    let table_name = dialect.quote_identifier(&self.name);
+    // 新表和原表在同一个 schema 中, eg: main.users => main.new_users
+    let new_table_name = match self.schema_and_table_name() {
+      (Some(schema), name) => dialect.quote_identifier(&format!("{}.new_{}", schema, name)),
+      (None, name) => dialect.quote_identifier(&format!("new_{}", name)),
+    };
+    let mut sqls = vec![rebuild_table.to_sqlite_create_table_sql(&new_table_name, dialect)?];
+    if !copy_column_names.is_empty() {
+      let (origin_column_names, new_column_names): (Vec<String>, Vec<String>) = copy_column_names.into_iter().unzip();
+      sqls.push(Sql::new(format!("INSERT INTO {} ({}) SELECT {} FROM {}", new_table_name, dialect.quote_identifiers(&new_column_names).join(", "), dialect.quote_identifiers(&origin_column_names).join(", "), table_name)));
+    }
+    sqls.push(Sql::new(format!("DROP TABLE {}", table_name)));
+    sqls.push(Sql::new(format!("ALTER TABLE {} RENAME TO {}", new_table_name, dialect.quote_identifier(self.schema_and_table_name().1))));
+    sqls.extend(rebuild_table.to_sqlite_statement_sqls(dialect));
+    Ok(sqls)
+  }
+  fn rename_sqlite_rebuild_column(columns: &mut [Column], copy_column_names: &mut [(String, String)], column_name: &str, new_column_name: &str) {
+    if column_name == new_column_name { return }
+    for column in columns.iter_mut() {
+      for name in column.column_names.iter_mut() {
+        if name == column_name { *name = new_column_name.to_owned(); }
+      }
+    }
+    for (_, name) in copy_column_names.iter_mut() {
+      if name == column_name { *name = new_column_name.to_owned(); }
+    }
+  }
+  // sqlite: 没有 RENAME INDEX, 删除后重新创建
+  fn to_sqlite_rename_index_sqls(&self, origin_columns: &[Column], dialect: crate::Dialect) -> Result<Vec<Sql>, crate::SqlError> {
+    let mut sqls = vec![];
+    for column in self.columns.iter() {
+      if let column_type::ColumnTypeAction::RenameIndex { new_name } = &column.column_type_action {
+        let origin_column = origin_columns.iter()
+          .find(|origin_column| origin_column.index_name().as_deref() == Some(column.column_name()))
+          .ok_or_else(|| crate::SqlError::DialectUnsupported { dialect: crate::Dialect::Sqlite, feature: "RENAME INDEX without origin table".to_owned() })?;
+        let unique = origin_column.column_type().unique().unwrap_or(false);
+        sqls.push(Sql::new(format!("DROP INDEX {}", self.sqlite_index_name(column.column_name(), dialect))));
+        sqls.push(Sql::new(format!("CREATE {}INDEX {} ON {} ({})", if unique { "UNIQUE " } else { "" }, self.sqlite_index_name(new_name, dialect), dialect.quote_identifier(self.schema_and_table_name().1), dialect.quote_identifiers(&origin_column.column_names).join(","))));
+      }
+    }
+    Ok(sqls)
+  }
+  // sqlite: schema 写在索引名前, ON 后只能是表名, eg: CREATE INDEX "main"."index_on_age" ON "users"
+  pub(crate) fn sqlite_index_name(&self, index_name: &str, dialect: crate::Dialect) -> String {
+    match self.schema_and_table_name().0 {
+      Some(schema) => format!("{}.{}", dialect.quote_identifier(schema), dialect.quote_identifier(index_name)),
+      None => dialect.quote_identifier(index_name),
+    }
+  }
+
+  // mssql: https://learn.microsoft.com/en-us/sql/t-sql/statements/create-table-transact-sql
+  // mssql: https://learn.microsoft.com/en-us/sql/t-sql/statements/alter-table-transact-sql
+  fn to_mssql_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::SqlError> {
+    let table_name = dialect.quote_identifier(&self.name);
+    match &self.action {
+      Some(ActionTable::CreateTable) => {
+        let mut definition_sqls = vec![];
+        for column in self.columns.iter() {
+          if let Some(column_sql) = column.to_sql(self, dialect)? {
+            definition_sqls.push(column_sql);
+          }
+        }
+        for column in self.columns.iter() {
+          if let Some(constraint_sql) = column.to_constraint_sql(self, dialect) {
+            definition_sqls.push(constraint_sql);
+          }
+        }
+        // mssql: 没有 CREATE TABLE IF NOT EXISTS
+        let mut sql = Sql::new(format!("IF OBJECT_ID({}, N'U') IS NULL CREATE TABLE {} (\n", quote_nstring(&self.name, dialect)?, table_name));
+        sql.push_sqls(&definition_sqls, ",\n").push('\n').push(')');
+        let mut sqls = vec![sql];
+        sqls.extend(self.to_mssql_statement_sqls(dialect)?);
+        Ok(Self::join_statement_sqls(&sqls))
+      },
+      // mssql: ALTER COLUMN 每条语句只能修改一列, 每个操作单独生成语句
+      Some(ActionTable::AltertTable { new_name }) => {
+        let mut sqls = vec![];
+        let mut rename_sqls = vec![];
+        for column in self.columns.iter() {
+          match &column.column_type_action {
+            column_type::ColumnTypeAction::AddColumn { .. } => {
+              if let Some(column_sql) = column.to_sql(self, dialect)? {
+                let mut sql = Sql::new(format!("ALTER TABLE {} ADD ", table_name));
+                sql.push_sql(&column_sql);
+                sqls.push(sql);
+              }
+            },
+            column_type::ColumnTypeAction::ModifyColumn { .. } => {
+              sqls.extend(column.to_mssql_alter_column_sqls(self, dialect)?);
+            },
+            // 先修改类型, 再重命名
+            column_type::ColumnTypeAction::ChangeColumn { new_name, .. } => {
+              sqls.extend(column.to_mssql_alter_column_sqls(self, dialect)?);
+              rename_sqls.push(Sql::new(format!("EXEC sp_rename {}, {}, N'COLUMN'", quote_nstring(&format!("{}.{}", self.name, column.column_name()), dialect)?, quote_nstring(new_name, dialect)?)));
+            },
+            column_type::ColumnTypeAction::RenameColumn { new_name, .. } => {
+              rename_sqls.push(Sql::new(format!("EXEC sp_rename {}, {}, N'COLUMN'", quote_nstring(&format!("{}.{}", self.name, column.column_name()), dialect)?, quote_nstring(new_name, dialect)?)));
+            },
+            column_type::ColumnTypeAction::DropColumn => {
+              sqls.push(Sql::new(format!("ALTER TABLE {} DROP COLUMN {}", table_name, dialect.quote_identifier(column.column_name()))));
+            },
+            column_type::ColumnTypeAction::AddConstraint |
+            column_type::ColumnTypeAction::DropConstraint |
+            column_type::ColumnTypeAction::RenameIndex { .. } => ()
+          }
+        }
+        for column in self.columns.iter() {
+          if let Some(constraint_sql) = column.to_constraint_sql(self, dialect) {
+            let mut sql = match column.column_type_action {
+              column_type::ColumnTypeAction::DropConstraint => Sql::new(format!("ALTER TABLE {} ", table_name)),
+              _ => Sql::new(format!("ALTER TABLE {} ADD ", table_name)),
+            };
+            sql.push_sql(&constraint_sql);
+            sqls.push(sql);
+          }
+        }
+        sqls.extend(rename_sqls);
+        sqls.extend(self.to_mssql_statement_sqls(dialect)?);
+        if let Some(new_name) = new_name {
+          sqls.push(Sql::new(format!("EXEC sp_rename {}, {}", quote_nstring(&self.name, dialect)?, quote_nstring(split_schema_name(new_name).1, dialect)?)));
+        }
+        Ok(Self::join_statement_sqls(&sqls))
       },
       Some(ActionTable::DropTable) => {
-        Sql::new(format!("DROP TABLE IF EXISTS {};", self.name))
+        Ok(Sql::new(format!("DROP TABLE IF EXISTS {};", table_name)))
       },
       None => {
-        Sql::default()
+        Ok(Sql::default())
+      }
+    }
+  }
+  // mssql: 索引和注释(扩展属性 MS_Description)不能写在表语句中
+  fn to_mssql_statement_sqls(&self, dialect: crate::Dialect) -> Result<Vec<Sql>, crate::SqlError> {
+    let mut sqls = vec![];
+    for column in self.columns.iter() {
+      if let Some(statement_sql) = column.to_statement_sql(self, dialect) {
+        sqls.push(statement_sql);
+      }
+    }
+    for column in self.columns.iter() {
+      let (is_new_column, column_name) = match &column.column_type_action {
+        column_type::ColumnTypeAction::AddColumn { .. } => (true, column.column_name()),
+        column_type::ColumnTypeAction::ModifyColumn { .. } => (false, column.column_name()),
+        column_type::ColumnTypeAction::ChangeColumn { new_name, .. } => (false, new_name.as_str()),
+        _ => continue,
+      };
+      if let Some(comment) = column.comment() {
+        let (schema, table_name) = self.schema_and_table_name();
+        let schema = schema.unwrap_or("dbo");
+        let arguments = format!("N'MS_Description', {}, N'SCHEMA', {}, N'TABLE', {}, N'COLUMN', {}", quote_nstring(comment, dialect)?, quote_nstring(schema, dialect)?, quote_nstring(table_name, dialect)?, quote_nstring(column_name, dialect)?);
+        if is_new_column {
+          sqls.push(Sql::new(format!("EXEC sp_addextendedproperty {}", arguments)));
+        } else {
+          // 已有的列不一定有注释, sp_updateextendedproperty 在没有注释时报错
+          let object_id = format!("OBJECT_ID({})", quote_nstring(&format!("{}.{}", schema, table_name), dialect)?);
+          sqls.push(Sql::new(format!(
+            "IF EXISTS (SELECT 1 FROM sys.extended_properties WHERE major_id = {} AND minor_id = COLUMNPROPERTY({}, {}, 'ColumnId') AND name = N'MS_Description') EXEC sp_updateextendedproperty {} ELSE EXEC sp_addextendedproperty {}",
+            object_id, object_id, quote_nstring(column_name, dialect)?, arguments, arguments,
+          )));
+        }
       }
     }
+    Ok(sqls)
+  }
+
+  // 每条语句以 ; 结尾, 换行分隔
+  fn join_statement_sqls(sqls: &[Sql]) -> Sql {
+    let mut sql = Sql::default();
+    for (idx, statement_sql) in sqls.iter().enumerate() {
+      if idx > 0 { sql.push('\n'); }
+      sql.push_sql(statement_sql).push(';');
+    }
+    sql
+  }
+}
+
+// mssql: 系统存储过程和 OBJECT_ID 的参数为 nvarchar, eg: N'users'
+fn quote_nstring(value: &str, dialect: crate::Dialect) -> Result<String, crate::SqlError> {
+  let string = dialect.quote_string(value)?;
+  Ok(if string.starts_with('N') { string } else { format!("N{}", string) })
+}
+
+// eg: sales.orders => (Some("sales"), "orders"), orders => (None, "orders")
+pub(crate) fn split_schema_name(name: &str) -> (Option<&str>, &str) {
+  match name.rsplit_once('.') {
+    Some((schema, name)) => (Some(schema), name),
+    None => (None, name),
   }
 }
 
 impl TryFrom<DefineTable> for String {
   type Error = crate::SqlError;
   fn try_from(table: DefineTable) -> Result<String, Self::Error> {
-    table.to_sql().to_sql_string()
+    table.to_sql(crate::Dialect::default())?.to_sql_string()
   }
 }
 
@@ -584,12 +1067,14 @@ pub enum ReferenceOption {
   Custom(String),
 }
 impl ReferenceOption {
-  fn to_sql(&self) -> Sql {
+  fn to_sql(&self, dialect: crate::Dialect) -> Sql {
     match self {
       ReferenceOption::NoAction => Sql::new("NO ACTION".to_owned()),
       ReferenceOption::SetNull => Sql::new("SET NULL".to_owned()),
       // 父表更新时子表也更新，父表删除时子表匹配的项也删除
       ReferenceOption::Cascade => Sql::new("CANSCADE".to_owned()),
+      // mssql: 没有 RESTRICT, NO ACTION 效果相同
+      ReferenceOption::Restrict if dialect == crate::Dialect::Mssql => Sql::new("NO ACTION".to_owned()),
       ReferenceOption::Restrict => Sql::new("RESTRICT".to_owned()),
       ReferenceOption::Custom(custom) => Sql::new(custom.to_owned()),
     }
@@ -612,7 +1097,7 @@ impl From<&str> for ForeignKey {
     ForeignKey {
       column_names: vec![column_name.into()],
       index_name: Some(format!("fk_on_{}", column_name)),
-      reference_table_name: inflector::string::pluralize::to_plural(regex::Regex::new(r"_id$").unwrap().replace(&column_name, "").as_ref()),
+      reference_table_name: inflector::string::pluralize::to_plural(regex::Regex::new(r"_id$").unwrap().replace(column_name, "").as_ref()),
       reference_table_column_names: vec!["id".into()],
       reference_on_update_option: None,
       reference_on_delete_option: None,
@@ -621,21 +1106,48 @@ impl From<&str> for ForeignKey {
 }
 
 impl ForeignKey {
-  pub fn to_sql(&self, column: &crate::Column) -> Sql {
+  pub fn reference_table_name(&self) -> &str {
+    &self.reference_table_name
+  }
+  pub fn to_sql(&self, column: &crate::Column, dialect: crate::Dialect) -> Sql {
+    if dialect != crate::Dialect::Mysql {
+      return self.to_standard_sql(column, dialect);
+    }
     let mut sql = Sql::new("FOREIGN KEY".to_owned());
     if let Some(index_name) = &self.index_name {
-      sql.push_value(&format!(" {}", index_name));
+      sql.push_value(&format!(" {}", dialect.quote_identifier(index_name)));
     }
     if column.column_type_action == column_type::ColumnTypeAction::DropConstraint {
       return sql;
     }
-    sql.push_value(&format!(" ({}) REFERENCES {} ({})", self.column_names.join(","), self.reference_table_name, self.reference_table_column_names.join(",")));
+    sql.push_value(&format!(" ({}) REFERENCES {} ({})", dialect.quote_identifiers(&self.column_names).join(","), dialect.quote_identifier(&self.reference_table_name), dialect.quote_identifiers(&self.reference_table_column_names).join(",")));
+    if let Some(reference_on_update_option) = &self.reference_on_update_option {
+      sql.push(' ').push_sql(&reference_on_update_option.to_sql(dialect));
+    }
+    if let Some(reference_on_delete_option) = &self.reference_on_delete_option {
+      sql.push(' ').push_sql(&reference_on_delete_option.to_sql(dialect));
+    }
+    sql
+  }
+  pub fn name<'a>(&'a self, column: &'a crate::Column) -> &'a str {
+    self.index_name.as_deref().unwrap_or(column.column_name())
+  }
+  // postgresql, sqlite, mssql: 约束名写在 CONSTRAINT 之后, 删除时使用 DROP CONSTRAINT
+  fn to_standard_sql(&self, column: &crate::Column, dialect: crate::Dialect) -> Sql {
+    if column.column_type_action == column_type::ColumnTypeAction::DropConstraint {
+      return Sql::new(format!("DROP CONSTRAINT {}", dialect.quote_identifier(self.name(column))));
+    }
+    let mut sql = Sql::default();
+    if let Some(index_name) = &self.index_name {
+      sql.push_value(&format!("CONSTRAINT {} ", dialect.quote_identifier(index_name)));
+    }
+    sql.push_value(&format!("FOREIGN KEY ({}) REFERENCES {} ({})", dialect.quote_identifiers(&self.column_names).join(","), dialect.quote_identifier(&self.reference_table_name), dialect.quote_identifiers(&self.reference_table_column_names).join(",")));
     if let Some(reference_on_update_option) = &self.reference_on_update_option {
-      sql.push(' ').push_sql(&reference_on_update_option.to_sql());
+      sql.push(' ').push_sql(&reference_on_update_option.to_sql(dialect));
     }
     if let Some(reference_on_delete_option) = &self.reference_on_delete_option {
-      sql.push(' ').push_sql(&reference_on_delete_option.to_sql());
+      sql.push(' ').push_sql(&reference_on_delete_option.to_sql(dialect));
     }
     sql
   }
-}
\ No newline at end of file
+}
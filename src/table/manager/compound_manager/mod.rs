@@ -0,0 +1,245 @@
+use std::marker::PhantomData;
+use crate::table::statements;
+use crate::collectors::Sql;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SetOperator {
+  Union,
+  UnionAll,
+  Intersect,
+  Except,
+}
+
+impl SetOperator {
+  fn to_sql(self) -> &'static str {
+    match self {
+      SetOperator::Union => "UNION",
+      SetOperator::UnionAll => "UNION ALL",
+      SetOperator::Intersect => "INTERSECT",
+      SetOperator::Except => "EXCEPT",
+    }
+  }
+}
+
+// UNION/INTERSECT/EXCEPT 组合的查询, ORDER BY 的列名按 M 转换
+pub struct CompoundManager<M: crate::Manageable> {
+  first: statements::Subquery,
+  rest: Vec<(SetOperator, statements::Subquery)>,
+  orders: Option<Vec<statements::Order<M>>>,
+  limit: Option<statements::Limit<M>>,
+  offset: Option<statements::Offset<M>>,
+
+  _marker: PhantomData<M>,
+}
+
+impl<M: crate::Manageable + Send + Sync + 'static> From<crate::manager::SelectManager<M>> for CompoundManager<M> {
+  fn from(select_manager: crate::manager::SelectManager<M>) -> Self {
+    Self {
+      first: select_manager.into(),
+      rest: vec![],
+      orders: None,
+      limit: None,
+      offset: None,
+
+      _marker: PhantomData,
+    }
+  }
+}
+
+impl<M: crate::Manageable> CompoundManager<M> {
+  pub fn push<N: crate::Manageable + Send + Sync + 'static>(&mut self, set_operator: SetOperator, select_manager: crate::manager::SelectManager<N>) -> &mut Self {
+    self.rest.push((set_operator, select_manager.into()));
+    self
+  }
+  pub fn union<N: crate::Manageable + Send + Sync + 'static>(&mut self, select_manager: crate::manager::SelectManager<N>) -> &mut Self {
+    self.push(SetOperator::Union, select_manager)
+  }
+  pub fn union_all<N: crate::Manageable + Send + Sync + 'static>(&mut self, select_manager: crate::manager::SelectManager<N>) -> &mut Self {
+    self.push(SetOperator::UnionAll, select_manager)
+  }
+  pub fn intersect<N: crate::Manageable + Send + Sync + 'static>(&mut self, select_manager: crate::manager::SelectManager<N>) -> &mut Self {
+    self.push(SetOperator::Intersect, select_manager)
+  }
+  pub fn except<N: crate::Manageable + Send + Sync + 'static>(&mut self, select_manager: crate::manager::SelectManager<N>) -> &mut Self {
+    self.push(SetOperator::Except, select_manager)
+  }
+  pub fn order<T: serde::Serialize>(&mut self, condition: T) -> &mut Self {
+    let order = statements::Order::<M>::new(serde_json::json!(condition));
+    if let Some(orders) = &mut self.orders {
+      orders.push(order);
+    } else {
+      self.orders = Some(vec![order])
+    }
+    self
+  }
+  pub fn limit(&mut self, value: usize) -> &mut Self {
+    self.limit = Some(statements::Limit::new(value));
+    self
+  }
+  pub fn offset(&mut self, value: usize) -> &mut Self {
+    self.offset = Some(statements::Offset::new(value));
+    self
+  }
+  pub fn paginate(&mut self, page: usize, page_size: usize) -> &mut Self {
+    // page 从 1 开始, 0 按第一页处理
+    let offset = page.saturating_sub(1) * page_size;
+    self.limit(page_size);
+    self.offset(offset);
+    self
+  }
+  // sqlite: 不支持带括号的 SELECT, 使用 SELECT * FROM (...)
+  fn operand_to_sql(&self, subquery: &statements::Subquery, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    let mut sql = Sql::default();
+    if dialect == crate::Dialect::Sqlite {
+      sql.push_value("SELECT * FROM ");
+    }
+    sql.push_sql(&subquery.to_sql(dialect)?);
+    Ok(sql)
+  }
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    let mut sql = self.operand_to_sql(&self.first, dialect)?;
+    let mut previous_operator: Option<SetOperator> = None;
+    for (set_operator, subquery) in self.rest.iter() {
+      // 运算符变化时左侧整体分组, 保证按调用顺序计算 (mysql/postgresql/mssql 中 INTERSECT 优先级更高)
+      // sqlite: 不支持带括号的组合查询, 使用派生表
+      if previous_operator.is_some_and(|previous_operator| previous_operator != *set_operator) {
+        let mut group = Sql::default();
+        if dialect == crate::Dialect::Sqlite {
+          group.push_value("SELECT * FROM ");
+        }
+        group.push('(').push_sql(&sql).push(')');
+        sql = group;
+      }
+      sql.push_value(&format!(" {} ", set_operator.to_sql())).push_sql(&self.operand_to_sql(subquery, dialect)?);
+      previous_operator = Some(*set_operator);
+    }
+
+    if let Some(ref orders) = self.orders {
+      sql.push_value(" ORDER BY ");
+      for (idx, order) in orders.iter().enumerate() {
+        if idx > 0 {
+          sql.push(',');
+        }
+        sql.push_sql(&order.to_sql(dialect)?);
+      }
+    }
+
+    // mssql: OFFSET ... ROWS FETCH NEXT ... ROWS ONLY 必须跟在 ORDER BY 之后
+    if dialect == crate::Dialect::Mssql {
+      if self.limit.is_some() || self.offset.is_some() {
+        if self.orders.is_none() {
+          sql.push_value(" ORDER BY (SELECT NULL)");
+        }
+        if let Some(ref offset) = self.offset {
+          sql.push(' ').push_sql(&offset.to_sql(dialect)?);
+        } else {
+          sql.push_value(" OFFSET 0 ROWS");
+        }
+        if let Some(ref limit) = self.limit {
+          sql.push(' ').push_sql(&limit.to_sql(dialect)?);
+        }
+      }
+    } else {
+      if let Some(ref limit) = self.limit {
+        sql.push(' ').push_sql(&limit.to_sql(dialect)?);
+      }
+
+      if let Some(ref offset) = self.offset {
+        // sqlite: OFFSET 必须和 LIMIT 一起使用
+        if self.limit.is_none() && dialect == crate::Dialect::Sqlite {
+          sql.push_value(" LIMIT -1");
+        }
+        sql.push(' ').push_sql(&offset.to_sql(dialect)?);
+      }
+    }
+
+    sql.dialect = Some(dialect);
+    sql.set_placeholder_style(dialect.placeholder_style());
+
+    Ok(sql)
+  }
+}
+
+impl<M: crate::Manageable + Send + Sync + 'static> From<CompoundManager<M>> for statements::Subquery {
+  fn from(compound_manager: CompoundManager<M>) -> Self {
+    statements::Subquery::new(move |dialect| compound_manager.to_sql(dialect))
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn to_sql() {
+      struct Order {}
+      impl crate::Manageable for Order {}
+      struct ArchivedOrder {}
+      impl crate::Manageable for ArchivedOrder {}
+
+      let mut live = crate::manager::SelectManager::<Order>::default();
+      live.select(["id", "amount"]).where_prepare(serde_json::json!({"user_id": 1})).order(serde_json::json!({"id": "desc"})).limit(5);
+      let mut archived = crate::manager::SelectManager::<ArchivedOrder>::default();
+      archived.select(["id", "amount"]).where_prepare(serde_json::json!({"user_id": 2}));
+      let mut compound_manager = live.union_all(archived);
+      compound_manager.order(serde_json::json!({"amount": "desc"})).paginate(2, 10);
+
+      let sql = compound_manager.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(&sql.value, "(SELECT \"id\",\"amount\" FROM \"orders\" WHERE \"user_id\" = $1 ORDER BY \"id\" desc LIMIT 5) UNION ALL (SELECT \"id\",\"amount\" FROM \"archived_orders\" WHERE \"user_id\" = $2) ORDER BY \"amount\" desc LIMIT 10 OFFSET 10");
+      assert_eq!(sql.prepare_value, Some(vec![crate::SqlValue::Int(1), crate::SqlValue::Int(2)]));
+      assert_eq!(String::try_from(compound_manager.to_sql(crate::Dialect::Sqlite).unwrap()).unwrap(), "SELECT * FROM (SELECT \"id\",\"amount\" FROM \"orders\" WHERE \"user_id\" = 1 ORDER BY \"id\" desc LIMIT 5) UNION ALL SELECT * FROM (SELECT \"id\",\"amount\" FROM \"archived_orders\" WHERE \"user_id\" = 2) ORDER BY \"amount\" desc LIMIT 10 OFFSET 10");
+      assert_eq!(compound_manager.to_sql(crate::Dialect::Mssql).unwrap().to_prepared_string(crate::PlaceholderStyle::AtP), "(SELECT [id],[amount] FROM [orders] WHERE [user_id] = @p1 ORDER BY [id] desc OFFSET 0 ROWS FETCH NEXT 5 ROWS ONLY) UNION ALL (SELECT [id],[amount] FROM [archived_orders] WHERE [user_id] = @p2) ORDER BY [amount] desc OFFSET 10 ROWS FETCH NEXT 10 ROWS ONLY");
+      // page 为 0 时按第一页处理
+      compound_manager.paginate(0, 10);
+      assert!(compound_manager.to_sql(crate::Dialect::Postgresql).unwrap().value.ends_with("ORDER BY \"amount\" desc LIMIT 10 OFFSET 0"));
+    }
+    #[test]
+    fn to_sql_with_set_operators() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let mut active = crate::manager::SelectManager::<User>::default();
+      active.select(["id"]).r#where(serde_json::json!({"active": true}));
+      let mut admins = crate::manager::SelectManager::<User>::default();
+      admins.select(["id"]).r#where(serde_json::json!({"role": "admin"}));
+      let mut banned = crate::manager::SelectManager::<User>::default();
+      banned.select(["id"]).r#where(serde_json::json!({"banned": true}));
+      let mut guests = crate::manager::SelectManager::<User>::default();
+      guests.select(["id"]).r#where(serde_json::json!({"role": "guest"}));
+      let mut compound_manager = active.intersect(admins);
+      compound_manager.except(banned).union(guests);
+      assert_eq!(String::try_from(compound_manager.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "(((SELECT `id` FROM `users` WHERE `active` = 1) INTERSECT (SELECT `id` FROM `users` WHERE `role` = 'admin')) EXCEPT (SELECT `id` FROM `users` WHERE `banned` = 1)) UNION (SELECT `id` FROM `users` WHERE `role` = 'guest')");
+
+      // 作为子查询
+      let mut select_manager = crate::manager::SelectManager::<User>::default();
+      select_manager.from_subquery(compound_manager, "ids");
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "SELECT * FROM ((((SELECT `id` FROM `users` WHERE `active` = 1) INTERSECT (SELECT `id` FROM `users` WHERE `role` = 'admin')) EXCEPT (SELECT `id` FROM `users` WHERE `banned` = 1)) UNION (SELECT `id` FROM `users` WHERE `role` = 'guest')) AS `ids`");
+    }
+    #[test]
+    fn to_sql_with_mixed_set_operators() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let build = || {
+        let mut a = crate::manager::SelectManager::<User>::default();
+        a.select(["id"]).r#where(serde_json::json!({"role": "a"}));
+        let mut b = crate::manager::SelectManager::<User>::default();
+        b.select(["id"]).r#where(serde_json::json!({"role": "b"}));
+        let mut c = crate::manager::SelectManager::<User>::default();
+        c.select(["id"]).r#where(serde_json::json!({"role": "c"}));
+        let mut compound_manager = a.union(b);
+        compound_manager.intersect(c);
+        compound_manager
+      };
+      // (a UNION b) INTERSECT c, 不能写成 a UNION b INTERSECT c
+      assert_eq!(build().to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap(), "((SELECT `id` FROM `users` WHERE `role` = 'a') UNION (SELECT `id` FROM `users` WHERE `role` = 'b')) INTERSECT (SELECT `id` FROM `users` WHERE `role` = 'c')");
+      assert_eq!(build().to_sql(crate::Dialect::Postgresql).unwrap().to_sql_string().unwrap(), "((SELECT \"id\" FROM \"users\" WHERE \"role\" = 'a') UNION (SELECT \"id\" FROM \"users\" WHERE \"role\" = 'b')) INTERSECT (SELECT \"id\" FROM \"users\" WHERE \"role\" = 'c')");
+      assert_eq!(build().to_sql(crate::Dialect::Sqlite).unwrap().to_sql_string().unwrap(), "SELECT * FROM (SELECT * FROM (SELECT \"id\" FROM \"users\" WHERE \"role\" = 'a') UNION SELECT * FROM (SELECT \"id\" FROM \"users\" WHERE \"role\" = 'b')) INTERSECT SELECT * FROM (SELECT \"id\" FROM \"users\" WHERE \"role\" = 'c')");
+      assert_eq!(build().to_sql(crate::Dialect::Mssql).unwrap().to_sql_string().unwrap(), "((SELECT [id] FROM [users] WHERE [role] = 'a') UNION (SELECT [id] FROM [users] WHERE [role] = 'b')) INTERSECT (SELECT [id] FROM [users] WHERE [role] = 'c')");
+      // 运算符相同时不分组
+      let mut a = crate::manager::SelectManager::<User>::default();
+      a.select(["id"]);
+      let mut compound_manager = a.union(crate::manager::SelectManager::<User>::default());
+      compound_manager.union(crate::manager::SelectManager::<User>::default());
+      assert_eq!(compound_manager.to_sql(crate::Dialect::Mysql).unwrap().to_sql_string().unwrap(), "(SELECT `id` FROM `users`) UNION (SELECT * FROM `users`) UNION (SELECT * FROM `users`)");
+    }
+}
@@ -2,6 +2,7 @@ use std::marker::PhantomData;
 use crate::table::statements;
 use crate::collectors::Sql;
 pub struct DeleteManager<M: crate::Manageable> {
+  with: Option<statements::With>,
   wheres: Option<Vec<statements::Where<M>>>,
   orders: Option<Vec<statements::Order<M>>>,
   limit: Option<statements::Limit<M>>,
@@ -14,6 +15,7 @@ pub struct DeleteManager<M: crate::Manageable> {
 impl<M: crate::Manageable> Default for DeleteManager<M> {
   fn default() -> Self {
       Self {
+        with: None,
         wheres: None,
         orders: None,
         limit: None,
@@ -25,8 +27,25 @@ impl<M: crate::Manageable> Default for DeleteManager<M> {
 }
 
 impl<M: crate::Manageable> DeleteManager<M> {
+  // eg: WITH paid_orders AS (SELECT ...) SELECT ...
+  pub fn with<T: Into<statements::Subquery>>(&mut self, name: &str, query: T) -> &mut Self {
+    self.with_cte(statements::Cte::new(name, query))
+  }
+  pub fn with_cte(&mut self, cte: statements::Cte) -> &mut Self {
+    self.with.get_or_insert_with(statements::With::default).ctes.push(cte);
+    self
+  }
+  // eg: WITH RECURSIVE tree (id, parent_id) AS (anchor UNION ALL recursive)
+  pub fn with_recursive<A: crate::Manageable + Send + Sync + 'static, R: crate::Manageable + Send + Sync + 'static>(&mut self, name: &str, column_names: &[&str], anchor: crate::manager::SelectManager<A>, recursive: crate::manager::SelectManager<R>) -> &mut Self {
+    let mut cte = statements::Cte::new(name, statements::Subquery::union_all(anchor.into(), recursive.into()));
+    if !column_names.is_empty() {
+      cte.column_names = Some(column_names.iter().map(|column_name| column_name.to_string()).collect());
+    }
+    self.with.get_or_insert_with(statements::With::default).recursive = true;
+    self.with_cte(cte)
+  }
   pub fn r#where<T: serde::Serialize>(&mut self, condition: T) -> &mut Self {
-    let r#where = statements::Where::<M>::new(serde_json::json!(condition), None, None, None);
+    let r#where = statements::Where::<M>::new(serde_json::json!(condition));
     if let Some(wheres) = &mut self.wheres {
       wheres.push(r#where);
     } else {
@@ -34,8 +53,9 @@ impl<M: crate::Manageable> DeleteManager<M> {
     }
     self
   }
-  pub fn where_not(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, Some(true), None, None);
+  // eg: Expr::eq("a", 1).or(Expr::eq("b", 2)).and(Expr::is_null("c"))
+  pub fn where_expr(&mut self, expr: statements::Expr) -> &mut Self {
+    let r#where = statements::Where::<M>::new_expr(expr);
     if let Some(wheres) = &mut self.wheres {
       wheres.push(r#where);
     } else {
@@ -43,53 +63,30 @@ impl<M: crate::Manageable> DeleteManager<M> {
     }
     self
   }
+  // 每一项取反, eg: {"a": 1, "b": [1, 2]}: a != 1 AND b NOT IN (1,2)
+  pub fn where_not(&mut self, condition: serde_json::Value) -> &mut Self {
+    self.where_expr(statements::Expr::And(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()))
+  }
   pub fn where_or(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, None, Some(true), None);
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition)))
   }
   pub fn where_not_or(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, Some(true), Some(true), None);
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()))
   }
   pub fn where_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, None, None, Some(true));
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::from_json(&condition).prepare())
   }
   pub fn where_not_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, Some(true), None, Some(true));
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::And(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()).prepare())
   }
   pub fn where_or_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, None, Some(true), Some(true));
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition)).prepare())
   }
   pub fn where_not_or_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, Some(true), Some(true), Some(true));
+    self.where_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()).prepare())
+  }
+  pub fn where_range<T: Clone + Into<crate::collectors::SqlValue>>(&mut self, column_name: &str, range: impl std::ops::RangeBounds<T>) -> &mut Self {
+    let r#where = statements::Where::<M>::new_range(column_name, range, None);
     if let Some(wheres) = &mut self.wheres {
       wheres.push(r#where);
     } else {
@@ -97,8 +94,8 @@ impl<M: crate::Manageable> DeleteManager<M> {
     }
     self
   }
-  pub fn where_range<T: ToString>(&mut self, column_name: &str, range: impl std::ops::RangeBounds<T>) -> &mut Self {
-    let r#where = statements::Where::<M>::new_range(column_name, range);
+  pub fn where_range_prepare<T: Clone + Into<crate::collectors::SqlValue>>(&mut self, column_name: &str, range: impl std::ops::RangeBounds<T>) -> &mut Self {
+    let r#where = statements::Where::<M>::new_range(column_name, range, Some(true));
     if let Some(wheres) = &mut self.wheres {
       wheres.push(r#where);
     } else {
@@ -129,42 +126,88 @@ impl<M: crate::Manageable> DeleteManager<M> {
     self.offset(offset);
     self
 }
-  pub fn to_sql(&self) -> Result<Sql, crate::error::SqlError> {
-    let mut sql = Sql::new(format!("DELETE FROM {}", M::table_name()));
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    let mut sql = Sql::new("DELETE ".to_owned());
 
+    let mut where_sql = Sql::default();
     if let Some(ref wheres) = self.wheres {
-      sql.push_value(" WHERE");
+      where_sql.push_value(" WHERE");
       for (idx, r#where) in wheres.iter().enumerate() {
         if idx > 0 {
-          sql.push_value(" AND");
+          where_sql.push_value(" AND");
         }
-        if let Some(or) = r#where.or && or {
-          sql.push_value(" (").push_sql(&r#where.to_sql()?).push(')');
+        if r#where.is_or() {
+          where_sql.push_value(" (").push_sql(&r#where.to_sql(dialect)?).push(')');
         } else {
-          sql.push(' ').push_sql(&r#where.to_sql()?);
+          where_sql.push(' ').push_sql(&r#where.to_sql(dialect)?);
         }
       }
     } else {
-      return Err(crate::error::SqlError::Message("delete table data where statements must exist".into()))
+      return Err(crate::error::SqlError::MissingWhere { statement: "DELETE" })
     }
 
+    let mut order_sql = Sql::default();
     if let Some(ref orders) = self.orders {
-      sql.push_value(" ORDER BY ");
+      order_sql.push_value(" ORDER BY ");
       for (idx, order) in orders.iter().enumerate() {
         if idx > 0 {
-          sql.push(',');
+          order_sql.push(',');
         }
-        sql.push_sql(&order.to_sql()?);
+        order_sql.push_sql(&order.to_sql(dialect)?);
       }
     }
 
-    if let Some(ref limit) = self.limit {
-      sql.push(' ').push_sql(&limit.to_sql()?);
+    // mssql: 只有 LIMIT 时使用 TOP, 否则在子查询中使用 OFFSET ... ROWS FETCH NEXT ... ROWS ONLY
+    let mut top = None;
+    if dialect == crate::Dialect::Mssql {
+      if self.orders.is_none() && self.offset.is_none() {
+        top = self.limit.as_ref().map(|limit| limit.value);
+      } else {
+        if self.orders.is_none() {
+          order_sql.push_value(" ORDER BY (SELECT NULL)");
+        }
+        if let Some(ref offset) = self.offset {
+          order_sql.push(' ').push_sql(&offset.to_sql(dialect)?);
+        } else {
+          order_sql.push_value(" OFFSET 0 ROWS");
+        }
+        if let Some(ref limit) = self.limit {
+          order_sql.push(' ').push_sql(&limit.to_sql(dialect)?);
+        }
+      }
+    } else {
+      if let Some(ref limit) = self.limit {
+        order_sql.push(' ').push_sql(&limit.to_sql(dialect)?);
+      }
+
+      if let Some(ref offset) = self.offset {
+        // sqlite: OFFSET 必须和 LIMIT 一起使用
+        if self.limit.is_none() && dialect == crate::Dialect::Sqlite {
+          order_sql.push_value(" LIMIT -1");
+        }
+        order_sql.push(' ').push_sql(&offset.to_sql(dialect)?);
+      }
     }
 
-    if let Some(ref offset) = self.offset {
-      sql.push(' ').push_sql(&offset.to_sql()?);
+    if let Some(top) = top {
+      sql.push_value(&format!("TOP ({}) ", top));
     }
+    sql.push_value(&format!("FROM {}", dialect.try_quote_identifier(&M::table_name())?));
+    // postgresql, sqlite, mssql: DELETE 不支持 ORDER BY, LIMIT, OFFSET, 通过主键子查询实现
+    if dialect != crate::Dialect::Mysql && !order_sql.is_empty() {
+      let primary_key = dialect.try_quote_identifier(M::primary_key())?;
+      sql.push_value(&format!(" WHERE {} IN (SELECT {} FROM {}", primary_key, primary_key, dialect.try_quote_identifier(&M::table_name())?));
+      sql.push_sql(&where_sql).push_sql(&order_sql).push(')');
+    } else {
+      sql.push_sql(&where_sql).push_sql(&order_sql);
+    }
+    if let Some(ref with) = self.with {
+      let mut with_sql = with.to_sql(dialect)?;
+      with_sql.push(' ').push_sql(&sql);
+      sql = with_sql;
+    }
+    sql.dialect = Some(dialect);
+    sql.set_placeholder_style(dialect.placeholder_style());
 
     Ok(sql)
   }
@@ -179,79 +222,162 @@ mod tests {
       struct User {}
       impl crate::Manageable for User {}
 
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         let delete_manager = DeleteManager::<User>::default();
-        assert!(delete_manager.to_sql().is_err());
+        assert!(delete_manager.to_sql(crate::Dialect::Mysql).is_err());
 
         // where
         let mut delete_manager = DeleteManager::<User>::default();
         delete_manager.r#where("a = 1");
-        let sql_string: String = delete_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "DELETE FROM users WHERE a = 1");
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM `users` WHERE a = 1");
         let mut delete_manager = DeleteManager::<User>::default();
         delete_manager.r#where(serde_json::json!("a = 1"));
-        let sql_string: String = delete_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "DELETE FROM users WHERE a = 1");
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM `users` WHERE a = 1");
 
         let mut delete_manager = DeleteManager::<User>::default();
         delete_manager.r#where(serde_json::json!(["a = ?", 1]));
-        let sql_string: String = delete_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "DELETE FROM users WHERE a = 1");
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM `users` WHERE a = 1");
         delete_manager.where_not(serde_json::json!({"b": [1, 2, 3]}));
-        let sql_string: String = delete_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "DELETE FROM users WHERE a = 1 AND b NOT IN (1,2,3)");
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM `users` WHERE a = 1 AND `b` NOT IN (1,2,3)");
         delete_manager.where_or(serde_json::json!({"c1": true, "c2": false}));
-        let sql_string: String = delete_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "DELETE FROM users WHERE a = 1 AND b NOT IN (1,2,3) AND (c1 = 1 OR c2 = 0)");
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM `users` WHERE a = 1 AND `b` NOT IN (1,2,3) AND (`c1` = 1 OR `c2` = 0)");
         delete_manager.where_not_prepare(serde_json::json!({"d": [1, 2, 3]}));
-        let sql = delete_manager.to_sql().unwrap();
-        assert_eq!(&sql.value, "DELETE FROM users WHERE a = ? AND b NOT IN (1,2,3) AND (c1 = 1 OR c2 = 0) AND d NOT IN (?,?,?)");
+        let sql = delete_manager.to_sql(crate::Dialect::Mysql).unwrap();
+        assert_eq!(&sql.value, "DELETE FROM `users` WHERE a = ? AND `b` NOT IN (1,2,3) AND (`c1` = 1 OR `c2` = 0) AND `d` NOT IN (?,?,?)");
         let sql_string: String = sql.try_into().unwrap();
-        assert_eq!(&sql_string, "DELETE FROM users WHERE a = 1 AND b NOT IN (1,2,3) AND (c1 = 1 OR c2 = 0) AND d NOT IN (1,2,3)");
+        assert_eq!(&sql_string, "DELETE FROM `users` WHERE a = 1 AND `b` NOT IN (1,2,3) AND (`c1` = 1 OR `c2` = 0) AND `d` NOT IN (1,2,3)");
 
         let mut delete_manager = DeleteManager::<User>::default();
         delete_manager.where_range("id", 1..100);
-        let sql_string: String = delete_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "DELETE FROM users WHERE id >= 1 AND id < 100");
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM `users` WHERE `id` >= 1 AND `id` < 100");
         let mut delete_manager = DeleteManager::<User>::default();
         delete_manager.where_range("id", 1..=100);
-        let sql_string: String = delete_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "DELETE FROM users WHERE id BETWEEN 1 AND 100");
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM `users` WHERE `id` BETWEEN 1 AND 100");
 
         // order
         let mut delete_manager = DeleteManager::<User>::default();
         delete_manager.order("id desc");
         delete_manager.order(serde_json::json!({"created_at": "desc"}));
         delete_manager.order(vec!["updated_at desc"]);
-        assert!(delete_manager.to_sql().is_err());
+        assert!(delete_manager.to_sql(crate::Dialect::Mysql).is_err());
         delete_manager.where_not_or(serde_json::json!({"a": 1, "b": [1, 2, 3]}));
-        let sql_string: String = delete_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "DELETE FROM users WHERE (a != 1 OR b NOT IN (1,2,3)) ORDER BY id desc,created_at desc,updated_at desc");
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM `users` WHERE (`a` != 1 OR `b` NOT IN (1,2,3)) ORDER BY id desc,`created_at` desc,updated_at desc");
 
         // limit
         let mut delete_manager = DeleteManager::<User>::default();
         delete_manager.limit(10);
         delete_manager.r#where("a = 1");
-        let sql_string: String = delete_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "DELETE FROM users WHERE a = 1 LIMIT 10");
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM `users` WHERE a = 1 LIMIT 10");
 
         // offset
         let mut delete_manager = DeleteManager::<User>::default();
         delete_manager.offset(5);
         delete_manager.r#where("a = 1");
-        let sql_string: String = delete_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "DELETE FROM users WHERE a = 1 OFFSET 5");
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM `users` WHERE a = 1 OFFSET 5");
 
         // paginate
         let mut delete_manager = DeleteManager::<User>::default();
         delete_manager.r#where("a = 1");
         delete_manager.paginate(1, 20);
-        let sql_string: String = delete_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "DELETE FROM users WHERE a = 1 LIMIT 20 OFFSET 0");
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM `users` WHERE a = 1 LIMIT 20 OFFSET 0");
+        delete_manager.paginate(2, 20);
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM `users` WHERE a = 1 LIMIT 20 OFFSET 20");
+      }
+      // postgresql
+      {
+        let mut delete_manager = DeleteManager::<User>::default();
+        delete_manager.r#where(serde_json::json!(["a = ?", 1]));
+        delete_manager.where_or_prepare(serde_json::json!({"c1": true, "c2": false}));
+        let sql = delete_manager.to_sql(crate::Dialect::Postgresql).unwrap();
+        assert_eq!(&sql.value, "DELETE FROM \"users\" WHERE a = $1 AND (\"c1\" = $2 OR \"c2\" = $3)");
+        let sql_string: String = sql.try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM \"users\" WHERE a = 1 AND (\"c1\" = true OR \"c2\" = false)");
+
+        // paginate
+        let mut delete_manager = DeleteManager::<User>::default();
+        delete_manager.r#where("a = 1");
         delete_manager.paginate(2, 20);
-        let sql_string: String = delete_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "DELETE FROM users WHERE a = 1 LIMIT 20 OFFSET 20");
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Postgresql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM \"users\" WHERE \"id\" IN (SELECT \"id\" FROM \"users\" WHERE a = 1 LIMIT 20 OFFSET 20)");
       }
+      // sqlite
+      {
+        let mut delete_manager = DeleteManager::<User>::default();
+        delete_manager.where_prepare(serde_json::json!({"c1": true}));
+        let sql = delete_manager.to_sql(crate::Dialect::Sqlite).unwrap();
+        assert_eq!(&sql.value, "DELETE FROM \"users\" WHERE \"c1\" = ?");
+        let sql_string: String = sql.try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM \"users\" WHERE \"c1\" = 1");
+
+        // offset
+        let mut delete_manager = DeleteManager::<User>::default();
+        delete_manager.r#where("a = 1");
+        delete_manager.order("id desc");
+        delete_manager.offset(5);
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Sqlite).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM \"users\" WHERE \"id\" IN (SELECT \"id\" FROM \"users\" WHERE a = 1 ORDER BY id desc LIMIT -1 OFFSET 5)");
+      }
+      // mssql
+      {
+        let mut delete_manager = DeleteManager::<User>::default();
+        delete_manager.where_prepare(serde_json::json!({"c1": true}));
+        delete_manager.limit(10);
+        let sql = delete_manager.to_sql(crate::Dialect::Mssql).unwrap();
+        assert_eq!(&sql.value, "DELETE TOP (10) FROM [users] WHERE [c1] = @p1");
+        let sql_string: String = sql.try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE TOP (10) FROM [users] WHERE [c1] = 1");
+
+        // paginate
+        let mut delete_manager = DeleteManager::<User>::default();
+        delete_manager.r#where("a = 1");
+        delete_manager.paginate(2, 20);
+        let sql_string: String = delete_manager.to_sql(crate::Dialect::Mssql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "DELETE FROM [users] WHERE [id] IN (SELECT [id] FROM [users] WHERE a = 1 ORDER BY (SELECT NULL) OFFSET 20 ROWS FETCH NEXT 20 ROWS ONLY)");
+      }
+    }
+    #[test]
+    fn to_sql_with_error() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let delete_manager = DeleteManager::<User>::default();
+      assert_eq!(delete_manager.to_sql(crate::Dialect::Postgresql).unwrap_err(), crate::SqlError::MissingWhere { statement: "DELETE" });
+    }
+    #[test]
+    fn to_sql_with_cte() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let mut inactive = crate::manager::SelectManager::<User>::default();
+      inactive.select(["id"]).where_prepare(serde_json::json!({"active": false}));
+      let mut delete_manager = DeleteManager::<User>::default();
+      delete_manager.with("inactive", inactive).r#where("id IN (SELECT id FROM inactive)");
+      let sql = delete_manager.to_sql(crate::Dialect::Mssql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::AtP), "WITH [inactive] AS (SELECT [id] FROM [users] WHERE [active] = @p1) DELETE FROM [users] WHERE id IN (SELECT id FROM inactive)");
+      assert_eq!(String::try_from(sql).unwrap(), "WITH [inactive] AS (SELECT [id] FROM [users] WHERE [active] = 0) DELETE FROM [users] WHERE id IN (SELECT id FROM inactive)");
     }
-}
\ No newline at end of file
+    #[test]
+    fn to_sql_with_operators() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let mut delete_manager = DeleteManager::<User>::default();
+      delete_manager.where_prepare(serde_json::json!({"last_login_at": {"lt": "2020-01-01"}, "email": {"ilike": "%@example.com"}})).where_not(serde_json::json!({"role": {"in": ["admin", "owner"]}}));
+      let sql = delete_manager.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::Dollar), "DELETE FROM \"users\" WHERE \"email\" ILIKE $1 AND \"last_login_at\" < $2 AND \"role\" NOT IN ('admin','owner')");
+      assert_eq!(String::try_from(sql).unwrap(), "DELETE FROM \"users\" WHERE \"email\" ILIKE '%@example.com' AND \"last_login_at\" < '2020-01-01' AND \"role\" NOT IN ('admin','owner')");
+    }
+}
@@ -25,15 +25,27 @@ impl<M: crate::Manageable> InsertManager<M> {
     self.insert = Some(statements::Insert::<M>::new(serde_json::json!(condition), Some(true)));
     self
   }
-  pub fn to_sql(&self) -> Result<Sql, crate::error::SqlError> {
+  // 按类型绑定, eg: [("avatar", SqlValue::Bytes(..)), ("created_at", SqlValue::DateTime(..))]
+  pub fn insert_values<K: Into<String>>(&mut self, values: impl IntoIterator<Item = (K, crate::collectors::SqlValue)>) -> &mut Self {
+    self.insert = Some(statements::Insert::<M>::new_values(values.into_iter().map(|(column_name, value)| (column_name.into(), value)).collect(), None));
+    self
+  }
+  pub fn insert_values_prepare<K: Into<String>>(&mut self, values: impl IntoIterator<Item = (K, crate::collectors::SqlValue)>) -> &mut Self {
+    self.insert = Some(statements::Insert::<M>::new_values(values.into_iter().map(|(column_name, value)| (column_name.into(), value)).collect(), Some(true)));
+    self
+  }
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
     let mut sql = Sql::default();
 
     if let Some(ref insert) = self.insert {
-      sql.push_sql(&insert.to_sql()?);
+      sql.push_sql(&insert.to_sql(dialect)?);
     }  else {
-      return Err(crate::error::SqlError::Message("insert table data insert value must exist".into()))
+      return Err(crate::error::SqlError::MissingValue { clause: "INSERT" })
     }
 
+    sql.dialect = Some(dialect);
+    sql.set_placeholder_style(dialect.placeholder_style());
+
     Ok(sql)
   }
 }
@@ -43,16 +55,16 @@ mod tests {
     use super::*;
     #[test]
     fn to_sql() {
-      struct User {}
-      impl crate::Manageable for User {}
-
-      #[cfg(feature = "mysql")]
+      // mysql
       {
+        struct User {}
+        impl crate::Manageable for User {}
+
         let mut insert_manager = InsertManager::<User>::default();
-        assert!(insert_manager.to_sql().is_err());
+        assert!(insert_manager.to_sql(crate::Dialect::Mysql).is_err());
         insert_manager.insert(serde_json::json!({"a": 1, "b": true, "c": null, "d": "desc"}));
-        let sql_string: String = insert_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "INSERT INTO users (a, b, c, d) VALUES (1, 1, null, 'desc')");
+        let sql_string: String = insert_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "INSERT INTO `users` (`a`, `b`, `c`, `d`) VALUES (1, 1, null, 'desc')");
       }
     }
 }
\ No newline at end of file
@@ -2,11 +2,15 @@ pub mod select_manager;
 pub mod insert_manager;
 pub mod update_manager;
 pub mod delete_manager;
+pub mod snapshot;
+pub mod compound_manager;
 
 pub use select_manager::{SelectManager};
 pub use insert_manager::{InsertManager};
 pub use update_manager::{UpdateManager};
 pub use delete_manager::{DeleteManager};
+pub use snapshot::{Snapshot};
+pub use compound_manager::{CompoundManager, SetOperator};
 
 
 pub trait Manageable: Sized {
@@ -14,11 +18,26 @@ pub trait Manageable: Sized {
 
   fn table_column_names() -> Vec<&'static str> { vec![] }
   fn attr_names() -> Vec<&'static str> { vec![] }
-  fn attr_name_to_table_column_name<'a>(_attr_name: &'a str) -> Result<&'a str, crate::SqlError> { Err(crate::SqlError::Message("No Impl".to_owned())) }
-  fn table_column_name_to_attr_name<'a>(_table_column_name: &'a str) -> Result<&'a str, crate::SqlError> { Err(crate::SqlError::Message("No Impl".to_owned())) }
+  fn attr_name_to_table_column_name(attr_name: &str) -> Result<&str, crate::SqlError> { Err(crate::SqlError::UnknownColumn { table: Self::table_name(), column: attr_name.to_owned() }) }
+  fn table_column_name_to_attr_name(table_column_name: &str) -> Result<&str, crate::SqlError> { Err(crate::SqlError::UnknownColumn { table: Self::table_name(), column: table_column_name.to_owned() }) }
+  // 条件中的属性名转换为列名, 没有属性信息时原样使用, 带表名的列不转换, eg: orders.user_id
+  fn table_column_name(attr_name: &str) -> Result<&str, crate::SqlError> {
+    if attr_name.contains('.') || Self::attr_names().is_empty() {
+      return Ok(attr_name);
+    }
+    Self::attr_name_to_table_column_name(attr_name)
+  }
 
   fn primary_key() -> &'static str { "id" }
   fn id() -> &'static str { Self::primary_key() }
+  fn primary_key_attr_name() -> &'static str {
+    Self::table_column_name_to_attr_name(Self::primary_key()).unwrap_or(Self::primary_key())
+  }
+  // 乐观锁的版本列, eg: lock_version
+  fn version_column() -> Option<&'static str> { None }
+  fn version_attr_name() -> Option<&'static str> {
+    Self::version_column().map(|version_column| Self::table_column_name_to_attr_name(version_column).unwrap_or(version_column))
+  }
 
   fn query() -> SelectManager<Self> {
     SelectManager::<Self>::default()
@@ -38,7 +57,188 @@ pub trait Manageable: Sized {
     delete_manager.r#where(where_condition);
     delete_manager
   }
+  fn find<T: serde::Serialize>(id: T) -> SelectManager<Self> {
+    let mut select_manager = SelectManager::<Self>::default();
+    select_manager.where_prepare(serde_json::json!({ Self::primary_key_attr_name(): id }));
+    select_manager
+  }
 
   // self
   fn get_json_value_from_attr_name__(&self, _attr_name: &str) -> Option<serde_json::Value> { None }
-}
\ No newline at end of file
+  // eg: {"id": 1, "name": "Tom"}
+  fn to_json_value__(&self) -> serde_json::Map<String, serde_json::Value> {
+    Self::attr_names().into_iter().filter_map(|attr_name| {
+      self.get_json_value_from_attr_name__(attr_name).map(|value| (attr_name.to_owned(), value))
+    }).collect()
+  }
+  // 按类型绑定的值, eg: Vec<u8> => SqlValue::Bytes, 没有类型信息时由 json 转换
+  fn get_sql_value_from_attr_name__(&self, attr_name: &str) -> Option<crate::SqlValue> {
+    self.get_json_value_from_attr_name__(attr_name).map(crate::SqlValue::from)
+  }
+  // 按属性名排序, 和 to_json_value__ 一致
+  fn to_sql_values__(&self) -> Vec<(String, crate::SqlValue)> {
+    let mut values: Vec<(String, crate::SqlValue)> = Self::attr_names().into_iter().filter_map(|attr_name| {
+      self.get_sql_value_from_attr_name__(attr_name).map(|value| (attr_name.to_owned(), value))
+    }).collect();
+    values.sort_by(|(attr_name, _), (other_attr_name, _)| attr_name.cmp(other_attr_name));
+    values
+  }
+  fn primary_key_value__(&self) -> Option<serde_json::Value> {
+    self.get_json_value_from_attr_name__(Self::primary_key_attr_name()).filter(|value| !value.is_null())
+  }
+  // 属性由 #[derive(Manageable)] 生成, 没有属性时 to_sql 返回 SqlError::MissingValue
+  // 主键为空时由数据库生成, eg: auto_increment
+  fn insert(&self) -> InsertManager<Self> {
+    let mut values = self.to_sql_values__();
+    if self.primary_key_value__().is_none() {
+      values.retain(|(attr_name, _)| attr_name != Self::primary_key_attr_name());
+    }
+    let mut insert_manager = InsertManager::<Self>::default();
+    insert_manager.insert_values_prepare(values);
+    insert_manager
+  }
+  // 主键为空时没有 where 条件, to_sql 返回 SqlError::MissingWhere
+  fn update(&self) -> UpdateManager<Self> {
+    let mut values = self.to_sql_values__();
+    values.retain(|(attr_name, _)| attr_name != Self::primary_key_attr_name());
+    let mut update_manager = UpdateManager::<Self>::default();
+    update_manager.update_values_prepare(values);
+    if let Some(id) = self.primary_key_value__() {
+      update_manager.where_prepare(serde_json::json!({ Self::primary_key_attr_name(): id }));
+    }
+    update_manager
+  }
+  fn snapshot(&self) -> Snapshot<Self> {
+    Snapshot::new(self)
+  }
+  fn delete(&self) -> DeleteManager<Self> {
+    let mut delete_manager = DeleteManager::<Self>::default();
+    if let Some(id) = self.primary_key_value__() {
+      delete_manager.where_prepare(serde_json::json!({ Self::primary_key_attr_name(): id }));
+    }
+    delete_manager
+  }
+}
+#[cfg(test)]
+mod tests {
+    use crate::Manageable;
+    #[test]
+    fn derive_manageable() {
+      #[derive(crate::Manageable)]
+      #[sql_gen(table = "legacy_users", primary_key = "UserID")]
+      #[allow(dead_code)]
+      struct User {
+        #[sql_gen(column = "UserID")]
+        user_id: i64,
+        name: Option<String>,
+        r#type: String,
+        #[sql_gen(skip)]
+        password_confirmation: String,
+      }
+      let user = User { user_id: 1, name: None, r#type: "admin".to_owned(), password_confirmation: "".to_owned() };
+
+      assert_eq!(User::table_name(), "legacy_users");
+      assert_eq!(User::primary_key(), "UserID");
+      assert_eq!(User::attr_names(), vec!["user_id", "name", "type"]);
+      assert_eq!(User::table_column_names(), vec!["UserID", "name", "type"]);
+      assert_eq!(User::attr_name_to_table_column_name("user_id"), Ok("UserID"));
+      assert_eq!(User::table_column_name_to_attr_name("UserID"), Ok("user_id"));
+      assert_eq!(User::attr_name_to_table_column_name("password_confirmation"), Err(crate::SqlError::UnknownColumn { table: "legacy_users".to_owned(), column: "password_confirmation".to_owned() }));
+      assert_eq!(user.get_json_value_from_attr_name__("user_id"), Some(serde_json::json!(1)));
+      assert_eq!(user.get_json_value_from_attr_name__("name"), Some(serde_json::json!(null)));
+      assert_eq!(user.get_json_value_from_attr_name__("type"), Some(serde_json::json!("admin")));
+      assert_eq!(user.get_json_value_from_attr_name__("password_confirmation"), None);
+
+      #[derive(crate::Manageable)]
+      struct Order {}
+      assert_eq!(Order::table_name(), "orders");
+      assert_eq!(Order::primary_key(), "id");
+      assert!(Order::attr_names().is_empty());
+    }
+    #[test]
+    fn table_column_name() {
+      #[derive(crate::Manageable)]
+      #[sql_gen(table = "LegacyUsers", primary_key = "UserID")]
+      #[allow(dead_code)]
+      struct User {
+        #[sql_gen(column = "UserID")]
+        user_id: i64,
+        #[sql_gen(column = "UserName")]
+        name: String,
+      }
+
+      let sql = User::query().r#where(serde_json::json!({"user_id": 1, "orders.status": "paid"})).where_range("user_id", 1..10).order(serde_json::json!({"user_id": "desc"})).to_sql(crate::Dialect::Mysql).unwrap();
+      assert_eq!(String::try_from(sql).unwrap(), "SELECT * FROM `LegacyUsers` WHERE `orders`.`status` = 'paid' AND `UserID` = 1 AND `UserID` >= 1 AND `UserID` < 10 ORDER BY `UserID` desc");
+      let sql = User::create(serde_json::json!({"user_id": 1, "name": "Tom"})).to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(String::try_from(sql).unwrap(), "INSERT INTO \"LegacyUsers\" (\"UserName\", \"UserID\") VALUES ('Tom', 1)");
+      let sql = User::update_all(serde_json::json!({"name": "Tom"})).r#where(serde_json::json!({"user_id": 1})).to_sql(crate::Dialect::Mssql).unwrap();
+      assert_eq!(String::try_from(sql).unwrap(), "UPDATE [LegacyUsers] SET [UserName] = 'Tom' WHERE [UserID] = 1");
+
+      let error = User::query().r#where(serde_json::json!({"nick": "Tom"})).to_sql(crate::Dialect::Mysql).unwrap_err();
+      assert_eq!(error, crate::SqlError::UnknownColumn { table: "LegacyUsers".to_owned(), column: "nick".to_owned() });
+      assert!(User::create(serde_json::json!({"UserID": 1})).to_sql(crate::Dialect::Mysql).is_err());
+    }
+    #[test]
+    fn instance_to_sql() {
+      #[derive(crate::Manageable)]
+      #[sql_gen(primary_key = "UserID")]
+      struct User {
+        #[sql_gen(column = "UserID")]
+        user_id: Option<i64>,
+        name: String,
+        age: i32,
+      }
+
+      let user = User { user_id: None, name: "Tom".to_owned(), age: 18 };
+      let sql = user.insert().to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(&sql.value, "INSERT INTO \"users\" (\"age\", \"name\") VALUES ($1, $2)");
+      assert_eq!(sql.prepare_value, Some(vec![crate::SqlValue::Int(18), crate::SqlValue::Text("Tom".to_owned())]));
+      assert_eq!(user.update().to_sql(crate::Dialect::Postgresql).unwrap_err(), crate::SqlError::MissingWhere { statement: "UPDATE" });
+      assert_eq!(user.delete().to_sql(crate::Dialect::Postgresql).unwrap_err(), crate::SqlError::MissingWhere { statement: "DELETE" });
+
+      let user = User { user_id: Some(1), name: "Tom".to_owned(), age: 18 };
+      let sql = user.insert().to_sql(crate::Dialect::Mysql).unwrap();
+      assert_eq!(&sql.value, "INSERT INTO `users` (`age`, `name`, `UserID`) VALUES (?, ?, ?)");
+      let sql = user.update().to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(&sql.value, "UPDATE \"users\" SET \"age\" = $1, \"name\" = $2 WHERE \"UserID\" = $3");
+      assert_eq!(String::try_from(sql).unwrap(), "UPDATE \"users\" SET \"age\" = 18, \"name\" = 'Tom' WHERE \"UserID\" = 1");
+      let sql = user.delete().to_sql(crate::Dialect::Mssql).unwrap();
+      assert_eq!(&sql.value, "DELETE FROM [users] WHERE [UserID] = @p1");
+      assert_eq!(sql.prepare_value, Some(vec![crate::SqlValue::Int(1)]));
+
+      let sql = User::find(1).to_sql(crate::Dialect::Sqlite).unwrap();
+      assert_eq!(&sql.value, "SELECT * FROM \"users\" WHERE \"UserID\" = ?");
+      assert_eq!(String::try_from(sql).unwrap(), "SELECT * FROM \"users\" WHERE \"UserID\" = 1");
+    }
+    #[test]
+    fn instance_to_sql_with_typed_values() {
+      #[derive(crate::Manageable)]
+      struct User {
+        id: i64,
+        avatar: Vec<u8>,
+        tags: Vec<String>,
+        created_at: chrono::NaiveDateTime,
+      }
+
+      let created_at = chrono::NaiveDate::from_ymd_opt(2021, 12, 31).unwrap().and_hms_opt(23, 59, 59).unwrap();
+      let user = User { id: 1, avatar: vec![0x01, 0xff], tags: vec!["a".to_owned()], created_at };
+      let sql = user.insert().to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(&sql.value, "INSERT INTO \"users\" (\"avatar\", \"created_at\", \"id\", \"tags\") VALUES ($1, $2, $3, $4)");
+      assert_eq!(sql.prepare_value, Some(vec![crate::SqlValue::Bytes(vec![0x01, 0xff]), crate::SqlValue::DateTime(created_at), crate::SqlValue::Int(1), crate::SqlValue::Json(serde_json::json!(["a"]))]));
+      let sql = user.update().to_sql(crate::Dialect::Mysql).unwrap();
+      assert_eq!(String::try_from(sql).unwrap(), "UPDATE `users` SET `avatar` = X'01ff', `created_at` = '2021-12-31 23:59:59', `tags` = '[\"a\"]' WHERE `id` = 1");
+
+      let sql = User::create(serde_json::json!({"tags": ["a", "b"]})).to_sql(crate::Dialect::Mysql).unwrap();
+      assert_eq!(String::try_from(sql).unwrap(), "INSERT INTO `users` (`tags`) VALUES ('[\"a\",\"b\"]')");
+    }
+    #[test]
+    fn instance_to_sql_without_derive() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let user = User {};
+      assert_eq!(user.insert().to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::MissingValue { clause: "INSERT" });
+      assert_eq!(User::create(serde_json::json!({})).to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::MissingValue { clause: "INSERT" });
+      assert_eq!(User::update_all(serde_json::json!({})).r#where(serde_json::json!({"id": 1})).to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::MissingValue { clause: "UPDATE" });
+    }
+}
@@ -3,11 +3,14 @@ use crate::table::statements;
 use crate::collectors::Sql;
 
 pub struct SelectManager<M: crate::Manageable> {
+  with: Option<statements::With>,
   select: statements::Select<M>,
   joins: Option<Vec<statements::Join<M>>>,
   wheres: Option<Vec<statements::Where<M>>>,
   groups: Option<Vec<statements::Group<M>>>,
   havings: Option<Vec<statements::Having<M>>>,
+  // WINDOW w AS (...)
+  windows: Option<Vec<(String, statements::Window<M>)>>,
   orders: Option<Vec<statements::Order<M>>>,
   limit: Option<statements::Limit<M>>,
   offset: Option<statements::Offset<M>>,
@@ -18,11 +21,13 @@ pub struct SelectManager<M: crate::Manageable> {
 impl<M: crate::Manageable> Default for SelectManager<M> {
   fn default() -> Self {
       Self {
+        with: None,
         select: statements::Select::<M>::default(),
         joins: None,
         wheres: None,
         groups: None,
         havings: None,
+        windows: None,
         orders: None,
         limit: None,
         offset: None,
@@ -33,13 +38,151 @@ impl<M: crate::Manageable> Default for SelectManager<M> {
 }
 
 impl<M: crate::Manageable> SelectManager<M> {
+  // eg: WITH paid_orders AS (SELECT ...) SELECT ...
+  pub fn with<T: Into<statements::Subquery>>(&mut self, name: &str, query: T) -> &mut Self {
+    self.with_cte(statements::Cte::new(name, query))
+  }
+  pub fn with_cte(&mut self, cte: statements::Cte) -> &mut Self {
+    self.with.get_or_insert_with(statements::With::default).ctes.push(cte);
+    self
+  }
+  // eg: WITH RECURSIVE tree (id, parent_id) AS (anchor UNION ALL recursive)
+  pub fn with_recursive<A: crate::Manageable + Send + Sync + 'static, R: crate::Manageable + Send + Sync + 'static>(&mut self, name: &str, column_names: &[&str], anchor: crate::manager::SelectManager<A>, recursive: crate::manager::SelectManager<R>) -> &mut Self {
+    let mut cte = statements::Cte::new(name, statements::Subquery::union_all(anchor.into(), recursive.into()));
+    if !column_names.is_empty() {
+      cte.column_names = Some(column_names.iter().map(|column_name| column_name.to_string()).collect());
+    }
+    self.with.get_or_insert_with(statements::With::default).recursive = true;
+    self.with_cte(cte)
+  }
+  // 多次调用时追加, eg: select(["id", "name"]).select({"created_at": "joined_at"}).select("COUNT(*) AS total")
+  pub fn select<T: serde::Serialize>(&mut self, condition: T) -> &mut Self {
+    let column = serde_json::json!(condition);
+    if let Some(columns) = &mut self.select.columns {
+      columns.push(column);
+    } else {
+      self.select.columns = Some(vec![column]);
+    }
+    self
+  }
+  // 在 * 之后追加, eg: SELECT *, COUNT(orders.id) AS orders_count
+  pub fn select_append<T: serde::Serialize>(&mut self, condition: T) -> &mut Self {
+    if self.select.columns.is_none() {
+      self.select.columns = Some(vec![serde_json::json!("*")]);
+    }
+    self.select(condition)
+  }
+  pub fn reselect<T: serde::Serialize>(&mut self, condition: T) -> &mut Self {
+    self.select.columns = None;
+    self.select(condition)
+  }
+  // 没有 GROUP BY 时只返回一行, 清空 select 列表以及 ORDER BY/LIMIT/OFFSET
+  // 有 GROUP BY 时保留已选择的列(一般为分组的列), 以及 ORDER BY/LIMIT/OFFSET
+  // DISTINCT 时 COUNT 保留 select 列表, 生成 SELECT COUNT(*) FROM (SELECT DISTINCT ...) AS t
+  fn aggregate(&mut self, aggregate: statements::Aggregate) -> &mut Self {
+    if self.groups.is_none() {
+      if !(self.select.distinct == Some(true) && matches!(aggregate, statements::Aggregate::Count(_))) {
+        self.select.columns = None;
+        self.select.subqueries = None;
+        self.select.windows = None;
+      }
+      self.windows = None;
+      self.orders = None;
+      self.limit = None;
+      self.offset = None;
+    }
+    self.select.aggregate = Some(aggregate);
+    self
+  }
+  // None: COUNT(*)
+  pub fn count(&mut self, column_name: Option<&str>) -> &mut Self {
+    self.aggregate(statements::Aggregate::Count(column_name.map(|column_name| column_name.to_owned())))
+  }
+  pub fn count_distinct(&mut self, column_name: &str) -> &mut Self {
+    self.aggregate(statements::Aggregate::CountDistinct(column_name.to_owned()))
+  }
+  pub fn sum(&mut self, column_name: &str) -> &mut Self {
+    self.aggregate(statements::Aggregate::Sum(column_name.to_owned()))
+  }
+  pub fn avg(&mut self, column_name: &str) -> &mut Self {
+    self.aggregate(statements::Aggregate::Avg(column_name.to_owned()))
+  }
+  pub fn min(&mut self, column_name: &str) -> &mut Self {
+    self.aggregate(statements::Aggregate::Min(column_name.to_owned()))
+  }
+  pub fn max(&mut self, column_name: &str) -> &mut Self {
+    self.aggregate(statements::Aggregate::Max(column_name.to_owned()))
+  }
+  // SELECT EXISTS(SELECT 1 FROM ...), mssql: SELECT CASE WHEN EXISTS(...) THEN 1 ELSE 0 END
+  // 清空 ORDER BY/LIMIT/OFFSET, mysql 不支持没有 LIMIT 的 OFFSET
+  // 作为条件使用时见 where_exists
+  pub fn exists(&mut self) -> &mut Self {
+    self.select.columns = None;
+    self.select.subqueries = None;
+    self.select.windows = None;
+    self.select.aggregate = Some(statements::Aggregate::Exists);
+    self.windows = None;
+    self.orders = None;
+    self.limit = None;
+    self.offset = None;
+    self
+  }
+  // 标量子查询, eg: (SELECT COUNT(*) FROM orders WHERE orders.user_id = users.id) AS orders_count
+  pub fn select_subquery<N: crate::Manageable + Send + Sync + 'static>(&mut self, subquery: SelectManager<N>, alias: &str) -> &mut Self {
+    let subquery = (statements::Subquery::from(subquery), alias.to_owned());
+    if let Some(subqueries) = &mut self.select.subqueries {
+      subqueries.push(subquery);
+    } else {
+      self.select.subqueries = Some(vec![subquery]);
+    }
+    self
+  }
+  // FROM (subquery) AS alias
+  pub fn from_subquery<T: Into<statements::Subquery>>(&mut self, subquery: T, alias: &str) -> &mut Self {
+    self.select.from = Some((subquery.into(), alias.to_owned()));
+    self
+  }
+  // 组合查询, eg: (SELECT ...) UNION (SELECT ...)
+  pub fn union<N: crate::Manageable + Send + Sync + 'static>(self, select_manager: SelectManager<N>) -> crate::manager::CompoundManager<M> where M: Send + Sync + 'static {
+    let mut compound_manager = crate::manager::CompoundManager::from(self);
+    compound_manager.union(select_manager);
+    compound_manager
+  }
+  pub fn union_all<N: crate::Manageable + Send + Sync + 'static>(self, select_manager: SelectManager<N>) -> crate::manager::CompoundManager<M> where M: Send + Sync + 'static {
+    let mut compound_manager = crate::manager::CompoundManager::from(self);
+    compound_manager.union_all(select_manager);
+    compound_manager
+  }
+  pub fn intersect<N: crate::Manageable + Send + Sync + 'static>(self, select_manager: SelectManager<N>) -> crate::manager::CompoundManager<M> where M: Send + Sync + 'static {
+    let mut compound_manager = crate::manager::CompoundManager::from(self);
+    compound_manager.intersect(select_manager);
+    compound_manager
+  }
+  pub fn except<N: crate::Manageable + Send + Sync + 'static>(self, select_manager: SelectManager<N>) -> crate::manager::CompoundManager<M> where M: Send + Sync + 'static {
+    let mut compound_manager = crate::manager::CompoundManager::from(self);
+    compound_manager.except(select_manager);
+    compound_manager
+  }
+  // eg: ROW_NUMBER() OVER (PARTITION BY user_id ORDER BY created_at) AS row_number
+  pub fn select_window(&mut self, function: statements::WindowFunction<M>, alias: &str) -> &mut Self {
+    self.select.windows.get_or_insert_with(Vec::new).push((function, alias.to_owned()));
+    self
+  }
+  // 命名窗口, eg: WINDOW w AS (PARTITION BY user_id), mssql: 需要 SQL Server 2022, 不支持
+  pub fn window(&mut self, window_name: &str, window: statements::Window<M>) -> &mut Self {
+    self.windows.get_or_insert_with(Vec::new).push((window_name.to_owned(), window));
+    self
+  }
   pub fn distinct(&mut self) -> &mut Self {
     self.select.distinct = Some(true);
     self
   }
-  pub fn joins(&mut self, condition: &str) -> &mut Self {
+  pub fn joins<T: serde::Serialize>(&mut self, condition: T) -> &mut Self {
     let mut join = statements::Join::<M>::default();
-    join.value = Some(condition.into());
+    join.value = Some(serde_json::json!(condition));
+    self.push_join(join)
+  }
+  fn push_join(&mut self, join: statements::Join<M>) -> &mut Self {
     if let Some(joins) = &mut self.joins {
       joins.push(join);
     } else {
@@ -47,8 +190,63 @@ impl<M: crate::Manageable> SelectManager<M> {
     }
     self
   }
+  // on 为 null 时根据 *_id 推断, eg: users.id = orders.user_id
+  fn join_manageable<N: crate::Manageable>(&mut self, join_type: statements::JoinType, on: serde_json::Value) -> &mut Self {
+    let on = if on.is_null() { statements::Join::<M>::infer_on::<N>(None) } else { Some(statements::Expr::from_on_json(&on)) };
+    self.push_join(statements::Join::<M>::new(join_type, &N::table_name(), None, on))
+  }
+  pub fn inner_join<N: crate::Manageable>(&mut self) -> &mut Self {
+    self.join_manageable::<N>(statements::JoinType::Inner, serde_json::Value::Null)
+  }
+  pub fn left_join<N: crate::Manageable>(&mut self) -> &mut Self {
+    self.join_manageable::<N>(statements::JoinType::Left, serde_json::Value::Null)
+  }
+  pub fn right_join<N: crate::Manageable>(&mut self) -> &mut Self {
+    self.join_manageable::<N>(statements::JoinType::Right, serde_json::Value::Null)
+  }
+  pub fn full_join<N: crate::Manageable>(&mut self) -> &mut Self {
+    self.join_manageable::<N>(statements::JoinType::Full, serde_json::Value::Null)
+  }
+  pub fn cross_join<N: crate::Manageable>(&mut self) -> &mut Self {
+    self.push_join(statements::Join::<M>::new(statements::JoinType::Cross, &N::table_name(), None, None))
+  }
+  // eg: {"users.id": {"column": "orders.user_id"}, "orders.status": "paid"}, "users.id = orders.user_id", ["orders.status = ?", "paid"], 见 Expr::from_on_json
+  pub fn inner_join_on<N: crate::Manageable, T: serde::Serialize>(&mut self, on: T) -> &mut Self {
+    self.join_manageable::<N>(statements::JoinType::Inner, serde_json::json!(on))
+  }
+  pub fn left_join_on<N: crate::Manageable, T: serde::Serialize>(&mut self, on: T) -> &mut Self {
+    self.join_manageable::<N>(statements::JoinType::Left, serde_json::json!(on))
+  }
+  pub fn right_join_on<N: crate::Manageable, T: serde::Serialize>(&mut self, on: T) -> &mut Self {
+    self.join_manageable::<N>(statements::JoinType::Right, serde_json::json!(on))
+  }
+  pub fn full_join_on<N: crate::Manageable, T: serde::Serialize>(&mut self, on: T) -> &mut Self {
+    self.join_manageable::<N>(statements::JoinType::Full, serde_json::json!(on))
+  }
+  // eg: join_expr::<Order>(JoinType::Left, Expr::column_eq("users.id", "orders.user_id").and(Expr::eq("orders.status", "paid")))
+  pub fn join_expr<N: crate::Manageable>(&mut self, join_type: statements::JoinType, on: statements::Expr) -> &mut Self {
+    self.push_join(statements::Join::<M>::new(join_type, &N::table_name(), None, Some(on)))
+  }
+  // 按表名 join, eg: join_table(JoinType::Left, "users", Some("managers"), {"orders.manager_id": {"column": "managers.id"}})
+  pub fn join_table<T: serde::Serialize>(&mut self, join_type: statements::JoinType, table_name: &str, alias: Option<&str>, on: T) -> &mut Self {
+    let on = serde_json::json!(on);
+    self.push_join(statements::Join::<M>::new(join_type, table_name, alias, if on.is_null() { None } else { Some(statements::Expr::from_on_json(&on)) }))
+  }
+  // 派生表, eg: join_subquery(JoinType::Inner, totals, "totals", {"users.id": {"column": "totals.user_id"}})
+  pub fn join_subquery<S: Into<statements::Subquery>, T: serde::Serialize>(&mut self, join_type: statements::JoinType, subquery: S, alias: &str, on: T) -> &mut Self {
+    let on = serde_json::json!(on);
+    self.push_join(statements::Join::<M>::new_subquery(join_type, subquery.into(), alias, if on.is_null() { None } else { Some(statements::Expr::from_on_json(&on)) }))
+  }
+  // 子查询可以引用前面的表, on 为 null 时: ON TRUE, mssql: CROSS APPLY/OUTER APPLY
+  // eg: lateral_join(JoinType::Left, recent_orders, "recent_orders", ())
+  pub fn lateral_join<S: Into<statements::Subquery>, T: serde::Serialize>(&mut self, join_type: statements::JoinType, subquery: S, alias: &str, on: T) -> &mut Self {
+    let on = serde_json::json!(on);
+    let mut join = statements::Join::<M>::new_subquery(join_type, subquery.into(), alias, if on.is_null() { None } else { Some(statements::Expr::from_on_json(&on)) });
+    join.lateral = true;
+    self.push_join(join)
+  }
   pub fn r#where<T: serde::Serialize>(&mut self, condition: T) -> &mut Self {
-    let r#where = statements::Where::<M>::new(serde_json::json!(condition), None, None, None);
+    let r#where = statements::Where::<M>::new(serde_json::json!(condition));
     if let Some(wheres) = &mut self.wheres {
       wheres.push(r#where);
     } else {
@@ -56,53 +254,34 @@ impl<M: crate::Manageable> SelectManager<M> {
     }
     self
   }
+  // eg: Expr::eq("a", 1).or(Expr::eq("b", 2)).and(Expr::is_null("c"))
+  pub fn where_expr(&mut self, expr: statements::Expr) -> &mut Self {
+    self.push_where(statements::Where::<M>::new_expr(expr))
+  }
+  // 每一项取反, eg: {"a": 1, "b": [1, 2]}: a != 1 AND b NOT IN (1,2)
   pub fn where_not(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, Some(true), None, None);
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::And(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()))
   }
   pub fn where_or(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, None, Some(true), None);
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition)))
   }
   pub fn where_not_or(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, Some(true), Some(true), None);
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()))
   }
   pub fn where_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, None, None, Some(true));
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::from_json(&condition).prepare())
   }
   pub fn where_not_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, Some(true), None, Some(true));
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::And(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()).prepare())
   }
   pub fn where_or_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, None, Some(true), Some(true));
+    self.where_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition)).prepare())
+  }
+  pub fn where_not_or_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
+    self.where_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()).prepare())
+  }
+  pub fn where_range<T: Clone + Into<crate::collectors::SqlValue>>(&mut self, column_name: &str, range: impl std::ops::RangeBounds<T>) -> &mut Self {
+    let r#where = statements::Where::<M>::new_range(column_name, range, None);
     if let Some(wheres) = &mut self.wheres {
       wheres.push(r#where);
     } else {
@@ -110,8 +289,8 @@ impl<M: crate::Manageable> SelectManager<M> {
     }
     self
   }
-  pub fn where_not_or_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, Some(true), Some(true), Some(true));
+  pub fn where_range_prepare<T: Clone + Into<crate::collectors::SqlValue>>(&mut self, column_name: &str, range: impl std::ops::RangeBounds<T>) -> &mut Self {
+    let r#where = statements::Where::<M>::new_range(column_name, range, Some(true));
     if let Some(wheres) = &mut self.wheres {
       wheres.push(r#where);
     } else {
@@ -119,8 +298,7 @@ impl<M: crate::Manageable> SelectManager<M> {
     }
     self
   }
-  pub fn where_range<T: ToString>(&mut self, column_name: &str, range: impl std::ops::RangeBounds<T>) -> &mut Self {
-    let r#where = statements::Where::<M>::new_range(column_name, range);
+  fn push_where(&mut self, r#where: statements::Where<M>) -> &mut Self {
     if let Some(wheres) = &mut self.wheres {
       wheres.push(r#where);
     } else {
@@ -128,6 +306,19 @@ impl<M: crate::Manageable> SelectManager<M> {
     }
     self
   }
+  // eg: `user_id` IN (SELECT `id` FROM `users` WHERE ...)
+  pub fn where_in_subquery<T: Into<statements::Subquery>>(&mut self, column_name: &str, subquery: T) -> &mut Self {
+    self.push_where(statements::Where::<M>::new_subquery(Some(column_name), subquery.into(), None))
+  }
+  pub fn where_not_in_subquery<T: Into<statements::Subquery>>(&mut self, column_name: &str, subquery: T) -> &mut Self {
+    self.push_where(statements::Where::<M>::new_subquery(Some(column_name), subquery.into(), Some(true)))
+  }
+  pub fn where_exists<N: crate::Manageable + Send + Sync + 'static>(&mut self, subquery: SelectManager<N>) -> &mut Self {
+    self.push_where(statements::Where::<M>::new_subquery(None, statements::Subquery::from(subquery), None))
+  }
+  pub fn where_not_exists<N: crate::Manageable + Send + Sync + 'static>(&mut self, subquery: SelectManager<N>) -> &mut Self {
+    self.push_where(statements::Where::<M>::new_subquery(None, statements::Subquery::from(subquery), Some(true)))
+  }
   pub fn group<T: serde::Serialize>(&mut self, condition: T) -> &mut Self {
     let group = statements::Group::<M>::new(serde_json::json!(condition));
     if let Some(groups) = &mut self.groups {
@@ -138,7 +329,7 @@ impl<M: crate::Manageable> SelectManager<M> {
     self
   }
   pub fn having<T: serde::Serialize>(&mut self, condition: T) -> &mut Self {
-    let having = statements::Having::<M>::new(serde_json::json!(condition), None, None, None);
+    let having = statements::Having::<M>::new(serde_json::json!(condition));
     if let Some(havings) = &mut self.havings {
       havings.push(having);
     } else {
@@ -146,8 +337,8 @@ impl<M: crate::Manageable> SelectManager<M> {
     }
     self
   }
-  pub fn having_not(&mut self, condition: serde_json::Value) -> &mut Self {
-    let having = statements::Having::<M>::new(condition, Some(true), None, None);
+  pub fn having_expr(&mut self, expr: statements::Expr) -> &mut Self {
+    let having = statements::Having::<M>::new_expr(expr);
     if let Some(havings) = &mut self.havings {
       havings.push(having);
     } else {
@@ -155,53 +346,29 @@ impl<M: crate::Manageable> SelectManager<M> {
     }
     self
   }
+  pub fn having_not(&mut self, condition: serde_json::Value) -> &mut Self {
+    self.having_expr(statements::Expr::And(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()))
+  }
   pub fn having_or(&mut self, condition: serde_json::Value) -> &mut Self {
-    let having = statements::Having::<M>::new(condition, None, Some(true), None);
-    if let Some(havings) = &mut self.havings {
-      havings.push(having);
-    } else {
-      self.havings = Some(vec![having])
-    }
-    self
+    self.having_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition)))
   }
   pub fn having_not_or(&mut self, condition: serde_json::Value) -> &mut Self {
-    let having = statements::Having::<M>::new(condition, Some(true), Some(true), None);
-    if let Some(havings) = &mut self.havings {
-      havings.push(having);
-    } else {
-      self.havings = Some(vec![having])
-    }
-    self
+    self.having_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()))
   }
   pub fn having_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let having = statements::Having::<M>::new(condition, None, None, Some(true));
-    if let Some(havings) = &mut self.havings {
-      havings.push(having);
-    } else {
-      self.havings = Some(vec![having])
-    }
-    self
+    self.having_expr(statements::Expr::from_json(&condition).prepare())
   }
   pub fn having_not_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let having = statements::Having::<M>::new(condition, Some(true), None, Some(true));
-    if let Some(havings) = &mut self.havings {
-      havings.push(having);
-    } else {
-      self.havings = Some(vec![having])
-    }
-    self
+    self.having_expr(statements::Expr::And(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()).prepare())
   }
   pub fn having_or_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let having = statements::Having::<M>::new(condition, None, Some(true), Some(true));
-    if let Some(havings) = &mut self.havings {
-      havings.push(having);
-    } else {
-      self.havings = Some(vec![having])
-    }
-    self
+    self.having_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition)).prepare())
   }
   pub fn having_not_or_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let having = statements::Having::<M>::new(condition, Some(true), Some(true), Some(true));
+    self.having_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()).prepare())
+  }
+  pub fn having_range<T: Clone + Into<crate::collectors::SqlValue>>(&mut self, column_name: &str, range: impl std::ops::RangeBounds<T>) -> &mut Self {
+    let having = statements::Having::<M>::new_range(column_name, range, None);
     if let Some(havings) = &mut self.havings {
       havings.push(having);
     } else {
@@ -209,8 +376,8 @@ impl<M: crate::Manageable> SelectManager<M> {
     }
     self
   }
-  pub fn having_range<T: ToString>(&mut self, column_name: &str, range: impl std::ops::RangeBounds<T>) -> &mut Self {
-    let having = statements::Having::<M>::new_range(column_name, range);
+  pub fn having_range_prepare<T: Clone + Into<crate::collectors::SqlValue>>(&mut self, column_name: &str, range: impl std::ops::RangeBounds<T>) -> &mut Self {
+    let having = statements::Having::<M>::new_range(column_name, range, Some(true));
     if let Some(havings) = &mut self.havings {
       havings.push(having);
     } else {
@@ -227,6 +394,11 @@ impl<M: crate::Manageable> SelectManager<M> {
     }
     self
   }
+  // eg: ORDER BY ROW_NUMBER() OVER (...) desc
+  pub fn order_window(&mut self, function: statements::WindowFunction<M>, direction: &str) -> &mut Self {
+    self.orders.get_or_insert_with(Vec::new).push(statements::Order::<M>::new_window(function, direction));
+    self
+  }
   pub fn limit(&mut self, value: usize) -> &mut Self {
     self.limit = Some(statements::Limit::new(value));
     self
@@ -241,13 +413,22 @@ impl<M: crate::Manageable> SelectManager<M> {
     self.offset(offset);
     self
 }
-  pub fn to_sql(&self) -> Result<Sql, crate::error::SqlError> {
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    // DISTINCT 的行数, eg: SELECT COUNT(*) FROM (SELECT DISTINCT ...) AS t
+    let count_distinct_rows = match self.select.aggregate {
+      Some(ref aggregate @ statements::Aggregate::Count(_)) if self.groups.is_none() && self.select.distinct == Some(true) => Some(aggregate),
+      _ => None,
+    };
     let mut sql = Sql::default();
-    sql.push_sql(&self.select.to_sql()?);
+    if count_distinct_rows.is_some() {
+      sql.push_sql(&self.select.to_sql_with_aggregate(dialect, None)?);
+    } else {
+      sql.push_sql(&self.select.to_sql(dialect)?);
+    }
 
     if let Some(ref joins) = self.joins {
       for join in joins.iter() {
-        sql.push(' ').push_sql(&join.to_sql()?);
+        sql.push(' ').push_sql(&join.to_sql(dialect)?);
       }
     }
 
@@ -257,10 +438,10 @@ impl<M: crate::Manageable> SelectManager<M> {
         if idx > 0 {
           sql.push_value(" AND");
         }
-        if let Some(or) = r#where.or && or {
-          sql.push_value(" (").push_sql(&r#where.to_sql()?).push(')');
+        if r#where.is_or() {
+          sql.push_value(" (").push_sql(&r#where.to_sql(dialect)?).push(')');
         } else {
-          sql.push(' ').push_sql(&r#where.to_sql()?);
+          sql.push(' ').push_sql(&r#where.to_sql(dialect)?);
         }
       }
     }
@@ -271,7 +452,7 @@ impl<M: crate::Manageable> SelectManager<M> {
         if idx > 0 {
           sql.push(',');
         }
-        sql.push_sql(&group.to_sql()?);
+        sql.push_sql(&group.to_sql(dialect)?);
       }
     }
 
@@ -281,11 +462,24 @@ impl<M: crate::Manageable> SelectManager<M> {
         if idx > 0 {
           sql.push_value(" AND");
         }
-        if let Some(or) = having.or && or {
-          sql.push_value(" (").push_sql(&having.to_sql()?).push(')');
+        if having.is_or() {
+          sql.push_value(" (").push_sql(&having.to_sql(dialect).map_err(|error| error.with_clause("HAVING"))?).push(')');
         } else {
-          sql.push(' ').push_sql(&having.to_sql()?);
+          sql.push(' ').push_sql(&having.to_sql(dialect).map_err(|error| error.with_clause("HAVING"))?);
+        }
+      }
+    }
+
+    if let Some(ref windows) = self.windows {
+      if dialect == crate::Dialect::Mssql {
+        return Err(crate::error::SqlError::DialectUnsupported { dialect, feature: "WINDOW clause".to_owned() });
+      }
+      sql.push_value(" WINDOW ");
+      for (idx, (window_name, window)) in windows.iter().enumerate() {
+        if idx > 0 {
+          sql.push_value(", ");
         }
+        sql.push_value(&format!("{} AS (", dialect.try_quote_identifier(window_name)?)).push_sql(&window.to_sql(dialect)?).push(')');
       }
     }
 
@@ -295,17 +489,57 @@ impl<M: crate::Manageable> SelectManager<M> {
         if idx > 0 {
           sql.push(',');
         }
-        sql.push_sql(&order.to_sql()?);
+        sql.push_sql(&order.to_sql(dialect)?);
       }
     }
 
-    if let Some(ref limit) = self.limit {
-      sql.push(' ').push_sql(&limit.to_sql()?);
+    // mssql: OFFSET ... ROWS FETCH NEXT ... ROWS ONLY 必须跟在 ORDER BY 之后
+    if dialect == crate::Dialect::Mssql {
+      if self.limit.is_some() || self.offset.is_some() {
+        if self.orders.is_none() {
+          sql.push_value(" ORDER BY (SELECT NULL)");
+        }
+        if let Some(ref offset) = self.offset {
+          sql.push(' ').push_sql(&offset.to_sql(dialect)?);
+        } else {
+          sql.push_value(" OFFSET 0 ROWS");
+        }
+        if let Some(ref limit) = self.limit {
+          sql.push(' ').push_sql(&limit.to_sql(dialect)?);
+        }
+      }
+    } else {
+      if let Some(ref limit) = self.limit {
+        sql.push(' ').push_sql(&limit.to_sql(dialect)?);
+      }
+
+      if let Some(ref offset) = self.offset {
+        // sqlite: OFFSET 必须和 LIMIT 一起使用
+        if self.limit.is_none() && dialect == crate::Dialect::Sqlite {
+          sql.push_value(" LIMIT -1");
+        }
+        sql.push(' ').push_sql(&offset.to_sql(dialect)?);
+      }
+    }
+
+    if let Some(aggregate) = count_distinct_rows {
+      let mut count_sql = Sql::new(format!("SELECT {} FROM (", self.select.aggregate_to_sql(aggregate, dialect)?));
+      count_sql.push_sql(&sql).push_value(&format!(") AS {}", dialect.quote_identifier("t")));
+      sql = count_sql;
+    }
+    if let Some(statements::Aggregate::Exists) = self.select.aggregate {
+      let mut exists_sql = Sql::new(if dialect == crate::Dialect::Mssql { "SELECT CASE WHEN EXISTS(" } else { "SELECT EXISTS(" }.to_owned());
+      exists_sql.push_sql(&sql).push_value(if dialect == crate::Dialect::Mssql { ") THEN 1 ELSE 0 END" } else { ")" });
+      sql = exists_sql;
     }
 
-    if let Some(ref offset) = self.offset {
-      sql.push(' ').push_sql(&offset.to_sql()?);
+    if let Some(ref with) = self.with {
+      let mut with_sql = with.to_sql(dialect)?;
+      with_sql.push(' ').push_sql(&sql);
+      sql = with_sql;
     }
+    sql.dialect = Some(dialect);
+    sql.set_placeholder_style(dialect.placeholder_style());
 
     Ok(sql)
   }
@@ -320,102 +554,461 @@ mod tests {
       struct User {}
       impl crate::Manageable for User {}
 
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         let mut select_manager = SelectManager::<User>::default();
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users`");
         // distinct
         select_manager.distinct();
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT DISTINCT * FROM users");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT DISTINCT * FROM `users`");
 
         // join
         let mut select_manager = SelectManager::<User>::default();
         select_manager.joins("left join orders on users.id = orders.user_id");
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users left join orders on users.id = orders.user_id");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` left join orders on users.id = orders.user_id");
         select_manager.joins("left join walltes on users.id = wallets.user_id");
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users left join orders on users.id = orders.user_id left join walltes on users.id = wallets.user_id");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` left join orders on users.id = orders.user_id left join walltes on users.id = wallets.user_id");
 
         // where
         let mut select_manager = SelectManager::<User>::default();
         select_manager.r#where("a = 1");
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users WHERE a = 1");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` WHERE a = 1");
         let mut select_manager = SelectManager::<User>::default();
         select_manager.r#where(serde_json::json!("a = 1"));
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users WHERE a = 1");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` WHERE a = 1");
 
         let mut select_manager = SelectManager::<User>::default();
         select_manager.r#where(serde_json::json!(["a = ?", "1"]));
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users WHERE a = '1'");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` WHERE a = '1'");
         select_manager.where_not(serde_json::json!({"b": [1, 2, 3]}));
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users WHERE a = '1' AND b NOT IN (1,2,3)");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` WHERE a = '1' AND `b` NOT IN (1,2,3)");
         select_manager.where_or(serde_json::json!({"c1": true, "c2": false}));
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users WHERE a = '1' AND b NOT IN (1,2,3) AND (c1 = 1 OR c2 = 0)");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` WHERE a = '1' AND `b` NOT IN (1,2,3) AND (`c1` = 1 OR `c2` = 0)");
         select_manager.where_not_prepare(serde_json::json!({"d": [1, 2, 3]}));
-        let sql = select_manager.to_sql().unwrap();
-        assert_eq!(&sql.value, "SELECT * FROM users WHERE a = ? AND b NOT IN (1,2,3) AND (c1 = 1 OR c2 = 0) AND d NOT IN (?,?,?)");
+        let sql = select_manager.to_sql(crate::Dialect::Mysql).unwrap();
+        assert_eq!(&sql.value, "SELECT * FROM `users` WHERE a = ? AND `b` NOT IN (1,2,3) AND (`c1` = 1 OR `c2` = 0) AND `d` NOT IN (?,?,?)");
         let sql_string: String = sql.try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users WHERE a = '1' AND b NOT IN (1,2,3) AND (c1 = 1 OR c2 = 0) AND d NOT IN (1,2,3)");
+        assert_eq!(&sql_string, "SELECT * FROM `users` WHERE a = '1' AND `b` NOT IN (1,2,3) AND (`c1` = 1 OR `c2` = 0) AND `d` NOT IN (1,2,3)");
 
         let mut select_manager = SelectManager::<User>::default();
         select_manager.where_range("id", 1..100);
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users WHERE id >= 1 AND id < 100");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` WHERE `id` >= 1 AND `id` < 100");
         let mut select_manager = SelectManager::<User>::default();
         select_manager.where_range("id", 1..=100);
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users WHERE id BETWEEN 1 AND 100");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` WHERE `id` BETWEEN 1 AND 100");
 
         // group
         let mut select_manager = SelectManager::<User>::default();
         select_manager.group("age");
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users GROUP BY age");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` GROUP BY age");
         select_manager.group(vec!["name", "province"]);
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users GROUP BY age,name,province");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` GROUP BY age,name,province");
         // having
         select_manager.having(serde_json::json!({"a": 1, "b": 2}));
         select_manager.having_range("c", 1..100);
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users GROUP BY age,name,province HAVING a = 1 AND b = 2 AND c >= 1 AND c < 100");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` GROUP BY age,name,province HAVING `a` = 1 AND `b` = 2 AND `c` >= 1 AND `c` < 100");
 
         // order
         let mut select_manager = SelectManager::<User>::default();
         select_manager.order("id desc");
         select_manager.order(serde_json::json!({"created": "desc"}));
         select_manager.order(vec!["updated_at desc"]);
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users ORDER BY id desc,created desc,updated_at desc");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` ORDER BY id desc,`created` desc,updated_at desc");
 
         // limit
         let mut select_manager = SelectManager::<User>::default();
         select_manager.limit(10);
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users LIMIT 10");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` LIMIT 10");
 
         // offset
         let mut select_manager = SelectManager::<User>::default();
         select_manager.offset(5);
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users OFFSET 5");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` OFFSET 5");
 
         // paginate
         let mut select_manager = SelectManager::<User>::default();
         select_manager.paginate(1, 20);
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users LIMIT 20 OFFSET 0");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` LIMIT 20 OFFSET 0");
         select_manager.paginate(2, 20);
-        let sql_string: String = select_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users LIMIT 20 OFFSET 20");
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users` LIMIT 20 OFFSET 20");
       }
+      // postgresql
+      {
+        let mut select_manager = SelectManager::<User>::default();
+        select_manager.r#where(serde_json::json!(["a = ?", "1"]));
+        select_manager.where_not_prepare(serde_json::json!({"d": [1, 2, 3]}));
+        select_manager.where_or(serde_json::json!({"c1": true}));
+        let sql = select_manager.to_sql(crate::Dialect::Postgresql).unwrap();
+        assert_eq!(&sql.value, "SELECT * FROM \"users\" WHERE a = $1 AND \"d\" NOT IN ($2,$3,$4) AND (\"c1\" = true)");
+        let sql_string: String = sql.try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM \"users\" WHERE a = '1' AND \"d\" NOT IN (1,2,3) AND (\"c1\" = true)");
+      }
+      // sqlite
+      {
+        let mut select_manager = SelectManager::<User>::default();
+        select_manager.where_prepare(serde_json::json!({"c1": true}));
+        select_manager.offset(10);
+        let sql = select_manager.to_sql(crate::Dialect::Sqlite).unwrap();
+        assert_eq!(&sql.value, "SELECT * FROM \"users\" WHERE \"c1\" = ? LIMIT -1 OFFSET 10");
+        let sql_string: String = sql.try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM \"users\" WHERE \"c1\" = 1 LIMIT -1 OFFSET 10");
+      }
+      // mssql
+      {
+        let mut select_manager = SelectManager::<User>::default();
+        select_manager.r#where(serde_json::json!(["a = ?", "1"]));
+        select_manager.where_not_prepare(serde_json::json!({"d": [1, 2, 3]}));
+        select_manager.where_or(serde_json::json!({"c1": true}));
+        let sql = select_manager.to_sql(crate::Dialect::Mssql).unwrap();
+        assert_eq!(&sql.value, "SELECT * FROM [users] WHERE a = @p1 AND [d] NOT IN (@p2,@p3,@p4) AND ([c1] = 1)");
+        let sql_string: String = sql.try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM [users] WHERE a = '1' AND [d] NOT IN (1,2,3) AND ([c1] = 1)");
+
+        // limit, offset
+        let mut select_manager = SelectManager::<User>::default();
+        select_manager.limit(10);
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mssql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM [users] ORDER BY (SELECT NULL) OFFSET 0 ROWS FETCH NEXT 10 ROWS ONLY");
+        let mut select_manager = SelectManager::<User>::default();
+        select_manager.order(serde_json::json!({"id": "desc"})).paginate(3, 20);
+        let sql_string: String = select_manager.to_sql(crate::Dialect::Mssql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM [users] ORDER BY [id] desc OFFSET 40 ROWS FETCH NEXT 20 ROWS ONLY");
+      }
+    }
+
+    #[test]
+    fn to_sql_with_dialect() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      // 同一个 select_manager 可以生成不同数据库的 sql
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.where_prepare(serde_json::json!({"name": "sql_gen", "active": true})).where_range("id", 1..=100).limit(10);
+      let sql = select_manager.to_sql(crate::Dialect::Mysql).unwrap();
+      assert_eq!(&sql.value, "SELECT * FROM `users` WHERE `active` = ? AND `name` = ? AND `id` BETWEEN 1 AND 100 LIMIT 10");
+      let sql_string: String = sql.try_into().unwrap();
+      assert_eq!(&sql_string, "SELECT * FROM `users` WHERE `active` = 1 AND `name` = 'sql_gen' AND `id` BETWEEN 1 AND 100 LIMIT 10");
+      let sql = select_manager.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(&sql.value, "SELECT * FROM \"users\" WHERE \"active\" = $1 AND \"name\" = $2 AND \"id\" BETWEEN 1 AND 100 LIMIT 10");
+      let sql_string: String = sql.try_into().unwrap();
+      assert_eq!(&sql_string, "SELECT * FROM \"users\" WHERE \"active\" = true AND \"name\" = 'sql_gen' AND \"id\" BETWEEN 1 AND 100 LIMIT 10");
+      let sql = select_manager.to_sql(crate::Dialect::Sqlite).unwrap();
+      assert_eq!(&sql.value, "SELECT * FROM \"users\" WHERE \"active\" = ? AND \"name\" = ? AND \"id\" BETWEEN 1 AND 100 LIMIT 10");
+      let sql = select_manager.to_sql(crate::Dialect::Mssql).unwrap();
+      assert_eq!(&sql.value, "SELECT * FROM [users] WHERE [active] = @p1 AND [name] = @p2 AND [id] BETWEEN 1 AND 100 ORDER BY (SELECT NULL) OFFSET 0 ROWS FETCH NEXT 10 ROWS ONLY");
+      let sql_string: String = sql.try_into().unwrap();
+      assert_eq!(&sql_string, "SELECT * FROM [users] WHERE [active] = 1 AND [name] = 'sql_gen' AND [id] BETWEEN 1 AND 100 ORDER BY (SELECT NULL) OFFSET 0 ROWS FETCH NEXT 10 ROWS ONLY");
     }
-}
\ No newline at end of file
+    #[test]
+    fn to_sql_with_range_prepare() {
+      struct Order {}
+      impl crate::Manageable for Order {}
+
+      let mut select_manager = SelectManager::<Order>::default();
+      select_manager.select("user_id, SUM(amount) AS total").where_range_prepare("created_at", chrono::NaiveDate::from_ymd_opt(2021, 1, 1).unwrap()..chrono::NaiveDate::from_ymd_opt(2022, 1, 1).unwrap()).group("user_id").having_range_prepare("total", 100..=1000);
+      let sql = select_manager.to_sql(crate::Dialect::Mysql).unwrap();
+      assert_eq!(&sql.value, "SELECT user_id, SUM(amount) AS total FROM `orders` WHERE `created_at` >= ? AND `created_at` < ? GROUP BY user_id HAVING `total` BETWEEN ? AND ?");
+      assert_eq!(String::try_from(sql).unwrap(), "SELECT user_id, SUM(amount) AS total FROM `orders` WHERE `created_at` >= '2021-01-01' AND `created_at` < '2022-01-01' GROUP BY user_id HAVING `total` BETWEEN 100 AND 1000");
+    }
+    #[test]
+    fn to_sql_with_quote_identifier() {
+      struct User {}
+      impl crate::Manageable for User {
+        fn table_name() -> String { "public.users".to_owned() }
+      }
+
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.r#where(serde_json::json!({"order": 1, "group": "a", "key": null})).order(serde_json::json!({"users.key": "desc"}));
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "SELECT * FROM `public`.`users` WHERE `group` = 'a' AND `key` IS NULL AND `order` = 1 ORDER BY `users`.`key` desc");
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "SELECT * FROM \"public\".\"users\" WHERE \"group\" = 'a' AND \"key\" IS NULL AND \"order\" = 1 ORDER BY \"users\".\"key\" desc");
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Sqlite).unwrap()).unwrap(), "SELECT * FROM \"public\".\"users\" WHERE \"group\" = 'a' AND \"key\" IS NULL AND \"order\" = 1 ORDER BY \"users\".\"key\" desc");
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "SELECT * FROM [public].[users] WHERE [group] = 'a' AND [key] IS NULL AND [order] = 1 ORDER BY [users].[key] desc");
+
+      // json 的 key 来自用户输入时不能注入
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.r#where(serde_json::json!({"name` = 1 OR 1=1 --": 1}));
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "SELECT * FROM `public`.`users` WHERE `name`` = 1 OR 1=1 --` = 1");
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.r#where(serde_json::json!({"name\n": 1}));
+      assert!(select_manager.to_sql(crate::Dialect::Mysql).is_err());
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.r#where(serde_json::json!({"": 1}));
+      assert!(select_manager.to_sql(crate::Dialect::Postgresql).is_err());
+    }
+    #[test]
+    fn to_sql_with_error() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.group("name").having(serde_json::json!(["count(*) > ? AND max(age) < ?", 1]));
+      assert_eq!(select_manager.to_sql(crate::Dialect::Mysql).unwrap_err().to_string(), "HAVING: parameter count mismatch, expected 2 but got 1: count(*) > ? AND max(age) < ?");
+
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.where_range::<i32>("id", ..);
+      assert!(matches!(select_manager.to_sql(crate::Dialect::Mysql), Err(crate::SqlError::InvalidRange { clause: "WHERE", .. })));
+    }
+    #[test]
+    fn to_sql_with_select() {
+      #[derive(crate::Manageable)]
+      #[allow(dead_code)]
+      struct User {
+        id: i64,
+        #[sql_gen(column = "UserName")]
+        name: String,
+      }
+
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.select(["id", "name"]).select(serde_json::json!({"name": "user_name"})).r#where(serde_json::json!({"id": 1}));
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "SELECT \"id\",\"UserName\",\"UserName\" AS \"user_name\" FROM \"users\" WHERE \"id\" = 1");
+      select_manager.reselect("COUNT(*) AS total");
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "SELECT COUNT(*) AS total FROM \"users\" WHERE \"id\" = 1");
+
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.select_append("LENGTH(\"UserName\") AS name_length");
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "SELECT *,LENGTH(\"UserName\") AS name_length FROM \"users\"");
+      select_manager.select_append(["id"]);
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "SELECT *,LENGTH(\"UserName\") AS name_length,\"id\" FROM \"users\"");
+
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.select(["nick"]);
+      assert_eq!(select_manager.to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::UnknownColumn { table: "users".to_owned(), column: "nick".to_owned() });
+    }
+    #[test]
+    fn to_sql_with_aggregate() {
+      #[derive(crate::Manageable)]
+      #[allow(dead_code)]
+      struct Order {
+        id: i64,
+        status: String,
+        #[sql_gen(column = "Amount")]
+        amount: f64,
+      }
+
+      let mut select_manager = SelectManager::<Order>::default();
+      select_manager.select(["id"]).joins("INNER JOIN users ON users.id = orders.user_id").r#where(serde_json::json!({"status": "paid"})).order(serde_json::json!({"id": "desc"})).paginate(3, 20).count(None);
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "SELECT COUNT(*) FROM `orders` INNER JOIN users ON users.id = orders.user_id WHERE `status` = 'paid'");
+      select_manager.sum("amount");
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "SELECT SUM([Amount]) FROM [orders] INNER JOIN users ON users.id = orders.user_id WHERE [status] = 'paid'");
+
+      let mut select_manager = SelectManager::<Order>::default();
+      select_manager.select(["status"]).group("status").having("SUM(Amount) > 100").order(serde_json::json!({"status": "asc"})).avg("amount");
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "SELECT \"status\",AVG(\"Amount\") FROM \"orders\" GROUP BY status HAVING SUM(Amount) > 100 ORDER BY \"status\" asc");
+      select_manager.count_distinct("id").min("amount").max("amount");
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Sqlite).unwrap()).unwrap(), "SELECT \"status\",MAX(\"Amount\") FROM \"orders\" GROUP BY status HAVING SUM(Amount) > 100 ORDER BY \"status\" asc");
+
+      let mut select_manager = SelectManager::<Order>::default();
+      select_manager.r#where(serde_json::json!({"status": "paid"})).order(serde_json::json!({"id": "desc"})).limit(10).exists();
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "SELECT EXISTS(SELECT 1 FROM `orders` WHERE `status` = 'paid')");
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "SELECT CASE WHEN EXISTS(SELECT 1 FROM [orders] WHERE [status] = 'paid') THEN 1 ELSE 0 END");
+      let mut subquery = SelectManager::<Order>::default();
+      subquery.count(None);
+      let mut select_manager = SelectManager::<Order>::default();
+      select_manager.select_subquery(subquery, "total").where_prepare(serde_json::json!({"status": "paid"})).offset(20).exists();
+      let sql = select_manager.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(&sql.value, "SELECT EXISTS(SELECT 1 FROM \"orders\" WHERE \"status\" = $1)");
+
+      // distinct
+      let mut select_manager = SelectManager::<Order>::default();
+      select_manager.select(["status"]).distinct().r#where(serde_json::json!({"id": {"gt": 10}})).order(serde_json::json!({"status": "asc"})).count(None);
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "SELECT COUNT(*) FROM (SELECT DISTINCT `status` FROM `orders` WHERE `id` > 10) AS `t`");
+      let mut select_manager = SelectManager::<Order>::default();
+      select_manager.distinct().count(None);
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "SELECT COUNT(*) FROM (SELECT DISTINCT * FROM [orders]) AS [t]");
+      // SUM/AVG 使用 DISTINCT 的值
+      let mut select_manager = SelectManager::<Order>::default();
+      select_manager.distinct().r#where(serde_json::json!({"status": "paid"})).sum("amount");
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "SELECT SUM(DISTINCT `Amount`) FROM `orders` WHERE `status` = 'paid'");
+      select_manager.avg("amount");
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "SELECT AVG(DISTINCT \"Amount\") FROM \"orders\" WHERE \"status\" = 'paid'");
+
+      let mut select_manager = SelectManager::<Order>::default();
+      select_manager.count(Some("price"));
+      assert_eq!(select_manager.to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::UnknownColumn { table: "orders".to_owned(), column: "price".to_owned() });
+    }
+    #[test]
+    fn to_sql_with_typed_join() {
+      #[derive(crate::Manageable)]
+      #[allow(dead_code)]
+      struct User {
+        id: i64,
+        name: String,
+      }
+      #[derive(crate::Manageable)]
+      #[allow(dead_code)]
+      struct Order {
+        id: i64,
+        user_id: i64,
+        status: String,
+      }
+      struct Tag {}
+      impl crate::Manageable for Tag {}
+
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.inner_join::<Order>().left_join::<Tag>().cross_join::<Tag>();
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "SELECT * FROM `users` INNER JOIN `orders` ON `users`.`id` = `orders`.`user_id` LEFT JOIN `tags` ON `users`.`id` = `tags`.`user_id` CROSS JOIN `tags`");
+
+      // belongs_to
+      let mut select_manager = SelectManager::<Order>::default();
+      select_manager.right_join::<User>().r#where(serde_json::json!({"status": "paid"}));
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "SELECT * FROM \"orders\" RIGHT JOIN \"users\" ON \"orders\".\"user_id\" = \"users\".\"id\" WHERE \"status\" = 'paid'");
+
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.left_join_on::<Order, _>(["users.id = orders.user_id AND orders.status = ?", "paid"]).full_join_on::<Tag, _>(serde_json::json!({"users.id": {"column": "tags.user_id"}, "users.name": {"column": "tags.name"}, "tags.kind": "friend"}));
+      let sql = select_manager.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::Dollar), "SELECT * FROM \"users\" LEFT JOIN \"orders\" ON users.id = orders.user_id AND orders.status = $1 FULL JOIN \"tags\" ON \"tags\".\"kind\" = 'friend' AND \"users\".\"id\" = \"tags\".\"user_id\" AND \"users\".\"name\" = \"tags\".\"name\"");
+      assert_eq!(select_manager.to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::DialectUnsupported { dialect: crate::Dialect::Mysql, feature: "FULL JOIN".to_owned() });
+
+      let mut select_manager = SelectManager::<Order>::default();
+      select_manager.join_table(statements::JoinType::Left, "users", Some("managers"), serde_json::json!({"orders.manager_id": {"column": "managers.id"}}));
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "SELECT * FROM [orders] LEFT JOIN [users] AS [managers] ON [orders].[manager_id] = [managers].[id]");
+      select_manager.join_table(statements::JoinType::Inner, "users", None, ());
+      assert_eq!(select_manager.to_sql(crate::Dialect::Mssql).unwrap_err(), crate::SqlError::MissingValue { clause: "JOIN" });
+
+      // 列信息中没有 *_id 时无法推断
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.inner_join::<User>();
+      assert_eq!(select_manager.to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::MissingValue { clause: "JOIN" });
+    }
+    #[test]
+    fn to_sql_with_lateral_join() {
+      struct User {}
+      impl crate::Manageable for User {}
+      struct Order {}
+      impl crate::Manageable for Order {}
+
+      let recent_orders = || {
+        let mut recent_orders = SelectManager::<Order>::default();
+        recent_orders.where_prepare(serde_json::json!({"status": "paid"})).r#where("orders.user_id = users.id").order(serde_json::json!({"id": "desc"})).limit(3);
+        recent_orders
+      };
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.lateral_join(statements::JoinType::Left, recent_orders(), "recent_orders", ()).where_prepare(serde_json::json!({"active": true}));
+      let sql = select_manager.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::Dollar), "SELECT * FROM \"users\" LEFT JOIN LATERAL (SELECT * FROM \"orders\" WHERE \"status\" = $1 AND orders.user_id = users.id ORDER BY \"id\" desc LIMIT 3) AS \"recent_orders\" ON TRUE WHERE \"active\" = $2");
+      assert_eq!(sql.prepare_value, Some(vec![crate::SqlValue::Text("paid".to_owned()), crate::SqlValue::Bool(true)]));
+      assert_eq!(select_manager.to_sql(crate::Dialect::Sqlite).unwrap_err(), crate::SqlError::DialectUnsupported { dialect: crate::Dialect::Sqlite, feature: "LATERAL JOIN".to_owned() });
+
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.lateral_join(statements::JoinType::Left, recent_orders(), "recent_orders", ());
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "SELECT * FROM [users] OUTER APPLY (SELECT * FROM [orders] WHERE [status] = 'paid' AND orders.user_id = users.id ORDER BY [id] desc OFFSET 0 ROWS FETCH NEXT 3 ROWS ONLY) AS [recent_orders]");
+
+      let mut stats = SelectManager::<Order>::default();
+      stats.r#where("orders.user_id = users.id").count(None);
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.lateral_join(statements::JoinType::Cross, stats, "stats", ());
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "SELECT * FROM `users` CROSS JOIN LATERAL (SELECT COUNT(*) FROM `orders` WHERE orders.user_id = users.id) AS `stats`");
+    }
+    #[test]
+    fn to_sql_with_subquery() {
+      struct User {}
+      impl crate::Manageable for User {}
+      struct Order {}
+      impl crate::Manageable for Order {}
+
+      let mut paid_orders = SelectManager::<Order>::default();
+      paid_orders.select(["user_id"]).where_prepare(serde_json::json!({"status": "paid"}));
+      let mut orders_count = SelectManager::<Order>::default();
+      orders_count.where_prepare(serde_json::json!({"kind": "gift"})).r#where("orders.user_id = users.id").count(None);
+      let mut refunds = SelectManager::<Order>::default();
+      refunds.where_prepare(serde_json::json!({"status": "refunded"})).r#where("orders.user_id = users.id");
+
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.select(["id"]).select_subquery(orders_count, "gift_orders_count").where_prepare(serde_json::json!({"active": true})).where_in_subquery("id", paid_orders).where_not_exists(refunds);
+      let sql = select_manager.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::Dollar), "SELECT \"id\",(SELECT COUNT(*) FROM \"orders\" WHERE \"kind\" = $1 AND orders.user_id = users.id) AS \"gift_orders_count\" FROM \"users\" WHERE \"active\" = $2 AND \"id\" IN (SELECT \"user_id\" FROM \"orders\" WHERE \"status\" = $3) AND NOT EXISTS (SELECT * FROM \"orders\" WHERE \"status\" = $4 AND orders.user_id = users.id)");
+      assert_eq!(sql.prepare_value, Some(vec![crate::SqlValue::Text("gift".to_owned()), crate::SqlValue::Bool(true), crate::SqlValue::Text("paid".to_owned()), crate::SqlValue::Text("refunded".to_owned())]));
+    }
+    #[test]
+    fn to_sql_with_subquery_in_from_and_join() {
+      struct User {}
+      impl crate::Manageable for User {}
+      struct Order {}
+      impl crate::Manageable for Order {}
+
+      let mut totals = SelectManager::<Order>::default();
+      totals.select("user_id, SUM(amount) AS total").where_prepare(serde_json::json!({"status": "paid"})).group("user_id");
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.join_subquery(statements::JoinType::Left, totals, "totals", serde_json::json!({"users.id": {"column": "totals.user_id"}})).where_prepare(serde_json::json!({"users.active": true}));
+      let sql = select_manager.to_sql(crate::Dialect::Mssql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::AtP), "SELECT * FROM [users] LEFT JOIN (SELECT user_id, SUM(amount) AS total FROM [orders] WHERE [status] = @p1 GROUP BY user_id) AS [totals] ON [users].[id] = [totals].[user_id] WHERE [users].[active] = @p2");
+      assert_eq!(String::try_from(sql).unwrap(), "SELECT * FROM [users] LEFT JOIN (SELECT user_id, SUM(amount) AS total FROM [orders] WHERE [status] = 'paid' GROUP BY user_id) AS [totals] ON [users].[id] = [totals].[user_id] WHERE [users].[active] = 1");
+
+      let mut recent = SelectManager::<User>::default();
+      recent.where_prepare(serde_json::json!({"active": true})).order(serde_json::json!({"id": "desc"})).limit(10);
+      let mut select_manager = SelectManager::<User>::default();
+      select_manager.from_subquery(recent, "recent_users").where_prepare(serde_json::json!({"recent_users.name": "Tom"}));
+      let sql = select_manager.to_sql(crate::Dialect::Mysql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::Question), "SELECT * FROM (SELECT * FROM `users` WHERE `active` = ? ORDER BY `id` desc LIMIT 10) AS `recent_users` WHERE `recent_users`.`name` = ?");
+      assert_eq!(String::try_from(sql).unwrap(), "SELECT * FROM (SELECT * FROM `users` WHERE `active` = 1 ORDER BY `id` desc LIMIT 10) AS `recent_users` WHERE `recent_users`.`name` = 'Tom'");
+    }
+    #[test]
+    fn to_sql_with_cte() {
+      struct Category {}
+      impl crate::Manageable for Category {}
+
+      let mut anchor = SelectManager::<Category>::default();
+      anchor.select("id, parent_id, 0").where_prepare(serde_json::json!({"id": 1}));
+      let mut recursive = SelectManager::<Category>::default();
+      recursive.select("categories.id, categories.parent_id, tree.depth + 1").join_table(statements::JoinType::Inner, "tree", None, serde_json::json!({"categories.parent_id": {"column": "tree.id"}})).where_prepare(serde_json::json!({"categories.active": true}));
+      let mut select_manager = SelectManager::<Category>::default();
+      select_manager.with_recursive("tree", &["id", "parent_id", "depth"], anchor, recursive).r#where("id IN (SELECT id FROM tree)").where_prepare(serde_json::json!({"deleted": false}));
+      let sql = select_manager.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::Dollar), "WITH RECURSIVE \"tree\" (\"id\",\"parent_id\",\"depth\") AS (SELECT id, parent_id, 0 FROM \"categories\" WHERE \"id\" = $1 UNION ALL SELECT categories.id, categories.parent_id, tree.depth + 1 FROM \"categories\" INNER JOIN \"tree\" ON \"categories\".\"parent_id\" = \"tree\".\"id\" WHERE \"categories\".\"active\" = $2) SELECT * FROM \"categories\" WHERE id IN (SELECT id FROM tree) AND \"deleted\" = $3");
+      assert_eq!(sql.prepare_value, Some(vec![crate::SqlValue::Int(1), crate::SqlValue::Bool(true), crate::SqlValue::Bool(false)]));
+
+      let mut paid = SelectManager::<Category>::default();
+      paid.where_prepare(serde_json::json!({"status": "paid"}));
+      let mut cte = statements::Cte::new("paid", paid);
+      cte.materialized = Some(true);
+      let mut select_manager = SelectManager::<Category>::default();
+      select_manager.with_cte(cte).join_table(statements::JoinType::Inner, "paid", None, serde_json::json!({"categories.id": {"column": "paid.id"}}));
+      assert_eq!(String::try_from(select_manager.to_sql(crate::Dialect::Sqlite).unwrap()).unwrap(), "WITH \"paid\" AS MATERIALIZED (SELECT * FROM \"categories\" WHERE \"status\" = 'paid') SELECT * FROM \"categories\" INNER JOIN \"paid\" ON \"categories\".\"id\" = \"paid\".\"id\"");
+      assert!(select_manager.to_sql(crate::Dialect::Mssql).is_err());
+    }
+    #[test]
+    fn to_sql_with_window() {
+      struct Order {}
+      impl crate::Manageable for Order {}
+
+      let mut window = statements::Window::<Order>::default();
+      window.partition_by(&["user_id"]).order(serde_json::json!({"created_at": "asc"}));
+      let mut running_total = statements::WindowFunction::<Order>::sum("amount");
+      running_total.over_window("w");
+      let mut row_number = statements::WindowFunction::<Order>::row_number();
+      row_number.over_window("w");
+      let mut rank = statements::WindowFunction::<Order>::rank();
+      let mut rank_window = statements::Window::<Order>::default();
+      rank_window.order(serde_json::json!({"amount": "desc"}));
+      rank.over(rank_window);
+
+      let mut select_manager = SelectManager::<Order>::default();
+      select_manager.select(["id"]).select_window(row_number, "row_number").select_window(running_total, "running_total").where_prepare(serde_json::json!({"status": "paid"})).window("w", window).order_window(rank, "asc").limit(10);
+      let sql = select_manager.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::Dollar), "SELECT \"id\",ROW_NUMBER() OVER \"w\" AS \"row_number\",SUM(\"amount\") OVER \"w\" AS \"running_total\" FROM \"orders\" WHERE \"status\" = $1 WINDOW \"w\" AS (PARTITION BY \"user_id\" ORDER BY \"created_at\" asc) ORDER BY RANK() OVER (ORDER BY \"amount\" desc) asc LIMIT 10");
+      assert_eq!(select_manager.to_sql(crate::Dialect::Mssql).unwrap_err(), crate::SqlError::DialectUnsupported { dialect: crate::Dialect::Mssql, feature: "WINDOW clause".to_owned() });
+
+      let mut select_manager = SelectManager::<Order>::default();
+      select_manager.order_window(statements::WindowFunction::<Order>::row_number(), "asc; DROP TABLE orders");
+      assert!(select_manager.to_sql(crate::Dialect::Mysql).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use std::marker::PhantomData;
+use super::UpdateManager;
+
+// 加载数据后保存原始值, 保存时只更新修改过的列
+// eg: let snapshot = user.snapshot(); user.name = "Tom".into(); snapshot.update(&user).to_sql(dialect)
+pub struct Snapshot<M: crate::Manageable> {
+  pub original: serde_json::Map<String, serde_json::Value>,
+  _marker: PhantomData<M>,
+}
+
+impl<M: crate::Manageable> Snapshot<M> {
+  pub fn new(record: &M) -> Self {
+    Self {
+      original: record.to_json_value__(),
+      _marker: PhantomData,
+    }
+  }
+  // 和原始值不同的属性, eg: {"name": "Tom"}
+  pub fn changes(&self, record: &M) -> serde_json::Map<String, serde_json::Value> {
+    record.to_json_value__().into_iter().filter(|(attr_name, value)| self.original.get(attr_name) != Some(value)).collect()
+  }
+  pub fn is_changed(&self, record: &M) -> bool {
+    !self.changes(record).is_empty()
+  }
+  // 没有修改时没有 update 值, to_sql 返回 SqlError::MissingValue
+  // 乐观锁: WHERE version = 原始值, SET version = 原始值 + 1
+  pub fn update(&self, record: &M) -> UpdateManager<M> {
+    let mut changes = self.changes(record);
+    let mut update_manager = UpdateManager::<M>::default();
+
+    let version = M::version_attr_name().and_then(|version_attr_name| {
+      self.original.get(version_attr_name).filter(|value| !value.is_null()).map(|value| (version_attr_name, value))
+    });
+    let mut next_version = None;
+    if let Some((version_attr_name, version)) = version
+      && !changes.is_empty() && !changes.contains_key(version_attr_name)
+      && let Some(version) = version.as_i64() {
+      changes.insert(version_attr_name.to_owned(), serde_json::json!(version + 1));
+      next_version = Some(version_attr_name);
+    }
+    if !changes.is_empty() {
+      // 修改的值按类型绑定, eg: Vec<u8> => SqlValue::Bytes
+      let values = changes.into_iter().map(|(attr_name, value)| {
+        let sql_value = match next_version {
+          Some(version_attr_name) if version_attr_name == attr_name => None,
+          _ => record.get_sql_value_from_attr_name__(&attr_name),
+        };
+        (attr_name, sql_value.unwrap_or_else(|| crate::SqlValue::from(value)))
+      }).collect::<Vec<(String, crate::SqlValue)>>();
+      update_manager.update_values_prepare(values);
+    }
+
+    if let Some(id) = self.original.get(M::primary_key_attr_name()).filter(|value| !value.is_null()) {
+      update_manager.where_prepare(serde_json::json!({ M::primary_key_attr_name(): id }));
+    }
+    if let Some((version_attr_name, version)) = version {
+      update_manager.where_prepare(serde_json::json!({ version_attr_name: version }));
+    }
+    update_manager
+  }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Manageable;
+    #[test]
+    fn update() {
+      #[derive(crate::Manageable)]
+      #[sql_gen(version = "LockVersion")]
+      struct User {
+        id: i64,
+        name: String,
+        age: i32,
+        #[sql_gen(column = "LockVersion")]
+        lock_version: i64,
+      }
+
+      let mut user = User { id: 1, name: "Tom".to_owned(), age: 18, lock_version: 3 };
+      let snapshot = user.snapshot();
+      assert!(!snapshot.is_changed(&user));
+      assert_eq!(snapshot.update(&user).to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::MissingValue { clause: "UPDATE" });
+
+      user.name = "Jerry".to_owned();
+      user.age = 19;
+      assert_eq!(snapshot.changes(&user), serde_json::json!({"name": "Jerry", "age": 19}).as_object().unwrap().to_owned());
+      let sql = snapshot.update(&user).to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(&sql.value, "UPDATE \"users\" SET \"age\" = $1, \"LockVersion\" = $2, \"name\" = $3 WHERE \"id\" = $4 AND \"LockVersion\" = $5");
+      assert_eq!(String::try_from(sql).unwrap(), "UPDATE \"users\" SET \"age\" = 19, \"LockVersion\" = 4, \"name\" = 'Jerry' WHERE \"id\" = 1 AND \"LockVersion\" = 3");
+
+      #[derive(crate::Manageable)]
+      struct Order {
+        id: i64,
+        status: String,
+      }
+      let mut order = Order { id: 7, status: "new".to_owned() };
+      let snapshot = order.snapshot();
+      order.status = "paid".to_owned();
+      let sql = snapshot.update(&order).to_sql(crate::Dialect::Mysql).unwrap();
+      assert_eq!(String::try_from(sql).unwrap(), "UPDATE `orders` SET `status` = 'paid' WHERE `id` = 7");
+    }
+}
@@ -2,6 +2,7 @@ use std::marker::PhantomData;
 use crate::table::statements;
 use crate::collectors::Sql;
 pub struct UpdateManager<M: crate::Manageable> {
+  with: Option<statements::With>,
   update: Option<statements::Update<M>>,
   wheres: Option<Vec<statements::Where<M>>>,
   orders: Option<Vec<statements::Order<M>>>,
@@ -15,6 +16,7 @@ pub struct UpdateManager<M: crate::Manageable> {
 impl<M: crate::Manageable> Default for UpdateManager<M> {
   fn default() -> Self {
       Self {
+        with: None,
         update: None,
         wheres: None,
         orders: None,
@@ -35,8 +37,34 @@ impl<M: crate::Manageable> UpdateManager<M> {
     self.update = Some(statements::Update::<M>::new(serde_json::json!(condition), Some(true)));
     self
   }
+  // 按类型绑定, eg: [("avatar", SqlValue::Bytes(..)), ("updated_at", SqlValue::DateTime(..))]
+  pub fn update_values<K: Into<String>>(&mut self, values: impl IntoIterator<Item = (K, crate::collectors::SqlValue)>) -> &mut Self {
+    self.update = Some(statements::Update::<M>::new_values(values.into_iter().map(|(column_name, value)| (column_name.into(), value)).collect(), None));
+    self
+  }
+  pub fn update_values_prepare<K: Into<String>>(&mut self, values: impl IntoIterator<Item = (K, crate::collectors::SqlValue)>) -> &mut Self {
+    self.update = Some(statements::Update::<M>::new_values(values.into_iter().map(|(column_name, value)| (column_name.into(), value)).collect(), Some(true)));
+    self
+  }
+  // eg: WITH paid_orders AS (SELECT ...) SELECT ...
+  pub fn with<T: Into<statements::Subquery>>(&mut self, name: &str, query: T) -> &mut Self {
+    self.with_cte(statements::Cte::new(name, query))
+  }
+  pub fn with_cte(&mut self, cte: statements::Cte) -> &mut Self {
+    self.with.get_or_insert_with(statements::With::default).ctes.push(cte);
+    self
+  }
+  // eg: WITH RECURSIVE tree (id, parent_id) AS (anchor UNION ALL recursive)
+  pub fn with_recursive<A: crate::Manageable + Send + Sync + 'static, R: crate::Manageable + Send + Sync + 'static>(&mut self, name: &str, column_names: &[&str], anchor: crate::manager::SelectManager<A>, recursive: crate::manager::SelectManager<R>) -> &mut Self {
+    let mut cte = statements::Cte::new(name, statements::Subquery::union_all(anchor.into(), recursive.into()));
+    if !column_names.is_empty() {
+      cte.column_names = Some(column_names.iter().map(|column_name| column_name.to_string()).collect());
+    }
+    self.with.get_or_insert_with(statements::With::default).recursive = true;
+    self.with_cte(cte)
+  }
   pub fn r#where<T: serde::Serialize>(&mut self, condition: T) -> &mut Self {
-    let r#where = statements::Where::<M>::new(serde_json::json!(condition), None, None, None);
+    let r#where = statements::Where::<M>::new(serde_json::json!(condition));
     if let Some(wheres) = &mut self.wheres {
       wheres.push(r#where);
     } else {
@@ -44,8 +72,9 @@ impl<M: crate::Manageable> UpdateManager<M> {
     }
     self
   }
-  pub fn where_not(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, Some(true), None, None);
+  // eg: Expr::eq("a", 1).or(Expr::eq("b", 2)).and(Expr::is_null("c"))
+  pub fn where_expr(&mut self, expr: statements::Expr) -> &mut Self {
+    let r#where = statements::Where::<M>::new_expr(expr);
     if let Some(wheres) = &mut self.wheres {
       wheres.push(r#where);
     } else {
@@ -53,53 +82,30 @@ impl<M: crate::Manageable> UpdateManager<M> {
     }
     self
   }
+  // 每一项取反, eg: {"a": 1, "b": [1, 2]}: a != 1 AND b NOT IN (1,2)
+  pub fn where_not(&mut self, condition: serde_json::Value) -> &mut Self {
+    self.where_expr(statements::Expr::And(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()))
+  }
   pub fn where_or(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, None, Some(true), None);
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition)))
   }
   pub fn where_not_or(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, Some(true), Some(true), None);
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()))
   }
   pub fn where_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, None, None, Some(true));
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::from_json(&condition).prepare())
   }
   pub fn where_not_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, Some(true), None, Some(true));
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::And(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()).prepare())
   }
   pub fn where_or_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, None, Some(true), Some(true));
-    if let Some(wheres) = &mut self.wheres {
-      wheres.push(r#where);
-    } else {
-      self.wheres = Some(vec![r#where])
-    }
-    self
+    self.where_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition)).prepare())
   }
   pub fn where_not_or_prepare(&mut self, condition: serde_json::Value) -> &mut Self {
-    let r#where = statements::Where::<M>::new(condition, Some(true), Some(true), Some(true));
+    self.where_expr(statements::Expr::Or(statements::Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()).prepare())
+  }
+  pub fn where_range<T: Clone + Into<crate::collectors::SqlValue>>(&mut self, column_name: &str, range: impl std::ops::RangeBounds<T>) -> &mut Self {
+    let r#where = statements::Where::<M>::new_range(column_name, range, None);
     if let Some(wheres) = &mut self.wheres {
       wheres.push(r#where);
     } else {
@@ -107,8 +113,8 @@ impl<M: crate::Manageable> UpdateManager<M> {
     }
     self
   }
-  pub fn where_range<T: ToString>(&mut self, column_name: &str, range: impl std::ops::RangeBounds<T>) -> &mut Self {
-    let r#where = statements::Where::<M>::new_range(column_name, range);
+  pub fn where_range_prepare<T: Clone + Into<crate::collectors::SqlValue>>(&mut self, column_name: &str, range: impl std::ops::RangeBounds<T>) -> &mut Self {
+    let r#where = statements::Where::<M>::new_range(column_name, range, Some(true));
     if let Some(wheres) = &mut self.wheres {
       wheres.push(r#where);
     } else {
@@ -139,48 +145,91 @@ impl<M: crate::Manageable> UpdateManager<M> {
     self.offset(offset);
     self
 }
-  pub fn to_sql(&self) -> Result<Sql, crate::error::SqlError> {
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
     let mut sql = Sql::default();
 
-    if let Some(ref update) = self.update {
-      sql.push_sql(&update.to_sql()?);
+    let update = if let Some(ref update) = self.update {
+      update
     } else {
-      return Err(crate::error::SqlError::Message("update table data update value must exist".into()))
-    }
+      return Err(crate::error::SqlError::MissingValue { clause: "UPDATE" })
+    };
 
+    let mut where_sql = Sql::default();
     if let Some(ref wheres) = self.wheres {
-      sql.push_value(" WHERE");
+      where_sql.push_value(" WHERE");
       for (idx, r#where) in wheres.iter().enumerate() {
         if idx > 0 {
-          sql.push_value(" AND");
+          where_sql.push_value(" AND");
         }
-        if let Some(or) = r#where.or && or {
-          sql.push_value(" (").push_sql(&r#where.to_sql()?).push(')');
+        if r#where.is_or() {
+          where_sql.push_value(" (").push_sql(&r#where.to_sql(dialect)?).push(')');
         } else {
-          sql.push(' ').push_sql(&r#where.to_sql()?);
+          where_sql.push(' ').push_sql(&r#where.to_sql(dialect)?);
         }
       }
     } else {
-      return Err(crate::error::SqlError::Message("delete table data where statements must exist".into()))
+      return Err(crate::error::SqlError::MissingWhere { statement: "UPDATE" })
     }
 
+    let mut order_sql = Sql::default();
     if let Some(ref orders) = self.orders {
-      sql.push_value(" ORDER BY ");
+      order_sql.push_value(" ORDER BY ");
       for (idx, order) in orders.iter().enumerate() {
         if idx > 0 {
-          sql.push(',');
+          order_sql.push(',');
         }
-        sql.push_sql(&order.to_sql()?);
+        order_sql.push_sql(&order.to_sql(dialect)?);
       }
     }
 
-    if let Some(ref limit) = self.limit {
-      sql.push(' ').push_sql(&limit.to_sql()?);
+    // mssql: 只有 LIMIT 时使用 TOP, 否则在子查询中使用 OFFSET ... ROWS FETCH NEXT ... ROWS ONLY
+    let mut top = None;
+    if dialect == crate::Dialect::Mssql {
+      if self.orders.is_none() && self.offset.is_none() {
+        top = self.limit.as_ref().map(|limit| limit.value);
+      } else {
+        if self.orders.is_none() {
+          order_sql.push_value(" ORDER BY (SELECT NULL)");
+        }
+        if let Some(ref offset) = self.offset {
+          order_sql.push(' ').push_sql(&offset.to_sql(dialect)?);
+        } else {
+          order_sql.push_value(" OFFSET 0 ROWS");
+        }
+        if let Some(ref limit) = self.limit {
+          order_sql.push(' ').push_sql(&limit.to_sql(dialect)?);
+        }
+      }
+    } else {
+      if let Some(ref limit) = self.limit {
+        order_sql.push(' ').push_sql(&limit.to_sql(dialect)?);
+      }
+
+      if let Some(ref offset) = self.offset {
+        // sqlite: OFFSET 必须和 LIMIT 一起使用
+        if self.limit.is_none() && dialect == crate::Dialect::Sqlite {
+          order_sql.push_value(" LIMIT -1");
+        }
+        order_sql.push(' ').push_sql(&offset.to_sql(dialect)?);
+      }
     }
 
-    if let Some(ref offset) = self.offset {
-      sql.push(' ').push_sql(&offset.to_sql()?);
+    sql.push_sql(&update.to_sql_with_top(top, dialect)?);
+    // postgresql, sqlite, mssql: UPDATE 不支持 ORDER BY, LIMIT, OFFSET, 通过主键子查询实现
+    if dialect != crate::Dialect::Mysql && !order_sql.is_empty() {
+      let primary_key = dialect.try_quote_identifier(M::primary_key())?;
+      sql.push_value(&format!(" WHERE {} IN (SELECT {} FROM {}", primary_key, primary_key, dialect.try_quote_identifier(&M::table_name())?));
+      sql.push_sql(&where_sql).push_sql(&order_sql).push(')');
+    } else {
+      sql.push_sql(&where_sql).push_sql(&order_sql);
+    }
+    if let Some(ref with) = self.with {
+      let mut with_sql = with.to_sql(dialect)?;
+      with_sql.push(' ').push_sql(&sql);
+      sql = with_sql;
     }
+    sql.dialect = Some(dialect);
+    sql.set_placeholder_style(dialect.placeholder_style());
 
     Ok(sql)
   }
@@ -195,20 +244,78 @@ mod tests {
       struct User {}
       impl crate::Manageable for User {}
 
-      #[cfg(feature = "mysql")]
+      // mysql
       {
         let update_manager = UpdateManager::<User>::default();
-        assert!(update_manager.to_sql().is_err());
+        assert!(update_manager.to_sql(crate::Dialect::Mysql).is_err());
 
         // update
         let mut update_manager = UpdateManager::<User>::default();
         update_manager.update(serde_json::json!({"a": 1, "b": true, "c": null, "d": "desc"})).r#where("a = 1");
-        let sql_string: String = update_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "UPDATE users SET a = 1, b = 1, c = null, d = 'desc' WHERE a = 1");
+        let sql_string: String = update_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "UPDATE `users` SET `a` = 1, `b` = 1, `c` = null, `d` = 'desc' WHERE a = 1");
         let mut update_manager = UpdateManager::<User>::default();
         update_manager.update_prepare(serde_json::json!({"a": 1, "b": true, "c": null, "d": "desc"})).r#where("a = 1");
-        let sql_string: String = update_manager.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "UPDATE users SET a = 1, b = 1, c = null, d = 'desc' WHERE a = 1");
+        let sql_string: String = update_manager.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "UPDATE `users` SET `a` = 1, `b` = 1, `c` = null, `d` = 'desc' WHERE a = 1");
+      }
+      // postgresql
+      {
+        let mut update_manager = UpdateManager::<User>::default();
+        update_manager.update_prepare(serde_json::json!({"a": 1, "b": true})).where_prepare(serde_json::json!({"c": "desc"}));
+        let sql = update_manager.to_sql(crate::Dialect::Postgresql).unwrap();
+        assert_eq!(&sql.value, "UPDATE \"users\" SET \"a\" = $1, \"b\" = $2 WHERE \"c\" = $3");
+        let sql_string: String = sql.try_into().unwrap();
+        assert_eq!(&sql_string, "UPDATE \"users\" SET \"a\" = 1, \"b\" = true WHERE \"c\" = 'desc'");
+
+        // order, limit
+        let mut update_manager = UpdateManager::<User>::default();
+        update_manager.update(serde_json::json!({"a": 1})).r#where("a = 2").order("id desc").limit(10);
+        let sql_string: String = update_manager.to_sql(crate::Dialect::Postgresql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "UPDATE \"users\" SET \"a\" = 1 WHERE \"id\" IN (SELECT \"id\" FROM \"users\" WHERE a = 2 ORDER BY id desc LIMIT 10)");
+      }
+      // mssql
+      {
+        let mut update_manager = UpdateManager::<User>::default();
+        update_manager.update_prepare(serde_json::json!({"a": 1, "b": true})).where_prepare(serde_json::json!({"c": "desc"}));
+        let sql = update_manager.to_sql(crate::Dialect::Mssql).unwrap();
+        assert_eq!(&sql.value, "UPDATE [users] SET [a] = @p1, [b] = @p2 WHERE [c] = @p3");
+        let sql_string: String = sql.try_into().unwrap();
+        assert_eq!(&sql_string, "UPDATE [users] SET [a] = 1, [b] = 1 WHERE [c] = 'desc'");
+
+        // limit
+        let mut update_manager = UpdateManager::<User>::default();
+        update_manager.update(serde_json::json!({"a": 1})).r#where("a = 2").limit(10);
+        let sql_string: String = update_manager.to_sql(crate::Dialect::Mssql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "UPDATE TOP (10) [users] SET [a] = 1 WHERE a = 2");
+
+        // order, limit
+        let mut update_manager = UpdateManager::<User>::default();
+        update_manager.update(serde_json::json!({"a": 1})).r#where("a = 2").order("id desc").limit(10);
+        let sql_string: String = update_manager.to_sql(crate::Dialect::Mssql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "UPDATE [users] SET [a] = 1 WHERE [id] IN (SELECT [id] FROM [users] WHERE a = 2 ORDER BY id desc OFFSET 0 ROWS FETCH NEXT 10 ROWS ONLY)");
+      }
+      // sqlite
+      {
+        let mut update_manager = UpdateManager::<User>::default();
+        update_manager.update_prepare(serde_json::json!({"a": 1, "b": true})).where_prepare(serde_json::json!({"c": "desc"}));
+        let sql = update_manager.to_sql(crate::Dialect::Sqlite).unwrap();
+        assert_eq!(&sql.value, "UPDATE \"users\" SET \"a\" = ?, \"b\" = ? WHERE \"c\" = ?");
+        let sql_string: String = sql.try_into().unwrap();
+        assert_eq!(&sql_string, "UPDATE \"users\" SET \"a\" = 1, \"b\" = 1 WHERE \"c\" = 'desc'");
       }
     }
-}
\ No newline at end of file
+    #[test]
+    fn to_sql_with_cte() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let mut inactive = crate::manager::SelectManager::<User>::default();
+      inactive.select(["id"]).where_prepare(serde_json::json!({"active": false}));
+      let mut update_manager = UpdateManager::<User>::default();
+      update_manager.with("inactive", inactive).update_prepare(serde_json::json!({"archived": true})).r#where("id IN (SELECT id FROM inactive)");
+      let sql = update_manager.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::Dollar), "WITH \"inactive\" AS (SELECT \"id\" FROM \"users\" WHERE \"active\" = $1) UPDATE \"users\" SET \"archived\" = $2 WHERE id IN (SELECT id FROM inactive)");
+      assert_eq!(sql.prepare_value, Some(vec![crate::SqlValue::Bool(false), crate::SqlValue::Bool(true)]));
+    }
+}
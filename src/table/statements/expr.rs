@@ -0,0 +1,435 @@
+use crate::collectors::Sql;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+  Eq,
+  Ne,
+  Gt,
+  Gte,
+  Lt,
+  Lte,
+  // mysql: NOT (a <=> b), sqlite: a IS NOT b
+  IsDistinctFrom,
+  // mysql: a <=> b, sqlite: a IS b
+  IsNotDistinctFrom,
+}
+
+impl Operator {
+  fn to_sql(self) -> &'static str {
+    match self {
+      Operator::Eq => "=",
+      Operator::Ne => "!=",
+      Operator::Gt => ">",
+      Operator::Gte => ">=",
+      Operator::Lt => "<",
+      Operator::Lte => "<=",
+      Operator::IsDistinctFrom => "IS DISTINCT FROM",
+      Operator::IsNotDistinctFrom => "IS NOT DISTINCT FROM",
+    }
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Operand {
+  // 直接写入 sql, eg: 'paid'
+  Literal(serde_json::Value),
+  // 占位符, eg: ?
+  Bind(serde_json::Value),
+  // 列名, eg: orders.user_id
+  Column(String),
+}
+
+impl Operand {
+  fn push_to(&self, sql: &mut Sql, dialect: crate::Dialect) -> Result<(), crate::error::SqlError> {
+    match self {
+      Operand::Literal(value) => { sql.push_value(&crate::methods::json_value_to_string(value, dialect)?); },
+      Operand::Bind(value) => { sql.push_placeholder(crate::methods::json_value_to_sql_value(value)?); },
+      Operand::Column(column_name) => { sql.push_value(&dialect.try_quote_identifier(column_name)?); },
+    }
+    Ok(())
+  }
+}
+
+// WHERE, HAVING, JOIN ... ON 的条件
+// eg: Expr::eq("a", 1).or(Expr::eq("b", 2)).and(Expr::gt("c", 3).or(Expr::is_null("d")))
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+  And(Vec<Expr>),
+  Or(Vec<Expr>),
+  Not(Box<Expr>),
+  Comparison { column: String, operator: Operator, operand: Operand },
+  In { column: String, operands: Vec<Operand>, not: bool },
+  Between { column: String, start: Operand, end: Operand, not: bool },
+  Like { column: String, pattern: Operand, not: bool },
+  // postgresql: ILIKE, 其他: LOWER(a) LIKE LOWER(b)
+  ILike { column: String, pattern: Operand, not: bool },
+  // postgresql: ~, mssql 不支持
+  Regexp { column: String, pattern: Operand, not: bool },
+  IsNull { column: String, not: bool },
+  // 与 where 的字符串/数组条件相同, eg: "a = 1", ["a = ? AND b = :b", ...]
+  Raw(serde_json::Value),
+}
+
+// 单个条件直接取反, eg: !Expr::eq("a", 1): a != 1, !Expr::in_list("b", [1, 2]): b NOT IN (1,2), 其它条件使用 NOT (...)
+impl std::ops::Not for Expr {
+  type Output = Expr;
+  fn not(self) -> Expr {
+    match self {
+      Expr::Comparison { column, operator: Operator::Eq, operand } => Expr::Comparison { column, operator: Operator::Ne, operand },
+      Expr::Comparison { column, operator: Operator::Ne, operand } => Expr::Comparison { column, operator: Operator::Eq, operand },
+      Expr::In { column, operands, not } => Expr::In { column, operands, not: !not },
+      Expr::Between { column, start, end, not } => Expr::Between { column, start, end, not: !not },
+      Expr::Like { column, pattern, not } => Expr::Like { column, pattern, not: !not },
+      Expr::ILike { column, pattern, not } => Expr::ILike { column, pattern, not: !not },
+      Expr::Regexp { column, pattern, not } => Expr::Regexp { column, pattern, not: !not },
+      Expr::IsNull { column, not } => Expr::IsNull { column, not: !not },
+      expr => Expr::Not(Box::new(expr)),
+    }
+  }
+}
+
+impl Expr {
+  fn comparison<T: serde::Serialize>(column: &str, operator: Operator, value: T) -> Self {
+    Expr::Comparison { column: column.to_owned(), operator, operand: Operand::Bind(serde_json::json!(value)) }
+  }
+  pub fn eq<T: serde::Serialize>(column: &str, value: T) -> Self { Self::comparison(column, Operator::Eq, value) }
+  pub fn ne<T: serde::Serialize>(column: &str, value: T) -> Self { Self::comparison(column, Operator::Ne, value) }
+  pub fn gt<T: serde::Serialize>(column: &str, value: T) -> Self { Self::comparison(column, Operator::Gt, value) }
+  pub fn gte<T: serde::Serialize>(column: &str, value: T) -> Self { Self::comparison(column, Operator::Gte, value) }
+  pub fn lt<T: serde::Serialize>(column: &str, value: T) -> Self { Self::comparison(column, Operator::Lt, value) }
+  pub fn lte<T: serde::Serialize>(column: &str, value: T) -> Self { Self::comparison(column, Operator::Lte, value) }
+  // eg: users.id = orders.user_id
+  pub fn column_eq(column: &str, other_column: &str) -> Self {
+    Expr::Comparison { column: column.to_owned(), operator: Operator::Eq, operand: Operand::Column(other_column.to_owned()) }
+  }
+  fn list<T: serde::Serialize>(column: &str, values: T, not: bool) -> Self {
+    let operands = match serde_json::json!(values) {
+      serde_json::Value::Array(arr) => arr.into_iter().map(Operand::Bind).collect(),
+      value => vec![Operand::Bind(value)],
+    };
+    Expr::In { column: column.to_owned(), operands, not }
+  }
+  pub fn in_list<T: serde::Serialize>(column: &str, values: T) -> Self { Self::list(column, values, false) }
+  pub fn not_in_list<T: serde::Serialize>(column: &str, values: T) -> Self { Self::list(column, values, true) }
+  pub fn between<T: serde::Serialize>(column: &str, start: T, end: T) -> Self {
+    Expr::Between { column: column.to_owned(), start: Operand::Bind(serde_json::json!(start)), end: Operand::Bind(serde_json::json!(end)), not: false }
+  }
+  pub fn like(column: &str, pattern: &str) -> Self {
+    Expr::Like { column: column.to_owned(), pattern: Operand::Bind(pattern.into()), not: false }
+  }
+  pub fn not_like(column: &str, pattern: &str) -> Self {
+    Expr::Like { column: column.to_owned(), pattern: Operand::Bind(pattern.into()), not: true }
+  }
+  pub fn ilike(column: &str, pattern: &str) -> Self {
+    Expr::ILike { column: column.to_owned(), pattern: Operand::Bind(pattern.into()), not: false }
+  }
+  pub fn not_ilike(column: &str, pattern: &str) -> Self {
+    Expr::ILike { column: column.to_owned(), pattern: Operand::Bind(pattern.into()), not: true }
+  }
+  pub fn regexp(column: &str, pattern: &str) -> Self {
+    Expr::Regexp { column: column.to_owned(), pattern: Operand::Bind(pattern.into()), not: false }
+  }
+  pub fn not_regexp(column: &str, pattern: &str) -> Self {
+    Expr::Regexp { column: column.to_owned(), pattern: Operand::Bind(pattern.into()), not: true }
+  }
+  pub fn distinct_from<T: serde::Serialize>(column: &str, value: T) -> Self { Self::comparison(column, Operator::IsDistinctFrom, value) }
+  pub fn not_distinct_from<T: serde::Serialize>(column: &str, value: T) -> Self { Self::comparison(column, Operator::IsNotDistinctFrom, value) }
+  pub fn is_null(column: &str) -> Self { Expr::IsNull { column: column.to_owned(), not: false } }
+  pub fn is_not_null(column: &str) -> Self { Expr::IsNull { column: column.to_owned(), not: true } }
+  // eg: Expr::raw("a = 1"), Expr::raw(["a = ?", 1])
+  pub fn raw<T: serde::Serialize>(condition: T) -> Self {
+    Expr::Raw(serde_json::json!(condition))
+  }
+
+  pub fn and(self, other: Expr) -> Self {
+    match self {
+      Expr::And(mut exprs) => {
+        exprs.push(other);
+        Expr::And(exprs)
+      },
+      expr => Expr::And(vec![expr, other]),
+    }
+  }
+  pub fn or(self, other: Expr) -> Self {
+    match self {
+      Expr::Or(mut exprs) => {
+        exprs.push(other);
+        Expr::Or(exprs)
+      },
+      expr => Expr::Or(vec![expr, other]),
+    }
+  }
+
+  // 运算符对象中的一个运算符, eg: {"gt": 18}, {"between": [1, 10]}, 不支持时返回 None
+  fn from_json_operator(column: &str, operator: &str, value: &serde_json::Value) -> Option<Self> {
+    let column = column.to_owned();
+    let operand = |value: &serde_json::Value| Operand::Literal(value.clone());
+    let comparison = |operator| Expr::Comparison { column: column.clone(), operator, operand: operand(value) };
+    let operands = || match value {
+      serde_json::Value::Array(arr) => arr.iter().map(operand).collect(),
+      value => vec![operand(value)],
+    };
+    let expr = match (operator, value) {
+      ("eq", serde_json::Value::Null) => Expr::IsNull { column, not: false },
+      ("ne", serde_json::Value::Null) => Expr::IsNull { column, not: true },
+      ("eq", _) => comparison(Operator::Eq),
+      ("ne", _) => comparison(Operator::Ne),
+      ("gt", _) => comparison(Operator::Gt),
+      ("gte", _) => comparison(Operator::Gte),
+      ("lt", _) => comparison(Operator::Lt),
+      ("lte", _) => comparison(Operator::Lte),
+      ("distinct_from", _) => comparison(Operator::IsDistinctFrom),
+      ("not_distinct_from", _) => comparison(Operator::IsNotDistinctFrom),
+      ("in", _) => Expr::In { column, operands: operands(), not: false },
+      ("not_in", _) => Expr::In { column, operands: operands(), not: true },
+      ("like", serde_json::Value::String(_)) => Expr::Like { column, pattern: operand(value), not: false },
+      ("not_like", serde_json::Value::String(_)) => Expr::Like { column, pattern: operand(value), not: true },
+      ("ilike", serde_json::Value::String(_)) => Expr::ILike { column, pattern: operand(value), not: false },
+      ("not_ilike", serde_json::Value::String(_)) => Expr::ILike { column, pattern: operand(value), not: true },
+      ("regexp", serde_json::Value::String(_)) => Expr::Regexp { column, pattern: operand(value), not: false },
+      ("not_regexp", serde_json::Value::String(_)) => Expr::Regexp { column, pattern: operand(value), not: true },
+      ("between" | "not_between", serde_json::Value::Array(arr)) if arr.len() == 2 => {
+        Expr::Between { column, start: operand(&arr[0]), end: operand(&arr[1]), not: operator == "not_between" }
+      },
+      ("is_null", serde_json::Value::Bool(is_null)) => Expr::IsNull { column, not: !is_null },
+      _ => return None,
+    };
+    Some(expr)
+  }
+  // JSON 条件中的一项, eg: "a": 1, "b": [1, 2], "c": null, "d": {"gt": 1}
+  fn from_json_entry(column_name: &str, value: &serde_json::Value) -> Self {
+    let column = column_name.to_owned();
+    let operand = |value: &serde_json::Value| Operand::Literal(value.clone());
+    match value {
+      serde_json::Value::Array(arr) => Expr::In { column, operands: arr.iter().map(operand).collect(), not: false },
+      serde_json::Value::Null => Expr::IsNull { column, not: false },
+      serde_json::Value::Object(operators) => {
+        let exprs: Option<Vec<Expr>> = operators.iter().map(|(operator, value)| Self::from_json_operator(&column, operator, value)).collect();
+        // 不支持的运算符在 to_sql 时返回错误
+        match exprs {
+          Some(mut exprs) if exprs.len() == 1 => exprs.remove(0),
+          Some(exprs) if !exprs.is_empty() => Expr::And(exprs),
+          _ => Expr::Raw(value.clone()),
+        }
+      },
+      _ => Expr::Comparison { column, operator: Operator::Eq, operand: operand(value) },
+    }
+  }
+  // where/having 的 JSON 条件, 值为字面量, 使用 prepare() 改为占位符
+  // {"a": 1, "b": [1, 2], "c": null}: a = 1 AND b IN (1,2) AND c IS NULL
+  // {"a": {"gt": 1, "lte": 10}, "b": {"like": "x%"}}: 运算符对象, 支持 eq, ne, gt, gte, lt, lte, in, not_in, like, not_like,
+  //   ilike, not_ilike, regexp, not_regexp, between, not_between, distinct_from, not_distinct_from, is_null
+  // "a = 1", ["a = ?", 1]: 原样使用
+  pub fn from_json(condition: &serde_json::Value) -> Self {
+    match condition {
+      serde_json::Value::Object(_) => Expr::And(Self::from_json_entries(condition)),
+      value => Expr::Raw(value.clone()),
+    }
+  }
+  // JSON 条件中的每一项, 用于 where_not/where_or, eg: Expr::Or(Expr::from_json_entries(..).into_iter().map(|expr| !expr).collect())
+  pub fn from_json_entries(condition: &serde_json::Value) -> Vec<Self> {
+    match condition {
+      serde_json::Value::Object(obj) => obj.iter().map(|(column_name, value)| Self::from_json_entry(column_name, value)).collect(),
+      value => vec![Expr::Raw(value.clone())],
+    }
+  }
+  // 字面量改为占位符, 列名和原样使用的条件不变
+  pub fn prepare(self) -> Self {
+    let bind = |operand: Operand| match operand {
+      Operand::Literal(value) => Operand::Bind(value),
+      operand => operand,
+    };
+    match self {
+      Expr::And(exprs) => Expr::And(exprs.into_iter().map(Expr::prepare).collect()),
+      Expr::Or(exprs) => Expr::Or(exprs.into_iter().map(Expr::prepare).collect()),
+      Expr::Not(expr) => Expr::Not(Box::new(expr.prepare())),
+      Expr::Comparison { column, operator, operand } => Expr::Comparison { column, operator, operand: bind(operand) },
+      Expr::In { column, operands, not } => Expr::In { column, operands: operands.into_iter().map(bind).collect(), not },
+      Expr::Between { column, start, end, not } => Expr::Between { column, start: bind(start), end: bind(end), not },
+      Expr::Like { column, pattern, not } => Expr::Like { column, pattern: bind(pattern), not },
+      Expr::ILike { column, pattern, not } => Expr::ILike { column, pattern: bind(pattern), not },
+      Expr::Regexp { column, pattern, not } => Expr::Regexp { column, pattern: bind(pattern), not },
+      expr @ (Expr::IsNull { .. } | Expr::Raw(_)) => expr,
+    }
+  }
+  // join ... on 的 JSON 条件, 与 where 的 JSON 条件相同, 字符串为字面量
+  // 与其他列比较时使用 {"column": ...} 标记
+  // eg: {"users.id": {"column": "orders.user_id"}, "orders.status": "paid"}: users.id = orders.user_id AND orders.status = 'paid'
+  pub fn from_on_json(condition: &serde_json::Value) -> Self {
+    match condition {
+      serde_json::Value::Object(obj) => {
+        Expr::And(obj.iter().map(|(column_name, value)| {
+          match value.as_object().filter(|marker| marker.len() == 1).and_then(|marker| marker.get("column")) {
+            Some(serde_json::Value::String(other_column)) => Self::column_eq(column_name, other_column),
+            _ => Self::from_json_entry(column_name, value),
+          }
+        }).collect())
+      },
+      value => Expr::Raw(value.clone()),
+    }
+  }
+
+  fn is_compound(&self) -> bool {
+    match self {
+      Expr::And(exprs) | Expr::Or(exprs) => exprs.len() > 1,
+      Expr::Raw(_) => true,
+      _ => false,
+    }
+  }
+  fn join_to_sql<M: crate::Manageable>(&self, exprs: &[Expr], separator: &str, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    let mut sql = Sql::default();
+    for (idx, expr) in exprs.iter().enumerate() {
+      if idx > 0 {
+        sql.push_value(separator);
+      }
+      // 优先级不同或原样使用的条件需要加括号
+      let same_kind = matches!((self, expr), (Expr::And(_), Expr::And(_)) | (Expr::Or(_), Expr::Or(_)));
+      if exprs.len() > 1 && !same_kind && expr.is_compound() {
+        sql.push('(').push_sql(&expr.to_sql::<M>(dialect)?).push(')');
+      } else {
+        sql.push_sql(&expr.to_sql::<M>(dialect)?);
+      }
+    }
+    Ok(sql)
+  }
+  pub fn to_sql<M: crate::Manageable>(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    let quote = |column: &str| dialect.try_quote_identifier(M::table_column_name(column)?);
+    let mut sql = Sql::default();
+    match self {
+      Expr::And(exprs) if exprs.is_empty() => { sql.push_value("1 = 1"); },
+      Expr::Or(exprs) if exprs.is_empty() => { sql.push_value("1 = 0"); },
+      Expr::And(exprs) => { sql.push_sql(&self.join_to_sql::<M>(exprs, " AND ", dialect)?); },
+      Expr::Or(exprs) => { sql.push_sql(&self.join_to_sql::<M>(exprs, " OR ", dialect)?); },
+      Expr::Not(expr) => { sql.push_value("NOT (").push_sql(&expr.to_sql::<M>(dialect)?).push(')'); },
+      // mysql 没有 IS DISTINCT FROM, 使用 NULL 安全的 <=>
+      Expr::Comparison { column, operator: operator @ (Operator::IsDistinctFrom | Operator::IsNotDistinctFrom), operand } if dialect != crate::Dialect::Postgresql && dialect != crate::Dialect::Mssql => {
+        let distinct = *operator == Operator::IsDistinctFrom;
+        match dialect {
+          crate::Dialect::Mysql => { sql.push_value(&format!("{}{} <=> ", if distinct { "NOT (" } else { "" }, quote(column)?)); },
+          _ => { sql.push_value(&format!("{} {} ", quote(column)?, if distinct { "IS NOT" } else { "IS" })); },
+        }
+        operand.push_to(&mut sql, dialect)?;
+        if distinct && dialect == crate::Dialect::Mysql {
+          sql.push(')');
+        }
+      },
+      Expr::Comparison { column, operator, operand } => {
+        sql.push_value(&format!("{} {} ", quote(column)?, operator.to_sql()));
+        operand.push_to(&mut sql, dialect)?;
+      },
+      // IN () 不是合法的 sql
+      Expr::In { operands, not, .. } if operands.is_empty() => { sql.push_value(if *not { "1 = 1" } else { "1 = 0" }); },
+      Expr::In { column, operands, not } => {
+        sql.push_value(&format!("{} {} (", quote(column)?, if *not { "NOT IN" } else { "IN" }));
+        for (idx, operand) in operands.iter().enumerate() {
+          if idx > 0 {
+            sql.push(',');
+          }
+          operand.push_to(&mut sql, dialect)?;
+        }
+        sql.push(')');
+      },
+      Expr::Between { column, start, end, not } => {
+        sql.push_value(&format!("{} {} ", quote(column)?, if *not { "NOT BETWEEN" } else { "BETWEEN" }));
+        start.push_to(&mut sql, dialect)?;
+        sql.push_value(" AND ");
+        end.push_to(&mut sql, dialect)?;
+      },
+      Expr::Like { column, pattern, not } => {
+        sql.push_value(&format!("{} {} ", quote(column)?, if *not { "NOT LIKE" } else { "LIKE" }));
+        pattern.push_to(&mut sql, dialect)?;
+      },
+      Expr::ILike { column, pattern, not } if dialect == crate::Dialect::Postgresql => {
+        sql.push_value(&format!("{} {} ", quote(column)?, if *not { "NOT ILIKE" } else { "ILIKE" }));
+        pattern.push_to(&mut sql, dialect)?;
+      },
+      Expr::ILike { column, pattern, not } => {
+        sql.push_value(&format!("LOWER({}) {} LOWER(", quote(column)?, if *not { "NOT LIKE" } else { "LIKE" }));
+        pattern.push_to(&mut sql, dialect)?;
+        sql.push(')');
+      },
+      Expr::Regexp { column, pattern, not } => {
+        let operator = match dialect {
+          crate::Dialect::Postgresql => if *not { "!~" } else { "~" },
+          crate::Dialect::Mssql => return Err(crate::error::SqlError::DialectUnsupported { dialect, feature: "REGEXP".to_owned() }),
+          _ => if *not { "NOT REGEXP" } else { "REGEXP" },
+        };
+        sql.push_value(&format!("{} {} ", quote(column)?, operator));
+        pattern.push_to(&mut sql, dialect)?;
+      },
+      Expr::IsNull { column, not } => {
+        sql.push_value(&format!("{} {}", quote(column)?, if *not { "IS NOT NULL" } else { "IS NULL" }));
+      },
+      Expr::Raw(serde_json::Value::String(raw)) => { sql.push_value(raw); },
+      Expr::Raw(serde_json::Value::Array(arr)) => { sql.push_sql(&crate::methods::raw_condition_to_sql(arr)?); },
+      Expr::Raw(value) => return Err(crate::error::SqlError::UnsupportedValue { clause: "SQL", value: value.to_string() }),
+    }
+    sql.dialect = Some(dialect);
+    Ok(sql)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn to_sql() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      // (a OR b) AND (c OR (d AND e))
+      let expr = Expr::eq("a", 1).or(Expr::like("b", "x%")).and(Expr::gt("c", 2).or(Expr::between("d", 1, 10).and(Expr::is_not_null("e"))));
+      let sql = expr.to_sql::<User>(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::Dollar), "(\"a\" = $1 OR \"b\" LIKE $2) AND (\"c\" > $3 OR (\"d\" BETWEEN $4 AND $5 AND \"e\" IS NOT NULL))");
+      assert_eq!(String::try_from(sql).unwrap(), "(\"a\" = 1 OR \"b\" LIKE 'x%') AND (\"c\" > 2 OR (\"d\" BETWEEN 1 AND 10 AND \"e\" IS NOT NULL))");
+
+      let expr = (!Expr::in_list("id", [1, 2]).and(Expr::raw(["name = ? OR name = ?", "a", "b"]))).or(Expr::not_in_list("id", Vec::<i32>::new()));
+      assert_eq!(String::try_from(expr.to_sql::<User>(crate::Dialect::Mysql).unwrap()).unwrap(), "NOT (`id` IN (1,2) AND (name = 'a' OR name = 'b')) OR 1 = 1");
+
+      let expr = Expr::column_eq("users.id", "orders.user_id").and(Expr::ne("orders.status", "void")).and(Expr::not_like("orders.note", "%test%"));
+      assert_eq!(String::try_from(expr.to_sql::<User>(crate::Dialect::Mssql).unwrap()).unwrap(), "[users].[id] = [orders].[user_id] AND [orders].[status] != 'void' AND [orders].[note] NOT LIKE '%test%'");
+    }
+    #[test]
+    fn dialect_operators() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let condition = serde_json::json!({"name": {"ilike": "a%"}, "code": {"regexp": "^[0-9]+$"}, "parent_id": {"distinct_from": 1}});
+      let expr = Expr::from_json(&condition);
+      assert_eq!(String::try_from(expr.to_sql::<User>(crate::Dialect::Postgresql).unwrap()).unwrap(), "\"code\" ~ '^[0-9]+$' AND \"name\" ILIKE 'a%' AND \"parent_id\" IS DISTINCT FROM 1");
+      assert_eq!(String::try_from(expr.to_sql::<User>(crate::Dialect::Mysql).unwrap()).unwrap(), "`code` REGEXP '^[0-9]+$' AND LOWER(`name`) LIKE LOWER('a%') AND NOT (`parent_id` <=> 1)");
+      assert_eq!(String::try_from(expr.to_sql::<User>(crate::Dialect::Sqlite).unwrap()).unwrap(), "\"code\" REGEXP '^[0-9]+$' AND LOWER(\"name\") LIKE LOWER('a%') AND \"parent_id\" IS NOT 1");
+      assert_eq!(expr.to_sql::<User>(crate::Dialect::Mssql).unwrap_err(), crate::SqlError::DialectUnsupported { dialect: crate::Dialect::Mssql, feature: "REGEXP".to_owned() });
+
+      let expr = Expr::not_regexp("code", "^a").and(Expr::not_ilike("name", "b%")).and(Expr::not_distinct_from("parent_id", 2));
+      assert_eq!(expr.to_sql::<User>(crate::Dialect::Postgresql).unwrap().to_prepared_string(crate::PlaceholderStyle::Dollar), "\"code\" !~ $1 AND \"name\" NOT ILIKE $2 AND \"parent_id\" IS NOT DISTINCT FROM $3");
+      assert_eq!(expr.to_sql::<User>(crate::Dialect::Mysql).unwrap().value, "`code` NOT REGEXP ? AND LOWER(`name`) NOT LIKE LOWER(?) AND `parent_id` <=> ?");
+      assert_eq!(Expr::distinct_from("parent_id", 1).to_sql::<User>(crate::Dialect::Mssql).unwrap().value, "[parent_id] IS DISTINCT FROM ?");
+    }
+    #[test]
+    fn from_json() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let condition = serde_json::json!({"a": 1, "b": [1, 2], "c": null});
+      assert_eq!(String::try_from(Expr::from_json(&condition).to_sql::<User>(crate::Dialect::Mysql).unwrap()).unwrap(), "`a` = 1 AND `b` IN (1,2) AND `c` IS NULL");
+      assert_eq!(Expr::Or(Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()).prepare().to_sql::<User>(crate::Dialect::Mysql).unwrap().value, "`a` != ? OR `b` NOT IN (?,?) OR `c` IS NOT NULL");
+      assert_eq!((!Expr::from_json(&serde_json::json!("a = 1"))).to_sql::<User>(crate::Dialect::Mysql).unwrap().value, "NOT (a = 1)");
+      assert_eq!(Expr::from_json(&serde_json::json!({"a": {"b": 1}})).to_sql::<User>(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::UnsupportedValue { clause: "SQL", value: "{\"b\":1}".to_owned() });
+      assert!(Expr::from_json(&serde_json::json!(1)).to_sql::<User>(crate::Dialect::Mysql).is_err());
+
+      let condition = serde_json::json!({"age": {"gte": 18, "lt": 60}, "name": {"like": "a%"}, "tags": {"not_in": [1, 2]}, "score": {"between": [1, 10]}, "deleted_at": {"is_null": true}});
+      assert_eq!(String::try_from(Expr::from_json(&condition).to_sql::<User>(crate::Dialect::Mysql).unwrap()).unwrap(), "`age` >= 18 AND `age` < 60 AND `deleted_at` IS NULL AND `name` LIKE 'a%' AND `score` BETWEEN 1 AND 10 AND `tags` NOT IN (1,2)");
+      let sql = Expr::Or(Expr::from_json_entries(&condition).into_iter().map(|expr| !expr).collect()).prepare().to_sql::<User>(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::Dollar), "NOT (\"age\" >= $1 AND \"age\" < $2) OR \"deleted_at\" IS NOT NULL OR \"name\" NOT LIKE $3 OR \"score\" NOT BETWEEN $4 AND $5 OR \"tags\" IN ($6,$7)");
+      assert_eq!(Expr::from_json(&serde_json::json!({"a": {"gt": 1, "foo": 2}})).to_sql::<User>(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::UnsupportedValue { clause: "SQL", value: "{\"foo\":2,\"gt\":1}".to_owned() });
+      assert!(Expr::from_json(&serde_json::json!({"a": {"between": [1]}})).to_sql::<User>(crate::Dialect::Mysql).is_err());
+
+      let condition = serde_json::json!({"users.id": {"column": "orders.user_id"}, "orders.kind": 1});
+      assert_eq!(String::try_from(Expr::from_on_json(&condition).to_sql::<User>(crate::Dialect::Mysql).unwrap()).unwrap(), "`orders`.`kind` = 1 AND `users`.`id` = `orders`.`user_id`");
+      // 字符串为字面量, 不会当作列名
+      let condition = serde_json::json!({"users.id": {"column": "orders.user_id"}, "orders.status": "paid", "orders.total": {"gt": 100}, "orders.deleted_at": null});
+      assert_eq!(String::try_from(Expr::from_on_json(&condition).to_sql::<User>(crate::Dialect::Postgresql).unwrap()).unwrap(), "\"orders\".\"deleted_at\" IS NULL AND \"orders\".\"status\" = 'paid' AND \"orders\".\"total\" > 100 AND \"users\".\"id\" = \"orders\".\"user_id\"");
+      assert!(Expr::from_on_json(&serde_json::json!({"users.id": {"column": 1}})).to_sql::<User>(crate::Dialect::Mysql).is_err());
+    }
+}
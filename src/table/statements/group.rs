@@ -17,24 +17,25 @@ impl<M: crate::Manageable> Default for Group<M> {
 
 impl<M: crate::Manageable> Group<M> {
   pub fn new(condition: serde_json::Value) -> Self {
-    let mut group = Group::<M>::default();
-    group.value = Some(condition);
-    group
+    Group::<M> {
+      value: Some(condition),
+      ..Group::<M>::default()
+    }
   }
-  pub fn to_sql(&self) -> Result<Sql, crate::error::SqlError> {
+  pub fn to_sql(&self, _dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
     let mut sql = Sql::default();
 
     if let Some(ref value) = self.value {
       match value {
         serde_json::Value::Array(arr) => {
-          let values: Vec<String> = arr.iter().map(|v| v.as_str().unwrap().to_string()).collect();
+          let values = arr.iter().map(|v| v.as_str().map(|v| v.to_owned()).ok_or_else(|| crate::error::SqlError::UnsupportedValue { clause: "GROUP BY", value: v.to_string() })).collect::<Result<Vec<String>, crate::error::SqlError>>()?;
           eprintln!("{:?}", values);
-          sql.push_value(&format!("{}", values.join(",")));
+          sql.push_value(&values.join(","));
         },
         serde_json::Value::String(string) => {
           sql.push_value(string);
         },
-        _ => return Err(crate::error::SqlError::Message(format!("Error: Value {:?} Type Not Support!", self.value)))
+        _ => return Err(crate::error::SqlError::UnsupportedValue { clause: "GROUP BY", value: value.to_string() })
       }
     }
 
@@ -47,20 +48,24 @@ mod tests {
     use super::*;
     #[test]
     fn to_sql() {
-      struct User {}
-      impl crate::Manageable for User {}
-
-      #[cfg(feature = "mysql")]
+      // mysql
       {
+        struct User {}
+        impl crate::Manageable for User {}
+
         //
-        let mut group = Group::<User>::default();
-        group.value = Some(serde_json::json!("a"));
-        let sql_string: String = group.to_sql().unwrap().try_into().unwrap();
+        let group = Group::<User> {
+          value: Some(serde_json::json!("a")),
+          ..Group::<User>::default()
+        };
+        let sql_string: String = group.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
         assert_eq!(&sql_string, "a");
 
-        let mut group = Group::<User>::default();
-        group.value = Some(serde_json::json!(["a", "b", "c"]));
-        let sql_string: String = group.to_sql().unwrap().try_into().unwrap();
+        let group = Group::<User> {
+          value: Some(serde_json::json!(["a", "b", "c"])),
+          ..Group::<User>::default()
+        };
+        let sql_string: String = group.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
         assert_eq!(&sql_string, "a,b,c");
       }
     }
@@ -1,8 +1,10 @@
 use std::{marker::PhantomData};
-use crate::collectors::Sql;
+use crate::collectors::{Sql, SqlValue};
 
 pub struct Insert<M: crate::Manageable> {
   pub value: Option<serde_json::Value>,
+  // 按类型绑定的值, 不为空时代替 value, eg: Bytes, DateTime
+  pub values: Option<Vec<(String, SqlValue)>>,
   pub prepare: Option<bool>,
   _marker: PhantomData<M>,
 }
@@ -11,6 +13,7 @@ impl<M: crate::Manageable> Default for Insert<M> {
   fn default() -> Self {
       Self {
           value: None,
+          values: None,
           prepare: None,
           _marker: PhantomData
       }
@@ -19,41 +22,55 @@ impl<M: crate::Manageable> Default for Insert<M> {
 
 impl<M: crate::Manageable> Insert<M> {
   pub fn new(condition: serde_json::Value, prepare: Option<bool>) -> Self {
-    let mut insert = Insert::default();
-    insert.value = Some(condition);
-    insert.prepare = prepare;
-    insert
+    Insert {
+      value: Some(condition),
+      prepare,
+      ..Insert::default()
+    }
   }
-  pub fn to_sql(&self) -> Result<Sql, crate::error::SqlError> {
-    let mut sql = Sql::new(format!("INSERT INTO {}", M::table_name()));
-
-    if let Some(ref value) = self.value {
-      match value {
-        serde_json::Value::Object(obj) => {
-          let column_names: Vec<String> = obj.keys().into_iter().map(|column_name| column_name.into()).collect();
-          let column_values = obj.keys().into_iter().map(|column_name| crate::methods::json_value_to_string(obj.get(column_name).unwrap())).collect::<Result<Vec<String>, crate::SqlError>>()?;
+  pub fn new_values(values: Vec<(String, SqlValue)>, prepare: Option<bool>) -> Self {
+    Insert {
+      values: Some(values),
+      prepare,
+      ..Insert::default()
+    }
+  }
+  // 数组和对象作为 Json
+  fn column_values(&self) -> Result<Option<Vec<(String, SqlValue)>>, crate::error::SqlError> {
+    match (&self.values, &self.value) {
+      (Some(values), _) => Ok(Some(values.clone())),
+      (None, Some(serde_json::Value::Object(obj))) => Ok(Some(obj.iter().map(|(column_name, value)| (column_name.to_owned(), SqlValue::from(value))).collect())),
+      (None, Some(value)) => Err(crate::error::SqlError::UnsupportedValue { clause: "INSERT", value: value.to_string() }),
+      (None, None) => Ok(None),
+    }
+  }
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    let mut sql = Sql::new(format!("INSERT INTO {}", dialect.try_quote_identifier(&M::table_name())?));
 
-          sql.push_value(" (");
-          for (idx, column_name) in column_names.iter().enumerate() {
-            if idx > 0 { sql.push_value(", "); }
-            sql.push_value(column_name);
-          }
-          sql.push_value(") VALUES (");
+    if let Some(column_values) = self.column_values()? {
+      // eg: 没有属性的 Manageable, 不能生成 () VALUES ()
+      if column_values.is_empty() {
+        return Err(crate::error::SqlError::MissingValue { clause: "INSERT" });
+      }
+      sql.push_value(" (");
+      for (idx, (column_name, _)) in column_values.iter().enumerate() {
+        if idx > 0 { sql.push_value(", "); }
+        sql.push_value(&dialect.try_quote_identifier(M::table_column_name(column_name)?)?);
+      }
+      sql.push_value(") VALUES (");
 
-          for (idx, column_value) in column_values.iter().enumerate() {
-            if idx > 0 { sql.push_value(", "); }
-            if let Some(prepare) = self.prepare && prepare {
-              sql.push_value_with_prepare_value("?", column_value);
-            } else {
-              sql.push_value(column_value);
-            }
-          }
-          sql.push(')');
-        },
-        _ => return Err(crate::error::SqlError::Message(format!("Error: Insert Value {:?} Not Support", value)))
+      for (idx, (_, column_value)) in column_values.iter().enumerate() {
+        if idx > 0 { sql.push_value(", "); }
+        if let Some(prepare) = self.prepare && prepare {
+          sql.push_placeholder(column_value.clone());
+        } else {
+          sql.push_value(&column_value.to_sql_literal(dialect)?);
+        }
       }
+      sql.push(')');
     }
 
+    sql.dialect = Some(dialect);
     Ok(sql)
   }
 }
@@ -63,11 +80,11 @@ mod tests {
     use super::*;
     #[test]
     fn to_sql() {
-      struct User {}
-      impl crate::Manageable for User {}
-
-      #[cfg(feature = "mysql")]
+      // mysql
       {
+        struct User {}
+        impl crate::Manageable for User {}
+
         //
         let mut insert = Insert::<User>::default();
         insert.value = Some(serde_json::json!({
@@ -76,12 +93,37 @@ mod tests {
           "c": null,
           "d": "desc"
         }));
-        let sql_string: String = insert.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "INSERT INTO users (a, b, c, d) VALUES (1, 0, null, 'desc')");
+        let sql_string: String = insert.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "INSERT INTO `users` (`a`, `b`, `c`, `d`) VALUES (1, 0, null, 'desc')");
 
         insert.prepare = Some(true);
-        let sql = insert.to_sql().unwrap();
-        assert_eq!(&sql.value, "INSERT INTO users (a, b, c, d) VALUES (?, ?, ?, ?)");
+        let sql = insert.to_sql(crate::Dialect::Mysql).unwrap();
+        assert_eq!(&sql.value, "INSERT INTO `users` (`a`, `b`, `c`, `d`) VALUES (?, ?, ?, ?)");
       }
     }
-}
\ No newline at end of file
+    #[test]
+    fn to_sql_with_escape() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let insert = Insert::<User>::new(serde_json::json!({"name": "O'Brien", "bio": "line1\nline2 \\"}), None);
+      assert_eq!(String::try_from(insert.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "INSERT INTO `users` (`bio`, `name`) VALUES ('line1\\nline2 \\\\', 'O''Brien')");
+      assert_eq!(String::try_from(insert.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "INSERT INTO \"users\" (\"bio\", \"name\") VALUES (E'line1\nline2 \\\\', 'O''Brien')");
+      assert_eq!(String::try_from(insert.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "INSERT INTO [users] ([bio], [name]) VALUES ('line1\nline2 \\', 'O''Brien')");
+    }
+    #[test]
+    fn to_sql_with_typed_values() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      // 数组和对象作为 Json
+      let insert = Insert::<User>::new(serde_json::json!({"tags": ["a", "b"], "profile": {"age": 18}}), Some(true));
+      let sql = insert.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(sql.prepare_value, Some(vec![SqlValue::Json(serde_json::json!({"age": 18})), SqlValue::Json(serde_json::json!(["a", "b"]))]));
+      assert_eq!(String::try_from(sql).unwrap(), "INSERT INTO \"users\" (\"profile\", \"tags\") VALUES ('{\"age\":18}', '[\"a\",\"b\"]')");
+
+      let created_at = chrono::NaiveDate::from_ymd_opt(2021, 12, 31).unwrap().and_hms_opt(23, 59, 59).unwrap();
+      let insert = Insert::<User>::new_values(vec![("avatar".to_owned(), SqlValue::from(vec![0x01u8, 0xff])), ("created_at".to_owned(), SqlValue::from(created_at))], None);
+      assert_eq!(String::try_from(insert.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "INSERT INTO `users` (`avatar`, `created_at`) VALUES (X'01ff', '2021-12-31 23:59:59')");
+    }
+}
@@ -1,8 +1,40 @@
 use std::marker::PhantomData;
 use crate::collectors::Sql;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinType {
+  Inner,
+  Left,
+  Right,
+  Full,
+  Cross,
+}
+
+impl JoinType {
+  fn to_sql(self, dialect: crate::Dialect) -> Result<&'static str, crate::error::SqlError> {
+    match self {
+      JoinType::Inner => Ok("INNER JOIN"),
+      JoinType::Left => Ok("LEFT JOIN"),
+      JoinType::Right => Ok("RIGHT JOIN"),
+      // mysql: 不支持 FULL JOIN
+      JoinType::Full if dialect == crate::Dialect::Mysql => Err(crate::error::SqlError::DialectUnsupported { dialect, feature: "FULL JOIN".to_owned() }),
+      JoinType::Full => Ok("FULL JOIN"),
+      JoinType::Cross => Ok("CROSS JOIN"),
+    }
+  }
+}
+
 pub struct Join<M: crate::Manageable> {
-  pub value: Option<String>,
+  // 原样使用, eg: "left join orders on users.id = orders.user_id"
+  pub value: Option<serde_json::Value>,
+  // 以下为 join_type 不为空时使用
+  pub join_type: Option<JoinType>,
+  pub table_name: Option<String>,
+  pub alias: Option<String>,
+  pub lateral: bool,
+  // 派生表, 不为空时代替 table_name, lateral 时必须使用
+  pub subquery: Option<super::Subquery>,
+  pub on: Option<super::Expr>,
   _marker: PhantomData<M>,
 }
 
@@ -10,21 +42,138 @@ impl<M: crate::Manageable> Default for Join<M> {
   fn default() -> Self {
       Self {
           value: None,
+          join_type: None,
+          table_name: None,
+          alias: None,
+          lateral: false,
+          subquery: None,
+          on: None,
           _marker: PhantomData
       }
   }
 }
 
 impl<M: crate::Manageable> Join<M> {
-  pub fn to_sql(&self) -> Result<Sql, crate::error::SqlError> {
+  pub fn new(join_type: JoinType, table_name: &str, alias: Option<&str>, on: Option<super::Expr>) -> Self {
+    Self {
+      join_type: Some(join_type),
+      table_name: Some(table_name.to_owned()),
+      alias: alias.map(|alias| alias.to_owned()),
+      on,
+      ..Self::default()
+    }
+  }
+  // eg: INNER JOIN (SELECT ...) AS alias ON ...
+  pub fn new_subquery(join_type: JoinType, subquery: super::Subquery, alias: &str, on: Option<super::Expr>) -> Self {
+    Self {
+      join_type: Some(join_type),
+      subquery: Some(subquery),
+      alias: Some(alias.to_owned()),
+      on,
+      ..Self::default()
+    }
+  }
+  // 根据 *_id 推断关联的列, 与 define_table::ForeignKey::from 相同
+  // has_many: users.id = orders.user_id, belongs_to: orders.user_id = users.id
+  // 没有列信息时按 has_many 处理
+  pub fn infer_on<N: crate::Manageable>(alias: Option<&str>) -> Option<super::Expr> {
+    let table_name = M::table_name();
+    let join_table_name = N::table_name();
+    let join_alias = alias.map(|alias| alias.to_owned()).unwrap_or(join_table_name.clone());
+    let is_reference_to = |column_name: &str, table_name: &str| column_name.ends_with("_id") && crate::define_table::ForeignKey::from(column_name).reference_table_name() == table_name;
+
+    if let Some(column_name) = N::table_column_names().into_iter().find(|column_name| is_reference_to(column_name, &table_name)) {
+      return Some(super::Expr::column_eq(&format!("{}.{}", table_name, M::primary_key()), &format!("{}.{}", join_alias, column_name)));
+    }
+    if let Some(column_name) = M::table_column_names().into_iter().find(|column_name| is_reference_to(column_name, &join_table_name)) {
+      return Some(super::Expr::column_eq(&format!("{}.{}", table_name, column_name), &format!("{}.{}", join_alias, N::primary_key())));
+    }
+    if N::table_column_names().is_empty() {
+      let column_name = format!("{}_id", inflector::string::singularize::to_singular(&table_name));
+      return Some(super::Expr::column_eq(&format!("{}.{}", table_name, M::primary_key()), &format!("{}.{}", join_alias, column_name)));
+    }
+    None
+  }
+  fn on_to_sql(&self, on: &super::Expr, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    on.to_sql::<M>(dialect).map_err(|error| error.with_clause("JOIN"))
+  }
+  fn typed_to_sql(&self, join_type: JoinType, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    let mut sql = Sql::default();
+    // 子查询自带括号
+    let source = match (&self.subquery, &self.table_name) {
+      (Some(subquery), _) => subquery.to_sql(dialect)?,
+      (None, Some(_)) if self.lateral => return Err(crate::error::SqlError::MissingValue { clause: "JOIN" }),
+      (None, Some(table_name)) => Sql::new(dialect.try_quote_identifier(table_name)?),
+      (None, None) => return Err(crate::error::SqlError::MissingValue { clause: "JOIN" }),
+    };
+
+    if self.lateral {
+      let alias = self.alias.as_ref().ok_or(crate::error::SqlError::MissingValue { clause: "JOIN" })?;
+      let alias = dialect.try_quote_identifier(alias)?;
+      match dialect {
+        // mssql: CROSS APPLY/OUTER APPLY, 条件写在子查询中
+        crate::Dialect::Mssql => {
+          let keyword = match join_type {
+            JoinType::Inner | JoinType::Cross => "CROSS APPLY",
+            JoinType::Left => "OUTER APPLY",
+            _ => return Err(crate::error::SqlError::DialectUnsupported { dialect, feature: "RIGHT/FULL LATERAL JOIN".to_owned() }),
+          };
+          if self.on.is_some() {
+            return Err(crate::error::SqlError::DialectUnsupported { dialect, feature: "LATERAL JOIN ... ON".to_owned() });
+          }
+          sql.push_value(&format!("{} ", keyword)).push_sql(&source).push_value(&format!(" AS {}", alias));
+        },
+        crate::Dialect::Sqlite => return Err(crate::error::SqlError::DialectUnsupported { dialect, feature: "LATERAL JOIN".to_owned() }),
+        _ => {
+          if matches!(join_type, JoinType::Right | JoinType::Full) {
+            return Err(crate::error::SqlError::DialectUnsupported { dialect, feature: "RIGHT/FULL LATERAL JOIN".to_owned() });
+          }
+          sql.push_value(&format!("{} LATERAL ", join_type.to_sql(dialect)?)).push_sql(&source).push_value(&format!(" AS {}", alias));
+          if join_type != JoinType::Cross {
+            match self.on {
+              Some(ref on) => { sql.push_value(" ON ").push_sql(&self.on_to_sql(on, dialect)?); },
+              None => { sql.push_value(" ON TRUE"); },
+            }
+          }
+        },
+      }
+      return Ok(sql);
+    }
+
+    sql.push_value(&format!("{} ", join_type.to_sql(dialect)?)).push_sql(&source);
+    if let Some(ref alias) = self.alias {
+      sql.push_value(&format!(" AS {}", dialect.try_quote_identifier(alias)?));
+    }
+    if join_type != JoinType::Cross {
+      let on = self.on.as_ref().ok_or(crate::error::SqlError::MissingValue { clause: "JOIN" })?;
+      sql.push_value(" ON ").push_sql(&self.on_to_sql(on, dialect)?);
+    }
+    Ok(sql)
+  }
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
     let mut sql = Sql::default();
 
-    if let Some(ref value) = self.value {
-      sql.push_value(value);
-    } else {
-      return Err(crate::error::SqlError::Message(format!("Error: Join Value Must Exists!")));
+    if let Some(join_type) = self.join_type {
+      sql.push_sql(&self.typed_to_sql(join_type, dialect)?);
+      sql.dialect = Some(dialect);
+      return Ok(sql);
+    }
+
+    match self.value {
+      Some(serde_json::Value::String(ref value)) => {
+        sql.push_value(value);
+      },
+      // eg: ["left join orders on users.id = orders.user_id and orders.status = :status", {"status": 1}]
+      Some(serde_json::Value::Array(ref arr)) => {
+        sql.push_sql(&crate::methods::raw_condition_to_sql(arr).map_err(|error| error.with_clause("JOIN"))?);
+      },
+      Some(ref value) => return Err(crate::error::SqlError::UnsupportedValue { clause: "JOIN", value: value.to_string() }),
+      None => {
+        return Err(crate::error::SqlError::MissingValue { clause: "JOIN" });
+      },
     }
 
+    sql.dialect = Some(dialect);
     Ok(sql)
   }
 }
@@ -34,18 +183,38 @@ mod tests {
     use super::*;
     #[test]
     fn to_sql() {
-      struct User {}
-      impl crate::Manageable for User {}
-
-      #[cfg(feature = "mysql")]
+      // mysql
       {
+        struct User {}
+        impl crate::Manageable for User {}
+
         let join = Join::<User>::default();
-        assert!(join.to_sql().is_err());
+        assert!(join.to_sql(crate::Dialect::Mysql).is_err());
 
-        let mut join = Join::<User>::default();
-        join.value = Some("left join orders on users.id = orders.user_id".into());
-        let sql_string: String = join.to_sql().unwrap().try_into().unwrap();
+        let join = Join::<User> {
+          value: Some("left join orders on users.id = orders.user_id".into()),
+          ..Join::<User>::default()
+        };
+        let sql_string: String = join.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
         assert_eq!(&sql_string, "left join orders on users.id = orders.user_id");
       }
     }
-}
\ No newline at end of file
+    #[test]
+    fn to_sql_with_named_params() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let mut join = Join::<User> {
+        value: Some(serde_json::json!(["LEFT JOIN orders ON users.id = orders.user_id AND orders.status = :status", {"status": "paid"}])),
+        ..Join::<User>::default()
+      };
+      let sql = join.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::Dollar), "LEFT JOIN orders ON users.id = orders.user_id AND orders.status = $1");
+      assert_eq!(String::try_from(sql).unwrap(), "LEFT JOIN orders ON users.id = orders.user_id AND orders.status = 'paid'");
+
+      join.value = Some(serde_json::json!(["LEFT JOIN orders ON orders.status = :status", {"status": "paid", "user_id": 1}]));
+      assert!(join.to_sql(crate::Dialect::Postgresql).is_err());
+      join.value = Some(serde_json::json!(1));
+      assert!(join.to_sql(crate::Dialect::Postgresql).is_err());
+    }
+}
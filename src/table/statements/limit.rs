@@ -17,13 +17,19 @@ impl<M: crate::Manageable> Default for Limit<M> {
 
 impl<M: crate::Manageable> Limit<M> {
   pub fn new(value: usize) -> Self {
-    let mut limit = Limit::<M>::default();
-    limit.value = value;
-    limit
+    Limit::<M> {
+      value,
+      ..Limit::<M>::default()
+    }
   }
-  pub fn to_sql(&self) -> Result<Sql, crate::error::SqlError> {
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
     let mut sql = Sql::default();
-    sql.push_value(&format!("LIMIT {}", self.value));
+    // mssql: OFFSET ... ROWS FETCH NEXT ... ROWS ONLY
+    if dialect == crate::Dialect::Mssql {
+      sql.push_value(&format!("FETCH NEXT {} ROWS ONLY", self.value));
+    } else {
+      sql.push_value(&format!("LIMIT {}", self.value));
+    }
 
     Ok(sql)
   }
@@ -34,15 +40,17 @@ mod tests {
     use super::*;
     #[test]
     fn to_sql() {
-      struct User {}
-      impl crate::Manageable for User {}
-
-      #[cfg(feature = "mysql")]
+      // mysql
       {
+        struct User {}
+        impl crate::Manageable for User {}
+
         //
-        let mut limit = Limit::<User>::default();
-        limit.value = 10;
-        let sql_string: String = limit.to_sql().unwrap().try_into().unwrap();
+        let limit = Limit::<User> {
+          value: 10,
+          ..Limit::<User>::default()
+        };
+        let sql_string: String = limit.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
         assert_eq!(&sql_string, "LIMIT 10");
       }
     }
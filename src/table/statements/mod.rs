@@ -8,10 +8,14 @@ pub mod limit;
 pub mod offset;
 pub mod insert;
 pub mod update;
+pub mod subquery;
+pub mod with;
+pub mod window;
+pub mod expr;
 
-pub use select::Select;
+pub use select::{Select, Aggregate};
 pub use r#where::Where;
-pub use join::Join;
+pub use join::{Join, JoinType};
 pub use group::Group;
 pub use having::Having;
 pub use order::Order;
@@ -19,3 +23,7 @@ pub use limit::Limit;
 pub use offset::Offset;
 pub use insert::Insert;
 pub use update::Update;
+pub use subquery::Subquery;
+pub use with::{With, Cte};
+pub use window::{Window, WindowFunction, FrameUnit, FrameBound};
+pub use expr::{Expr, Operator, Operand};
@@ -17,13 +17,18 @@ impl<M: crate::Manageable> Default for Offset<M> {
 
 impl<M: crate::Manageable> Offset<M> {
   pub fn new(value: usize) -> Self {
-    let mut offset = Offset::<M>::default();
-    offset.value = value;
-    offset
+    Offset::<M> {
+      value,
+      ..Offset::<M>::default()
+    }
   }
-  pub fn to_sql(&self) -> Result<Sql, crate::error::SqlError> {
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
     let mut sql = Sql::default();
-    sql.push_value(&format!("OFFSET {}", self.value));
+    if dialect == crate::Dialect::Mssql {
+      sql.push_value(&format!("OFFSET {} ROWS", self.value));
+    } else {
+      sql.push_value(&format!("OFFSET {}", self.value));
+    }
 
     Ok(sql)
   }
@@ -34,15 +39,17 @@ mod tests {
     use super::*;
     #[test]
     fn to_sql() {
-      struct User {}
-      impl crate::Manageable for User {}
-
-      #[cfg(feature = "mysql")]
+      // mysql
       {
+        struct User {}
+        impl crate::Manageable for User {}
+
         //
-        let mut offset = Offset::<User>::default();
-        offset.value = 10;
-        let sql_string: String = offset.to_sql().unwrap().try_into().unwrap();
+        let offset = Offset::<User> {
+          value: 10,
+          ..Offset::<User>::default()
+        };
+        let sql_string: String = offset.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
         assert_eq!(&sql_string, "OFFSET 10");
       }
     }
@@ -3,6 +3,8 @@ use crate::collectors::Sql;
 
 pub struct Order<M: crate::Manageable> {
   pub value: Option<serde_json::Value>,
+  // (window function, asc/desc)
+  pub window: Option<(super::WindowFunction<M>, String)>,
   _marker: PhantomData<M>,
 }
 
@@ -10,6 +12,7 @@ impl<M: crate::Manageable> Default for Order<M> {
   fn default() -> Self {
       Self {
           value: None,
+          window: None,
           _marker: PhantomData
       }
   }
@@ -17,20 +20,34 @@ impl<M: crate::Manageable> Default for Order<M> {
 
 impl<M: crate::Manageable> Order<M> {
   pub fn new(condition: serde_json::Value) -> Self {
-    let mut order = Order::<M>::default();
-    order.value = Some(condition);
-    order
+    Order::<M> {
+      value: Some(condition),
+      ..Order::<M>::default()
+    }
+  }
+  pub fn new_window(function: super::WindowFunction<M>, direction: &str) -> Self {
+    Order::<M> {
+      window: Some((function, direction.to_owned())),
+      ..Order::<M>::default()
+    }
   }
-  pub fn to_sql(&self) -> Result<Sql, crate::error::SqlError> {
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
     let mut sql = Sql::default();
 
+    if let Some((ref function, ref direction)) = self.window {
+      sql.push_sql(&function.to_sql(dialect)?).push(' ').push_value(Self::validate_direction(direction)?);
+      return Ok(sql);
+    }
+
     if let Some(ref value) = self.value {
       match value {
         serde_json::Value::Object(obj) => {
-          for (_idx, column_name) in obj.keys().enumerate() {
-            sql.push_value(column_name);
+          for (idx, column_name) in obj.keys().enumerate() {
+            if idx > 0 { sql.push(','); }
+            sql.push_value(&dialect.try_quote_identifier(M::table_column_name(column_name)?)?);
             let value = obj.get(column_name).unwrap();
-            sql.push(' ').push_value(value.as_str().unwrap());
+            let direction = value.as_str().ok_or_else(|| crate::error::SqlError::UnsupportedValue { clause: "ORDER BY", value: value.to_string() })?;
+            sql.push(' ').push_value(Self::validate_direction(direction)?);
           }
         },
         serde_json::Value::Array(arr) => {
@@ -40,17 +57,24 @@ impl<M: crate::Manageable> Order<M> {
               _ => v.to_string()
             }
           }).collect();
-          sql.push_value(&format!("{}", values.join(",")));
+          sql.push_value(&values.join(","));
         },
         serde_json::Value::String(str) => {
           sql.push_value(str);
         },
-        _ => return Err(crate::error::SqlError::Message(format!("Error: Value {:?} Type Not Support", self.value)))
+        _ => return Err(crate::error::SqlError::UnsupportedValue { clause: "ORDER BY", value: value.to_string() })
       }
     }
 
     Ok(sql)
   }
+  // 方向只能是 asc/desc, 不区分大小写
+  fn validate_direction(direction: &str) -> Result<&str, crate::error::SqlError> {
+    if !direction.eq_ignore_ascii_case("asc") && !direction.eq_ignore_ascii_case("desc") {
+      return Err(crate::error::SqlError::UnsupportedValue { clause: "ORDER BY", value: direction.to_owned() });
+    }
+    Ok(direction)
+  }
 }
 
 #[cfg(test)]
@@ -58,21 +82,41 @@ mod tests {
     use super::*;
     #[test]
     fn to_sql() {
-      struct User {}
-      impl crate::Manageable for User {}
-
-      #[cfg(feature = "mysql")]
+      // mysql
       {
+        struct User {}
+        impl crate::Manageable for User {}
+
         //
-        let mut order = Order::<User>::default();
-        order.value = Some(serde_json::json!("a"));
-        let sql_string: String = order.to_sql().unwrap().try_into().unwrap();
+        let order = Order::<User> {
+          value: Some(serde_json::json!("a")),
+          ..Order::<User>::default()
+        };
+        let sql_string: String = order.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
         assert_eq!(&sql_string, "a");
 
-        let mut order = Order::<User>::default();
-        order.value = Some(serde_json::json!(["a", "b", "c"]));
-        let sql_string: String = order.to_sql().unwrap().try_into().unwrap();
+        let order = Order::<User> {
+          value: Some(serde_json::json!(["a", "b", "c"])),
+          ..Order::<User>::default()
+        };
+        let sql_string: String = order.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
         assert_eq!(&sql_string, "a,b,c");
+
+        let order = Order::<User>::new(serde_json::json!({"a": "asc", "b": "DESC"}));
+        let sql_string: String = order.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "`a` asc,`b` DESC");
       }
     }
-}
\ No newline at end of file
+    #[test]
+    fn to_sql_with_error() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let order = Order::<User>::new(serde_json::json!({"id": 1}));
+      assert_eq!(order.to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::UnsupportedValue { clause: "ORDER BY", value: "1".to_owned() });
+      let order = Order::<User>::new(serde_json::json!({"id": "desc; DROP TABLE users"}));
+      assert_eq!(order.to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::UnsupportedValue { clause: "ORDER BY", value: "desc; DROP TABLE users".to_owned() });
+      let order = Order::<User>::new(serde_json::json!(1));
+      assert_eq!(order.to_sql(crate::Dialect::Mysql).unwrap_err().to_string(), "ORDER BY: unsupported value 1");
+    }
+}
@@ -1,9 +1,30 @@
 use std::marker::PhantomData;
 use crate::collectors::Sql;
 
+pub enum Aggregate {
+  // None: COUNT(*)
+  Count(Option<String>),
+  CountDistinct(String),
+  Sum(String),
+  Avg(String),
+  Min(String),
+  Max(String),
+  // SELECT EXISTS(SELECT 1 FROM ...), 由 SelectManager 包装
+  Exists,
+}
+
 pub struct Select<M: crate::Manageable> {
-  pub columns: Option<Vec<String>>,
+  // "COUNT(*) AS total": 原样使用, ["id", "users.name"]: 列名, {"name": "user_name"}: name AS user_name
+  pub columns: Option<Vec<serde_json::Value>>,
   pub distinct: Option<bool>,
+  // 标量子查询, 跟在 columns 之后, eg: (SELECT COUNT(*) FROM orders WHERE orders.user_id = users.id) AS orders_count
+  pub subqueries: Option<Vec<(super::Subquery, String)>>,
+  // 窗口函数, 跟在 subqueries 之后, eg: ROW_NUMBER() OVER (...) AS row_number
+  pub windows: Option<Vec<(super::WindowFunction<M>, String)>>,
+  // 跟在 windows 之后
+  pub aggregate: Option<Aggregate>,
+  // FROM (subquery) AS alias
+  pub from: Option<(super::Subquery, String)>,
   _marker: PhantomData<M>,
 }
 
@@ -12,30 +33,100 @@ impl<M: crate::Manageable> Default for Select<M> {
       Self {
           columns: None,
           distinct: None,
+          subqueries: None,
+          windows: None,
+          aggregate: None,
+          from: None,
           _marker: PhantomData
       }
   }
 }
 
 impl<M: crate::Manageable> Select<M> {
-  pub fn to_sql(&self) -> Result<Sql, crate::error::SqlError> {
+  fn column_name_to_sql(&self, column_name: &serde_json::Value, dialect: crate::Dialect) -> Result<String, crate::error::SqlError> {
+    let column_name = column_name.as_str().ok_or_else(|| crate::error::SqlError::UnsupportedValue { clause: "SELECT", value: column_name.to_string() })?;
+    dialect.try_quote_identifier(M::table_column_name(column_name)?)
+  }
+  fn column_to_sql(&self, column: &serde_json::Value, dialect: crate::Dialect) -> Result<String, crate::error::SqlError> {
+    match column {
+      serde_json::Value::String(string) => Ok(string.to_owned()),
+      serde_json::Value::Array(arr) => {
+        Ok(arr.iter().map(|column_name| self.column_name_to_sql(column_name, dialect)).collect::<Result<Vec<String>, crate::error::SqlError>>()?.join(","))
+      },
+      serde_json::Value::Object(obj) => {
+        let mut values = vec![];
+        for (column_name, alias) in obj.iter() {
+          let alias = alias.as_str().ok_or_else(|| crate::error::SqlError::UnsupportedValue { clause: "SELECT", value: alias.to_string() })?;
+          values.push(format!("{} AS {}", dialect.try_quote_identifier(M::table_column_name(column_name)?)?, dialect.try_quote_identifier(alias)?));
+        }
+        Ok(values.join(","))
+      },
+      _ => Err(crate::error::SqlError::UnsupportedValue { clause: "SELECT", value: column.to_string() }),
+    }
+  }
+  pub(crate) fn aggregate_to_sql(&self, aggregate: &Aggregate, dialect: crate::Dialect) -> Result<String, crate::error::SqlError> {
+    let quote = |column_name: &str| dialect.try_quote_identifier(M::table_column_name(column_name)?);
+    Ok(match aggregate {
+      Aggregate::Count(None) => "COUNT(*)".to_owned(),
+      Aggregate::Count(Some(column_name)) => format!("COUNT({})", quote(column_name)?),
+      Aggregate::CountDistinct(column_name) => format!("COUNT(DISTINCT {})", quote(column_name)?),
+      // DISTINCT 时只计算不同的值, eg: SUM(DISTINCT amount)
+      Aggregate::Sum(column_name) => format!("SUM({}{})", if self.distinct == Some(true) { "DISTINCT " } else { "" }, quote(column_name)?),
+      Aggregate::Avg(column_name) => format!("AVG({}{})", if self.distinct == Some(true) { "DISTINCT " } else { "" }, quote(column_name)?),
+      Aggregate::Min(column_name) => format!("MIN({})", quote(column_name)?),
+      Aggregate::Max(column_name) => format!("MAX({})", quote(column_name)?),
+      Aggregate::Exists => "1".to_owned(),
+    })
+  }
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    self.to_sql_with_aggregate(dialect, self.aggregate.as_ref())
+  }
+  // aggregate 为 None 时不生成聚合函数, eg: COUNT 包装的 SELECT DISTINCT 子查询
+  pub(crate) fn to_sql_with_aggregate(&self, dialect: crate::Dialect, aggregate: Option<&Aggregate>) -> Result<Sql, crate::error::SqlError> {
     let mut sql = Sql::default();
     sql.push_value("SELECT ");
 
-    if let Some(distinct) = self.distinct && distinct {
+    if let Some(distinct) = self.distinct && distinct && aggregate.is_none() {
       sql.push_value("DISTINCT ");
     }
 
-    match self.columns {
-      Some(ref columns) => {
-        let value = columns.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(",");
-        sql.push_value(&value).push(' ');
+    let mut values = vec![];
+    if let Some(ref columns) = self.columns {
+      for column in columns.iter() {
+        values.push(Sql::new(self.column_to_sql(column, dialect)?));
+      }
+    }
+    if let Some(ref subqueries) = self.subqueries {
+      for (subquery, alias) in subqueries.iter() {
+        let mut value = subquery.to_sql(dialect)?;
+        value.push_value(&format!(" AS {}", dialect.try_quote_identifier(alias)?));
+        values.push(value);
+      }
+    }
+    if let Some(ref windows) = self.windows {
+      for (window, alias) in windows.iter() {
+        let mut value = window.to_sql(dialect)?;
+        value.push_value(&format!(" AS {}", dialect.try_quote_identifier(alias)?));
+        values.push(value);
+      }
+    }
+    if let Some(aggregate) = aggregate {
+      values.push(Sql::new(self.aggregate_to_sql(aggregate, dialect)?));
+    }
+    if values.is_empty() {
+      sql.push_value("* ");
+    } else {
+      sql.push_sqls(&values, ",").push(' ');
+    }
+
+    match self.from {
+      Some((ref subquery, ref alias)) => {
+        sql.push_value("FROM ").push_sql(&subquery.to_sql(dialect)?).push_value(&format!(" AS {}", dialect.try_quote_identifier(alias)?));
       },
       None => {
-        sql.push_value("* ");
-      }
+        sql.push_value(&format!("FROM {}", dialect.try_quote_identifier(&M::table_name())?));
+      },
     }
-    sql.push_value(&format!("FROM {}", M::table_name()));
 
     Ok(sql)
   }
@@ -46,20 +137,54 @@ mod tests {
     use super::*;
     #[test]
     fn to_sql() {
-      struct User {}
-      impl crate::Manageable for User {}
-
-      #[cfg(feature = "mysql")]
+      // mysql
       {
+        struct User {}
+        impl crate::Manageable for User {}
+
         let select = Select::<User>::default();
-        let sql_string: String = select.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT * FROM users");
+        let sql_string: String = select.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT * FROM `users`");
 
-        let mut select = Select::<User>::default();
-        select.columns = Some(vec!["id".into(), "name".into()]);
-        select.distinct = Some(true);
-        let sql_string: String = select.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "SELECT DISTINCT id,name FROM users");
+        let select = Select::<User> {
+          columns: Some(vec!["id".into(), "name".into()]),
+          distinct: Some(true),
+          ..Select::<User>::default()
+        };
+        let sql_string: String = select.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "SELECT DISTINCT id,name FROM `users`");
       }
     }
-}
\ No newline at end of file
+    #[test]
+    fn to_sql_with_columns() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let mut select = Select::<User> {
+        columns: Some(vec![serde_json::json!(["id", "users.name", "orders.*"]), serde_json::json!({"created_at": "joined_at"}), serde_json::json!("COUNT(orders.id) AS orders_count")]),
+        ..Select::<User>::default()
+      };
+      assert_eq!(String::try_from(select.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "SELECT `id`,`users`.`name`,`orders`.*,`created_at` AS `joined_at`,COUNT(orders.id) AS orders_count FROM `users`");
+      assert_eq!(String::try_from(select.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "SELECT [id],[users].[name],[orders].*,[created_at] AS [joined_at],COUNT(orders.id) AS orders_count FROM [users]");
+
+      select.columns = Some(vec![serde_json::json!([1])]);
+      assert_eq!(select.to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::UnsupportedValue { clause: "SELECT", value: "1".to_owned() });
+      select.columns = Some(vec![serde_json::json!({"name": "user name\n"})]);
+      assert!(select.to_sql(crate::Dialect::Mysql).is_err());
+    }
+    #[test]
+    fn to_sql_with_aggregate() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let mut select = Select::<User> {
+        distinct: Some(true),
+        aggregate: Some(Aggregate::CountDistinct("users.email".to_owned())),
+        ..Select::<User>::default()
+      };
+      assert_eq!(String::try_from(select.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "SELECT COUNT(DISTINCT \"users\".\"email\") FROM \"users\"");
+      select.columns = Some(vec![serde_json::json!(["status"])]);
+      select.aggregate = Some(Aggregate::Sum("amount".to_owned()));
+      assert_eq!(String::try_from(select.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "SELECT `status`,SUM(DISTINCT `amount`) FROM `users`");
+    }
+}
@@ -0,0 +1,69 @@
+use crate::collectors::Sql;
+
+// 子查询, 不同 Manageable 的 SelectManager 统一保存, 在父查询 to_sql 时生成并合并参数
+// Send + Sync: 保存子查询的 SelectManager 等可以跨 .await 持有
+pub struct Subquery {
+  to_sql: Box<dyn Fn(crate::Dialect) -> Result<Sql, crate::error::SqlError> + Send + Sync>,
+}
+
+impl<N: crate::Manageable + Send + Sync + 'static> From<crate::manager::SelectManager<N>> for Subquery {
+  fn from(select_manager: crate::manager::SelectManager<N>) -> Self {
+    Self {
+      to_sql: Box::new(move |dialect| select_manager.to_sql(dialect)),
+    }
+  }
+}
+
+impl Subquery {
+  pub fn new(to_sql: impl Fn(crate::Dialect) -> Result<Sql, crate::error::SqlError> + Send + Sync + 'static) -> Self {
+    Self {
+      to_sql: Box::new(to_sql),
+    }
+  }
+  // 递归 CTE: anchor UNION ALL recursive
+  pub fn union_all(anchor: Subquery, recursive: Subquery) -> Self {
+    Self {
+      to_sql: Box::new(move |dialect| {
+        let mut sql = Sql::default();
+        sql.push_sql(&(anchor.to_sql)(dialect)?).push_value(" UNION ALL ").push_sql(&(recursive.to_sql)(dialect)?);
+        Ok(sql)
+      }),
+    }
+  }
+  // eg: (SELECT `user_id` FROM `orders` WHERE `status` = ?)
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    let mut sql = Sql::default();
+    sql.push('(').push_sql(&(self.to_sql)(dialect)?).push(')');
+    sql.dialect = Some(dialect);
+    Ok(sql)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn to_sql() {
+      struct Order {}
+      impl crate::Manageable for Order {}
+
+      let mut select_manager = crate::manager::SelectManager::<Order>::default();
+      select_manager.select(["user_id"]).where_prepare(serde_json::json!({"status": "paid"}));
+      let subquery = Subquery::from(select_manager);
+      let sql = subquery.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::Dollar), "(SELECT \"user_id\" FROM \"orders\" WHERE \"status\" = $1)");
+      assert_eq!(String::try_from(sql).unwrap(), "(SELECT \"user_id\" FROM \"orders\" WHERE \"status\" = 'paid')");
+    }
+    #[test]
+    fn send_sync() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      fn assert_send_sync<T: Send + Sync>() {}
+      assert_send_sync::<Subquery>();
+      assert_send_sync::<crate::manager::SelectManager<User>>();
+      assert_send_sync::<crate::manager::CompoundManager<User>>();
+      assert_send_sync::<crate::manager::UpdateManager<User>>();
+      assert_send_sync::<crate::manager::DeleteManager<User>>();
+    }
+}
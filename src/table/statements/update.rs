@@ -1,8 +1,10 @@
 use std::{marker::PhantomData};
-use crate::collectors::Sql;
+use crate::collectors::{Sql, SqlValue};
 
 pub struct Update<M: crate::Manageable> {
   pub value: Option<serde_json::Value>,
+  // 按类型绑定的值, 不为空时代替 value, eg: Bytes, DateTime
+  pub values: Option<Vec<(String, SqlValue)>>,
   pub prepare: Option<bool>,
   _marker: PhantomData<M>,
 }
@@ -11,6 +13,7 @@ impl<M: crate::Manageable> Default for Update<M> {
   fn default() -> Self {
       Self {
           value: None,
+          values: None,
           prepare: None,
           _marker: PhantomData
       }
@@ -19,35 +22,57 @@ impl<M: crate::Manageable> Default for Update<M> {
 
 impl<M: crate::Manageable> Update<M> {
   pub fn new(condition: serde_json::Value, prepare: Option<bool>) -> Self {
-    let mut update = Update::default();
-    update.value = Some(condition);
-    update.prepare = prepare;
-    update
+    Update {
+      value: Some(condition),
+      prepare,
+      ..Update::default()
+    }
   }
-  pub fn to_sql(&self) -> Result<Sql, crate::error::SqlError> {
-    let mut sql = Sql::new(format!("UPDATE {} SET", M::table_name()));
-
-    if let Some(ref value) = self.value {
-      match value {
-        serde_json::Value::Object(obj) => {
-          let column_names: Vec<String> = obj.keys().into_iter().map(|column_name| column_name.into()).collect();
-          let column_values = obj.keys().into_iter().map(|column_name| crate::methods::json_value_to_string(obj.get(column_name).unwrap())).collect::<Result<Vec<String>, crate::SqlError>>()?;
+  pub fn new_values(values: Vec<(String, SqlValue)>, prepare: Option<bool>) -> Self {
+    Update {
+      values: Some(values),
+      prepare,
+      ..Update::default()
+    }
+  }
+  // 数组和对象作为 Json
+  fn column_values(&self) -> Result<Option<Vec<(String, SqlValue)>>, crate::error::SqlError> {
+    match (&self.values, &self.value) {
+      (Some(values), _) => Ok(Some(values.clone())),
+      (None, Some(serde_json::Value::Object(obj))) => Ok(Some(obj.iter().map(|(column_name, value)| (column_name.to_owned(), SqlValue::from(value))).collect())),
+      (None, Some(value)) => Err(crate::error::SqlError::UnsupportedValue { clause: "UPDATE", value: value.to_string() }),
+      (None, None) => Ok(None),
+    }
+  }
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    self.to_sql_with_top(None, dialect)
+  }
+  // mssql: UPDATE TOP (n) table SET ...
+  pub fn to_sql_with_top(&self, top: Option<usize>, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    let mut sql = Sql::new("UPDATE ".to_owned());
+    if let Some(top) = top {
+      sql.push_value(&format!("TOP ({}) ", top));
+    }
+    sql.push_value(&format!("{} SET", dialect.try_quote_identifier(&M::table_name())?));
 
-          sql.push_value(" ");
-          for (idx, column_name) in column_names.iter().enumerate() {
-            if idx > 0 { sql.push_value(", "); }
-            sql.push_value(&format!("{} = ", column_name));
-            if let Some(prepare) = self.prepare && prepare {
-              sql.push_value_with_prepare_value("?", &column_values[idx]);
-            } else {
-              sql.push_value(&column_values[idx]);
-            }
-          }
-        },
-        _ => return Err(crate::error::SqlError::Message(format!("Error: Update Value {:?} Not Support", value)))
+    if let Some(column_values) = self.column_values()? {
+      // eg: 没有属性的 Manageable, 不能生成 () VALUES ()
+      if column_values.is_empty() {
+        return Err(crate::error::SqlError::MissingValue { clause: "UPDATE" });
+      }
+      sql.push_value(" ");
+      for (idx, (column_name, column_value)) in column_values.iter().enumerate() {
+        if idx > 0 { sql.push_value(", "); }
+        sql.push_value(&format!("{} = ", dialect.try_quote_identifier(M::table_column_name(column_name)?)?));
+        if let Some(prepare) = self.prepare && prepare {
+          sql.push_placeholder(column_value.clone());
+        } else {
+          sql.push_value(&column_value.to_sql_literal(dialect)?);
+        }
       }
     }
 
+    sql.dialect = Some(dialect);
     Ok(sql)
   }
 }
@@ -57,25 +82,50 @@ mod tests {
     use super::*;
     #[test]
     fn to_sql() {
-      struct User {}
-      impl crate::Manageable for User {}
-
-      #[cfg(feature = "mysql")]
+      // mysql
       {
+        struct User {}
+        impl crate::Manageable for User {}
+
         //
-        let mut update = Update::<User>::default();
-        update.value = Some(serde_json::json!({
-          "a": 1,
-          "b": false,
-          "c": null,
-          "d": "desc"
-        }));
-        let sql_string: String = update.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "UPDATE users SET a = 1, b = 0, c = null, d = 'desc'");
+        let mut update = Update::<User> {
+          value: Some(serde_json::json!({
+            "a": 1,
+            "b": false,
+            "c": null,
+            "d": "desc"
+          })),
+          ..Update::<User>::default()
+        };
+        let sql_string: String = update.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "UPDATE `users` SET `a` = 1, `b` = 0, `c` = null, `d` = 'desc'");
 
         update.prepare = Some(true);
-        let sql = update.to_sql().unwrap();
-        assert_eq!(&sql.value, "UPDATE users SET a = ?, b = ?, c = ?, d = ?");
+        let sql = update.to_sql(crate::Dialect::Mysql).unwrap();
+        assert_eq!(&sql.value, "UPDATE `users` SET `a` = ?, `b` = ?, `c` = ?, `d` = ?");
       }
     }
-}
\ No newline at end of file
+    #[test]
+    fn to_sql_with_escape() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let update = Update::<User>::new(serde_json::json!({"name": "O'Brien", "title": "Zoë"}), None);
+      assert_eq!(String::try_from(update.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "UPDATE `users` SET `name` = 'O''Brien', `title` = 'Zoë'");
+      assert_eq!(String::try_from(update.to_sql(crate::Dialect::Sqlite).unwrap()).unwrap(), "UPDATE \"users\" SET \"name\" = 'O''Brien', \"title\" = 'Zoë'");
+      assert_eq!(String::try_from(update.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "UPDATE [users] SET [name] = 'O''Brien', [title] = N'Zoë'");
+    }
+    #[test]
+    fn to_sql_with_typed_values() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let update = Update::<User>::new(serde_json::json!({"tags": ["a", "b"]}), Some(true));
+      let sql = update.to_sql(crate::Dialect::Mysql).unwrap();
+      assert_eq!(&sql.value, "UPDATE `users` SET `tags` = ?");
+      assert_eq!(sql.prepare_value, Some(vec![SqlValue::Json(serde_json::json!(["a", "b"]))]));
+
+      let update = Update::<User>::new_values(vec![("avatar".to_owned(), SqlValue::from(vec![0x01u8, 0xff]))], None);
+      assert_eq!(String::try_from(update.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "UPDATE [users] SET [avatar] = 0x01ff");
+    }
+}
@@ -1,177 +1,126 @@
 use std::marker::PhantomData;
-use crate::collectors::Sql;
+use crate::collectors::{Sql, SqlValue};
 use std::ops::{Bound, RangeBounds};
 
 pub struct Where<M: crate::Manageable> {
-  pub value: Option<serde_json::Value>,
-  pub not: Option<bool>,
-  pub or: Option<bool>,
-  pub prepare: Option<bool>,
+  pub expr: Option<super::Expr>,
+  // (column_name, start, end, prepare)
+  pub range: Option<(String, Bound<SqlValue>, Bound<SqlValue>, bool)>,
+  // (column_name, subquery, not), column_name 为空时: EXISTS (subquery)
+  pub subquery: Option<(Option<String>, super::Subquery, bool)>,
   _marker: PhantomData<M>,
 }
 
 impl<M: crate::Manageable> Default for Where<M> {
   fn default() -> Self {
       Self {
-        value: None,
-        not: None,
-        or: None,
-        prepare: None,
+        expr: None,
+        range: None,
+        subquery: None,
         _marker: PhantomData
       }
   }
 }
 
 impl<M: crate::Manageable> Where<M> {
-  pub fn new(condition: serde_json::Value, not: Option<bool>, or: Option<bool>, prepare: Option<bool>) -> Self {
-    let mut r#where = Where::<M>::default();
-    r#where.value = Some(condition);
-    r#where.not = not;
-    r#where.or = or;
-    r#where.prepare = prepare;
-    r#where
+  // JSON 条件, 见 Expr::from_json
+  pub fn new(condition: serde_json::Value) -> Self {
+    Self::new_expr(super::Expr::from_json(&condition))
   }
-  pub fn new_range<T: ToString>(column_name: &str, range: impl RangeBounds<T>) -> Self {
-    let generate_raw_sql = || -> Result<String, crate::error::SqlError> {
-      let raw_sql;
-
-      let get_bound_value = |value: &T| {
-        value.to_string()
-      };
-
-      match range.start_bound() {
-          Bound::Unbounded => {
-              match range.end_bound() {
-                  Bound::Unbounded => return Err(crate::error::SqlError::Message(format!("Error: Not Support"))),
-                  Bound::Included(end) => raw_sql = format!("{} <= {}", column_name, get_bound_value(end)),
-                  Bound::Excluded(end) => raw_sql = format!("{} < {}", column_name, get_bound_value(end)),
-              }
-          },
-          Bound::Included(start) => {
-              match range.end_bound() {
-                  Bound::Unbounded => {
-                      raw_sql = format!("{} >= {}", column_name, get_bound_value(start))
-                  },
-                  Bound::Included(end) => raw_sql = format!("{} BETWEEN {} AND {}", column_name, get_bound_value(start), get_bound_value(end)),
-                  Bound::Excluded(end) => raw_sql = format!("{} >= {} AND {} < {}", column_name, get_bound_value(start), column_name, get_bound_value(end)),
-              }
-          },
-          Bound::Excluded(start) => {
-              match range.end_bound() {
-                  Bound::Unbounded => raw_sql = format!("{} > {}", column_name, get_bound_value(start)),
-                  Bound::Included(end) => raw_sql = format!("{} > {} AND {} <= {}", column_name, get_bound_value(start), column_name, get_bound_value(end)),
-                  Bound::Excluded(end) => raw_sql = format!("{} > {} AND {} < {}", column_name, get_bound_value(start), column_name, get_bound_value(end)),
-              }
-          },
-      }
-      Ok(raw_sql)
-    };
-
-    let raw_sql = generate_raw_sql().unwrap();
-    Where::<M>::new(serde_json::json!(raw_sql), None, None, None)
+  pub fn new_expr(expr: super::Expr) -> Self {
+    Where::<M> {
+      expr: Some(expr),
+      ..Where::<M>::default()
+    }
   }
-  fn column_value_condition_to_sql(&self, column_value_condition: &serde_json::Value) -> Result<Sql, crate::error::SqlError> {
+  // 与其他条件使用 AND 连接时需要加括号
+  pub fn is_or(&self) -> bool {
+    matches!(self.expr, Some(super::Expr::Or(_)))
+  }
+  // 值根据类型和数据库格式化, prepare 时使用占位符
+  pub fn new_range<T: Clone + Into<SqlValue>>(column_name: &str, range: impl RangeBounds<T>, prepare: Option<bool>) -> Self {
+    let get_bound_value = |bound: Bound<&T>| bound.cloned().map(Into::into);
+    Where::<M> {
+      // 列名需要根据数据库加引号, 所以在 to_sql 时才生成 sql
+      range: Some((column_name.to_owned(), get_bound_value(range.start_bound()), get_bound_value(range.end_bound()), prepare.unwrap_or(false))),
+      ..Where::<M>::default()
+    }
+  }
+  pub fn new_subquery(column_name: Option<&str>, subquery: super::Subquery, not: Option<bool>) -> Self {
+    Where::<M> {
+      subquery: Some((column_name.map(|column_name| column_name.to_owned()), subquery, not.unwrap_or(false))),
+      ..Where::<M>::default()
+    }
+  }
+  fn subquery_to_sql(&self, column_name: &Option<String>, subquery: &super::Subquery, not: bool, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
     let mut sql = Sql::default();
-    match column_value_condition {
-      serde_json::Value::Array(arr) => {
-        let values = arr.iter().map(|v| crate::methods::json_value_to_string(v)).collect::<Result<Vec<String>, crate::SqlError>>()?;
-        if let Some(prepare) = self.prepare && prepare {
-          if let Some(not) = self.not && not {
-            sql.push_value("NOT IN (");
-          } else {
-            sql.push_value("IN (");
-          }
-          let len = values.len();
-          for (idx, value) in values.iter().enumerate() {
-            if idx < len - 1 {
-              sql.push_value_with_prepare_value("?,", value);
-            } else {
-              sql.push_value_with_prepare_value("?", value);
-            }
-          }
-          sql.push(')');
-        } else {
-          if let Some(not) = self.not && not {
-            sql.push_value(&format!("NOT IN ({})", values.join(",")));
-          } else {
-            sql.push_value(&format!("IN ({})", values.join(",")));
-          }
-        }
-      },
-      serde_json::Value::String(_) | serde_json::Value::Number(_) | serde_json::Value::Bool(_) => {
-        let value = crate::methods::json_value_to_string(column_value_condition)?;
-        if let Some(prepare) = self.prepare && prepare {
-          if let Some(not) = self.not && not {
-            sql.push_value_with_prepare_value("!= ?", &value);
-          } else {
-            sql.push_value_with_prepare_value("= ?", &value);
-          }
-        } else {
-          if let Some(not) = self.not && not {
-            sql.push_value(&format!("!= {}", value));
-          } else {
-            sql.push_value(&format!("= {}", value));
-          }
-        }
+    match column_name {
+      Some(column_name) => {
+        sql.push_value(&dialect.try_quote_identifier(M::table_column_name(column_name)?)?);
+        sql.push_value(if not { " NOT IN " } else { " IN " });
       },
-      serde_json::Value::Null => {
-        if let Some(not) = self.not && not {
-          sql.push_value(&format!("IS NOT NULL"));
-        } else {
-          sql.push_value(&format!("IS NULL"));
-        }
+      None => {
+        sql.push_value(if not { "NOT EXISTS " } else { "EXISTS " });
       },
-      _ => return Err(crate::error::SqlError::Message(format!("Error: Where Value {:?} Type Not Support!", column_value_condition)))
     }
+    sql.push_sql(&subquery.to_sql(dialect)?);
     Ok(sql)
   }
-  fn condition_to_sql(&self, condition: &serde_json::Value) -> Result<Sql, crate::error::SqlError> {
+  fn range_to_sql(&self, column_name: &str, start: &Bound<SqlValue>, end: &Bound<SqlValue>, prepare: bool, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    let column = column_name.to_owned();
+    let column_name = dialect.try_quote_identifier(M::table_column_name(column_name)?)?;
+    let push_bound_value = |sql: &mut Sql, value: &SqlValue| -> Result<(), crate::error::SqlError> {
+      if prepare {
+        sql.push_placeholder(value.clone());
+      } else {
+        sql.push_value(&value.to_sql_literal(dialect)?);
+      }
+      Ok(())
+    };
     let mut sql = Sql::default();
-    match condition {
-      serde_json::Value::Object(obj) => {
-        for (idx, column_name) in obj.keys().enumerate() {
-          if idx > 0 {
-            if let Some(or) = self.or && or {
-              sql.push_value(" OR ");
-            } else {
-              sql.push_value(" AND ");
-            }
-          }
-          sql.push_value(column_name);
-          let column_value = obj.get(column_name).unwrap();
-
-          let value_sql = self.column_value_condition_to_sql(column_value)?;
-          sql.push(' ').push_sql(&value_sql);
-        }
-      },
-      serde_json::Value::Array(arr) => {
-        for (idx, value) in arr.iter().enumerate() {
-          if idx == 0 {
-            sql.push_value(value.as_str().unwrap());
-          } else {
-            let value = crate::methods::json_value_to_string(value)?;
-            sql.push_prepare_value(&value);
-          }
-        }
-      },
-      serde_json::Value::String(string) => {
-        sql.push_value(string);
+    if let (Bound::Included(start), Bound::Included(end)) = (start, end) {
+      sql.push_value(&format!("{} BETWEEN ", column_name));
+      push_bound_value(&mut sql, start)?;
+      sql.push_value(" AND ");
+      push_bound_value(&mut sql, end)?;
+      return Ok(sql);
+    }
+    let start = match start {
+      Bound::Unbounded => None,
+      Bound::Included(start) => Some((">=", start)),
+      Bound::Excluded(start) => Some((">", start)),
+    };
+    let end = match end {
+      Bound::Unbounded => None,
+      Bound::Included(end) => Some(("<=", end)),
+      Bound::Excluded(end) => Some(("<", end)),
+    };
+    if start.is_none() && end.is_none() {
+      return Err(crate::error::SqlError::InvalidRange { clause: "WHERE", column, reason: "start or end bound is required".to_owned() });
+    }
+    for (idx, (operator, value)) in start.into_iter().chain(end).enumerate() {
+      if idx > 0 {
+        sql.push_value(" AND ");
       }
-      _ => ()
+      sql.push_value(&format!("{} {} ", column_name, operator));
+      push_bound_value(&mut sql, value)?;
     }
     Ok(sql)
   }
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    let mut sql;
 
-
-  pub fn to_sql(&self) -> Result<Sql, crate::error::SqlError> {
-    let sql;
-
-    if let Some(ref condition) = self.value {
-      sql = self.condition_to_sql(condition)?;
+    if let Some((ref column_name, ref subquery, not)) = self.subquery {
+      sql = self.subquery_to_sql(column_name, subquery, not, dialect).map_err(|error| error.with_clause("WHERE"))?;
+    } else if let Some((ref column_name, ref start, ref end, prepare)) = self.range {
+      sql = self.range_to_sql(column_name, start, end, prepare, dialect).map_err(|error| error.with_clause("WHERE"))?;
+    } else if let Some(ref expr) = self.expr {
+      sql = expr.to_sql::<M>(dialect).map_err(|error| error.with_clause("WHERE"))?;
     } else {
-      return Err(crate::error::SqlError::Message(format!("where value must exists!")));
+      return Err(crate::error::SqlError::MissingValue { clause: "WHERE" });
     }
 
+    sql.dialect = Some(dialect);
     Ok(sql)
   }
 }
@@ -179,54 +128,142 @@ impl<M: crate::Manageable> Where<M> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::collectors::SqlValue;
     use chrono::{TimeZone};
     #[test]
     fn to_sql() {
-      struct User {}
-      impl crate::Manageable for User {}
-
-      #[cfg(feature = "mysql")]
+      // mysql
       {
+        struct User {}
+        impl crate::Manageable for User {}
+
         //
         let r#where = Where::<User>::default();
-        assert!(r#where.to_sql().is_err());
+        assert!(r#where.to_sql(crate::Dialect::Mysql).is_err());
 
-        let mut r#where = Where::<User>::default();
-        r#where.value = Some(serde_json::json!({"a": 1, "b": "2", "c": true, "d": [1, 2, 3], "e": null}));
-        let sql_string: String = r#where.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "a = 1 AND b = '2' AND c = 1 AND d IN (1,2,3) AND e IS NULL");
+        let mut r#where = Where::<User>::new(serde_json::json!({"a": 1, "b": "2", "c": true, "d": [1, 2, 3], "e": null}));
+        let sql_string: String = r#where.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "`a` = 1 AND `b` = '2' AND `c` = 1 AND `d` IN (1,2,3) AND `e` IS NULL");
 
-        r#where.not = Some(true);
-        let sql_string: String = r#where.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "a != 1 AND b != '2' AND c != 1 AND d NOT IN (1,2,3) AND e IS NOT NULL");
+        r#where = Where::<User>::new_expr(crate::table::statements::Expr::And(crate::table::statements::Expr::from_json_entries(&serde_json::json!({"a": 1, "b": "2", "c": true, "d": [1, 2, 3], "e": null})).into_iter().map(|expr| !expr).collect()));
+        let sql_string: String = r#where.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "`a` != 1 AND `b` != '2' AND `c` != 1 AND `d` NOT IN (1,2,3) AND `e` IS NOT NULL");
 
-        r#where.prepare = Some(true);
-        let sql = r#where.to_sql().unwrap();
-        assert_eq!(&sql.value, "a != ? AND b != ? AND c != ? AND d NOT IN (?,?,?) AND e IS NOT NULL");
+        r#where = Where::<User>::new_expr(crate::table::statements::Expr::And(crate::table::statements::Expr::from_json_entries(&serde_json::json!({"a": 1, "b": "2", "c": true, "d": [1, 2, 3], "e": null})).into_iter().map(|expr| !expr).collect()).prepare());
+        let sql = r#where.to_sql(crate::Dialect::Mysql).unwrap();
+        assert_eq!(&sql.value, "`a` != ? AND `b` != ? AND `c` != ? AND `d` NOT IN (?,?,?) AND `e` IS NOT NULL");
 
         //
-        let mut r#where = Where::<User>::default();
-        r#where.value = Some(serde_json::json!(["active = ?", true]));
-        let sql_string: String = r#where.to_sql().unwrap().try_into().unwrap();
+        let mut r#where = Where::<User>::new(serde_json::json!(["active = ?", true]));
+        let sql_string: String = r#where.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
         assert_eq!(&sql_string, "active = 1");
 
-        r#where.prepare = Some(true);
-        let sql = r#where.to_sql().unwrap();
+        r#where = Where::<User>::new_expr(crate::table::statements::Expr::from_json(&serde_json::json!(["active = ?", true])).prepare());
+        let sql = r#where.to_sql(crate::Dialect::Mysql).unwrap();
         assert_eq!(&sql.value, "active = ?");
 
         //
-        let r#where = Where::<User>::new_range("id", 1..100);
-        let sql_string: String = r#where.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "id >= 1 AND id < 100");
-        let r#where = Where::<User>::new_range("id", 1..=100);
-        let sql_string: String = r#where.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "id BETWEEN 1 AND 100");
-        let r#where = Where::<User>::new_range("expired_at", ..=chrono::Utc.ymd(2021, 12, 31).and_hms(23, 59, 59));
-        let sql_string: String = r#where.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "expired_at <= 2021-12-31 23:59:59 UTC");
-        let r#where = Where::<User>::new_range("expired_at", chrono::Utc.ymd(2021, 12, 31).and_hms(23, 59, 59)..);
-        let sql_string: String = r#where.to_sql().unwrap().try_into().unwrap();
-        assert_eq!(&sql_string, "expired_at >= 2021-12-31 23:59:59 UTC");
+        let r#where = Where::<User>::new_range("id", 1..100, None);
+        let sql_string: String = r#where.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "`id` >= 1 AND `id` < 100");
+        let r#where = Where::<User>::new_range("id", 1..=100, None);
+        let sql_string: String = r#where.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "`id` BETWEEN 1 AND 100");
+        let r#where = Where::<User>::new_range("expired_at", ..=chrono::Utc.with_ymd_and_hms(2021, 12, 31, 23, 59, 59).unwrap(), None);
+        let sql_string: String = r#where.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "`expired_at` <= '2021-12-31 23:59:59'");
+        let r#where = Where::<User>::new_range("expired_at", chrono::Utc.with_ymd_and_hms(2021, 12, 31, 23, 59, 59).unwrap().., None);
+        let sql_string: String = r#where.to_sql(crate::Dialect::Mysql).unwrap().try_into().unwrap();
+        assert_eq!(&sql_string, "`expired_at` >= '2021-12-31 23:59:59'");
       }
     }
-}
\ No newline at end of file
+    #[test]
+    fn to_sql_with_typed_range() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let r#where = Where::<User>::new_range("expired_at", chrono::Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap()..chrono::Utc.with_ymd_and_hms(2021, 12, 31, 23, 59, 59).unwrap(), None);
+      assert_eq!(String::try_from(r#where.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "\"expired_at\" >= '2021-01-01 00:00:00+00:00' AND \"expired_at\" < '2021-12-31 23:59:59+00:00'");
+      let r#where = Where::<User>::new_range("name", "a"..="m", None);
+      assert_eq!(String::try_from(r#where.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "[name] BETWEEN 'a' AND 'm'");
+      let r#where = Where::<User>::new_range("name", .."O'Brien", None);
+      assert_eq!(String::try_from(r#where.to_sql(crate::Dialect::Sqlite).unwrap()).unwrap(), "\"name\" < 'O''Brien'");
+
+      let r#where = Where::<User>::new_range("expired_at", chrono::NaiveDate::from_ymd_opt(2021, 1, 1).unwrap()..=chrono::NaiveDate::from_ymd_opt(2021, 12, 31).unwrap(), Some(true));
+      let sql = r#where.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::Dollar), "\"expired_at\" BETWEEN $1 AND $2");
+      assert_eq!(sql.prepare_value, Some(vec![SqlValue::Date(chrono::NaiveDate::from_ymd_opt(2021, 1, 1).unwrap()), SqlValue::Date(chrono::NaiveDate::from_ymd_opt(2021, 12, 31).unwrap())]));
+      let r#where = Where::<User>::new_range("id", 10_u64.., Some(true));
+      let sql = r#where.to_sql(crate::Dialect::Mysql).unwrap();
+      assert_eq!(sql.value, "`id` >= ?");
+      assert_eq!(sql.prepare_value, Some(vec![SqlValue::Int(10)]));
+    }
+    #[test]
+    fn to_sql_with_escape() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let mut r#where = Where::<User>::new(serde_json::json!({"name": "O'Brien", "path": "C:\\temp", "tags": ["a'b", "c"]}));
+      assert_eq!(String::try_from(r#where.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "`name` = 'O''Brien' AND `path` = 'C:\\\\temp' AND `tags` IN ('a''b','c')");
+      assert_eq!(String::try_from(r#where.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "\"name\" = 'O''Brien' AND \"path\" = E'C:\\\\temp' AND \"tags\" IN ('a''b','c')");
+      assert_eq!(String::try_from(r#where.to_sql(crate::Dialect::Sqlite).unwrap()).unwrap(), "\"name\" = 'O''Brien' AND \"path\" = 'C:\\temp' AND \"tags\" IN ('a''b','c')");
+      assert_eq!(String::try_from(r#where.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "[name] = 'O''Brien' AND [path] = 'C:\\temp' AND [tags] IN ('a''b','c')");
+
+      r#where = Where::<User>::new_expr(crate::table::statements::Expr::from_json(&serde_json::json!({"name": "O'Brien", "path": "C:\\temp", "tags": ["a'b", "c"]})).prepare());
+      assert_eq!(String::try_from(r#where.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "`name` = 'O''Brien' AND `path` = 'C:\\\\temp' AND `tags` IN ('a''b','c')");
+
+      let r#where = Where::<User>::new(serde_json::json!(["name = ? OR name = ?", "'; DROP TABLE users; --", "\\' OR 1=1 --"]));
+      assert_eq!(String::try_from(r#where.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "name = '''; DROP TABLE users; --' OR name = '\\\\'' OR 1=1 --'");
+      assert_eq!(String::try_from(r#where.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "name = '''; DROP TABLE users; --' OR name = E'\\\\'' OR 1=1 --'");
+
+      let r#where = Where::<User>::new(serde_json::json!({"name": "a\u{0}b"}));
+      assert_eq!(String::try_from(r#where.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "`name` = 'a\\0b'");
+      assert!(r#where.to_sql(crate::Dialect::Postgresql).is_err());
+    }
+    #[test]
+    fn to_sql_with_prepare_value() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let r#where = Where::<User>::new_expr(crate::table::statements::Expr::from_json(&serde_json::json!({"a": 1, "b": "2", "c": true, "d": [1.5, 18446744073709551615u64]})).prepare());
+      let sql = r#where.to_sql(crate::Dialect::Postgresql).unwrap();
+      assert_eq!(&sql.value, "\"a\" = ? AND \"b\" = ? AND \"c\" = ? AND \"d\" IN (?,?)");
+      assert_eq!(sql.prepare_value, Some(vec![SqlValue::Int(1), SqlValue::Text("2".to_owned()), SqlValue::Bool(true), SqlValue::Float(1.5), SqlValue::Decimal("18446744073709551615".to_owned())]));
+      assert_eq!(String::try_from(sql).unwrap(), "\"a\" = 1 AND \"b\" = '2' AND \"c\" = true AND \"d\" IN (1.5,18446744073709551615)");
+
+      let r#where = Where::<User>::new(serde_json::json!(["active = ? AND name = ?", false, null]));
+      let sql = r#where.to_sql(crate::Dialect::Mssql).unwrap();
+      assert_eq!(sql.prepare_value, Some(vec![SqlValue::Bool(false), SqlValue::Null]));
+      assert_eq!(String::try_from(sql).unwrap(), "active = 0 AND name = null");
+    }
+    #[test]
+    fn to_sql_with_named_params() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let mut r#where = Where::<User>::new(serde_json::json!(["created_at > :since AND (owner = :uid OR creator = :uid)", {"since": "2021-01-01", "uid": 1}]));
+      let sql = r#where.to_sql(crate::Dialect::Mssql).unwrap();
+      assert_eq!(sql.to_prepared_string(crate::PlaceholderStyle::AtP), "created_at > @p1 AND (owner = @p2 OR creator = @p3)");
+      assert_eq!(String::try_from(sql).unwrap(), "created_at > '2021-01-01' AND (owner = 1 OR creator = 1)");
+
+      r#where = Where::<User>::new(serde_json::json!(["created_at > :since AND owner = :uid", {"since": "2021-01-01"}]));
+      assert_eq!(r#where.to_sql(crate::Dialect::Mssql).unwrap_err(), crate::SqlError::MissingParameter { clause: "WHERE", name: "uid".to_owned(), sql: "created_at > :since AND owner = :uid".to_owned() });
+      r#where = Where::<User>::new(serde_json::json!(["owner = ?"]));
+      assert!(r#where.to_sql(crate::Dialect::Mssql).is_err());
+    }
+    #[test]
+    fn to_sql_with_error() {
+      struct User {}
+      impl crate::Manageable for User {}
+
+      let r#where = Where::<User>::new_range::<i32>("id", .., None);
+      assert_eq!(r#where.to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::InvalidRange { clause: "WHERE", column: "id".to_owned(), reason: "start or end bound is required".to_owned() });
+
+      let mut r#where = Where::<User>::new(serde_json::json!([1, 2]));
+      assert_eq!(r#where.to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::UnsupportedValue { clause: "WHERE", value: "1".to_owned() });
+      r#where = Where::<User>::new(serde_json::json!({"a": {"b": 1}}));
+      assert_eq!(r#where.to_sql(crate::Dialect::Mysql).unwrap_err().to_string(), "WHERE: unsupported value {\"b\":1}");
+      r#where = Where::<User>::new(serde_json::json!(["a = ? AND b = ?", 1]));
+      assert_eq!(r#where.to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::ParameterCountMismatch { clause: "WHERE", expected: 2, actual: 1, sql: "a = ? AND b = ?".to_owned() });
+    }
+}
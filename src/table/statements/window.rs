@@ -0,0 +1,239 @@
+use std::marker::PhantomData;
+use crate::collectors::Sql;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameUnit {
+  Rows,
+  Range,
+  // postgresql, sqlite
+  Groups,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameBound {
+  UnboundedPreceding,
+  Preceding(u64),
+  CurrentRow,
+  Following(u64),
+  UnboundedFollowing,
+}
+
+impl FrameBound {
+  fn to_sql(self) -> String {
+    match self {
+      FrameBound::UnboundedPreceding => "UNBOUNDED PRECEDING".to_owned(),
+      FrameBound::Preceding(value) => format!("{} PRECEDING", value),
+      FrameBound::CurrentRow => "CURRENT ROW".to_owned(),
+      FrameBound::Following(value) => format!("{} FOLLOWING", value),
+      FrameBound::UnboundedFollowing => "UNBOUNDED FOLLOWING".to_owned(),
+    }
+  }
+}
+
+// 窗口定义, eg: PARTITION BY user_id ORDER BY created_at ROWS BETWEEN 2 PRECEDING AND CURRENT ROW
+pub struct Window<M: crate::Manageable> {
+  // 基于命名窗口, eg: OVER (w ORDER BY id)
+  pub base: Option<String>,
+  pub partitions: Option<Vec<String>>,
+  pub orders: Option<Vec<super::Order<M>>>,
+  // (unit, start, end), end 为空时: ROWS UNBOUNDED PRECEDING
+  pub frame: Option<(FrameUnit, FrameBound, Option<FrameBound>)>,
+  _marker: PhantomData<M>,
+}
+
+impl<M: crate::Manageable> Default for Window<M> {
+  fn default() -> Self {
+      Self {
+          base: None,
+          partitions: None,
+          orders: None,
+          frame: None,
+          _marker: PhantomData
+      }
+  }
+}
+
+impl<M: crate::Manageable> Window<M> {
+  pub fn base(&mut self, window_name: &str) -> &mut Self {
+    self.base = Some(window_name.to_owned());
+    self
+  }
+  pub fn partition_by(&mut self, column_names: &[&str]) -> &mut Self {
+    self.partitions.get_or_insert_with(Vec::new).extend(column_names.iter().map(|column_name| column_name.to_string()));
+    self
+  }
+  // eg: {"created_at": "desc"}
+  pub fn order<T: serde::Serialize>(&mut self, condition: T) -> &mut Self {
+    self.orders.get_or_insert_with(Vec::new).push(super::Order::<M>::new(serde_json::json!(condition)));
+    self
+  }
+  pub fn frame(&mut self, unit: FrameUnit, start: FrameBound, end: Option<FrameBound>) -> &mut Self {
+    self.frame = Some((unit, start, end));
+    self
+  }
+  pub fn rows_between(&mut self, start: FrameBound, end: FrameBound) -> &mut Self {
+    self.frame(FrameUnit::Rows, start, Some(end))
+  }
+  pub fn range_between(&mut self, start: FrameBound, end: FrameBound) -> &mut Self {
+    self.frame(FrameUnit::Range, start, Some(end))
+  }
+  fn frame_to_sql(&self, unit: FrameUnit, start: FrameBound, end: Option<FrameBound>, dialect: crate::Dialect) -> Result<String, crate::error::SqlError> {
+    let unit = match unit {
+      FrameUnit::Rows => "ROWS",
+      FrameUnit::Range => {
+        // mssql: RANGE 只支持 UNBOUNDED 和 CURRENT ROW
+        let has_offset = |bound: FrameBound| matches!(bound, FrameBound::Preceding(_) | FrameBound::Following(_));
+        if dialect == crate::Dialect::Mssql && (has_offset(start) || end.is_some_and(has_offset)) {
+          return Err(crate::error::SqlError::DialectUnsupported { dialect, feature: "RANGE frame with offset".to_owned() });
+        }
+        "RANGE"
+      },
+      FrameUnit::Groups => {
+        if !matches!(dialect, crate::Dialect::Postgresql | crate::Dialect::Sqlite) {
+          return Err(crate::error::SqlError::DialectUnsupported { dialect, feature: "GROUPS frame".to_owned() });
+        }
+        "GROUPS"
+      },
+    };
+    if start == FrameBound::UnboundedFollowing || end == Some(FrameBound::UnboundedPreceding) {
+      return Err(crate::error::SqlError::UnsupportedValue { clause: "OVER", value: format!("{:?}", (start, end)) });
+    }
+    Ok(match end {
+      Some(end) => format!("{} BETWEEN {} AND {}", unit, start.to_sql(), end.to_sql()),
+      None => format!("{} {}", unit, start.to_sql()),
+    })
+  }
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    let mut values = vec![];
+    if let Some(ref base) = self.base {
+      values.push(Sql::new(dialect.try_quote_identifier(base)?));
+    }
+    if let Some(ref partitions) = self.partitions {
+      let partitions = partitions.iter().map(|column_name| dialect.try_quote_identifier(M::table_column_name(column_name)?)).collect::<Result<Vec<String>, crate::error::SqlError>>()?;
+      values.push(Sql::new(format!("PARTITION BY {}", partitions.join(","))));
+    }
+    if let Some(ref orders) = self.orders {
+      let orders = orders.iter().map(|order| order.to_sql(dialect)).collect::<Result<Vec<Sql>, crate::error::SqlError>>()?;
+      let mut sql = Sql::new("ORDER BY ".to_owned());
+      sql.push_sqls(&orders, ",");
+      values.push(sql);
+    }
+    if let Some((unit, start, end)) = self.frame {
+      values.push(Sql::new(self.frame_to_sql(unit, start, end, dialect)?));
+    }
+    let mut sql = Sql::default();
+    if !values.is_empty() {
+      sql.push_sqls(&values, " ");
+    }
+    sql.dialect = Some(dialect);
+    Ok(sql)
+  }
+}
+
+// eg: ROW_NUMBER() OVER (PARTITION BY user_id ORDER BY created_at), SUM(amount) OVER w
+pub struct WindowFunction<M: crate::Manageable> {
+  pub function: String,
+  // 字符串为列名, 其他为字面量, eg: LAG(amount, 1, 0)
+  pub args: Vec<serde_json::Value>,
+  // OVER w
+  pub window_name: Option<String>,
+  // OVER (...)
+  pub window: Option<Window<M>>,
+}
+
+impl<M: crate::Manageable> WindowFunction<M> {
+  pub fn new(function: &str, args: Vec<serde_json::Value>) -> Self {
+    Self {
+      function: function.to_owned(),
+      args,
+      window_name: None,
+      window: None,
+    }
+  }
+  pub fn row_number() -> Self { Self::new("ROW_NUMBER", vec![]) }
+  pub fn rank() -> Self { Self::new("RANK", vec![]) }
+  pub fn dense_rank() -> Self { Self::new("DENSE_RANK", vec![]) }
+  pub fn lag(column_name: &str, offset: u64) -> Self { Self::new("LAG", vec![column_name.into(), offset.into()]) }
+  pub fn lead(column_name: &str, offset: u64) -> Self { Self::new("LEAD", vec![column_name.into(), offset.into()]) }
+  pub fn first_value(column_name: &str) -> Self { Self::new("FIRST_VALUE", vec![column_name.into()]) }
+  pub fn last_value(column_name: &str) -> Self { Self::new("LAST_VALUE", vec![column_name.into()]) }
+  pub fn count() -> Self { Self::new("COUNT", vec!["*".into()]) }
+  pub fn sum(column_name: &str) -> Self { Self::new("SUM", vec![column_name.into()]) }
+  pub fn avg(column_name: &str) -> Self { Self::new("AVG", vec![column_name.into()]) }
+  pub fn min(column_name: &str) -> Self { Self::new("MIN", vec![column_name.into()]) }
+  pub fn max(column_name: &str) -> Self { Self::new("MAX", vec![column_name.into()]) }
+
+  pub fn over(&mut self, window: Window<M>) -> &mut Self {
+    self.window = Some(window);
+    self.window_name = None;
+    self
+  }
+  pub fn over_window(&mut self, window_name: &str) -> &mut Self {
+    self.window_name = Some(window_name.to_owned());
+    self.window = None;
+    self
+  }
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    if self.function.is_empty() || !self.function.chars().all(|char| char.is_ascii_alphanumeric() || char == '_') {
+      return Err(crate::error::SqlError::UnsupportedValue { clause: "OVER", value: self.function.clone() });
+    }
+    let mut args = vec![];
+    for arg in self.args.iter() {
+      args.push(match arg {
+        serde_json::Value::String(column_name) if column_name == "*" => column_name.to_owned(),
+        serde_json::Value::String(column_name) => dialect.try_quote_identifier(M::table_column_name(column_name)?)?,
+        _ => crate::methods::json_value_to_sql_value(arg).and_then(|value| value.to_sql_literal(dialect)).map_err(|error| error.with_clause("OVER"))?,
+      });
+    }
+    let mut sql = Sql::new(format!("{}({}) OVER ", self.function.to_uppercase(), args.join(", ")));
+    match (&self.window_name, &self.window) {
+      (Some(window_name), _) => { sql.push_value(&dialect.try_quote_identifier(window_name)?); },
+      (None, Some(window)) => { sql.push('(').push_sql(&window.to_sql(dialect)?).push(')'); },
+      (None, None) => { sql.push_value("()"); },
+    }
+    sql.dialect = Some(dialect);
+    Ok(sql)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn to_sql() {
+      struct Order {}
+      impl crate::Manageable for Order {}
+
+      let mut window = Window::<Order>::default();
+      window.partition_by(&["user_id"]).order(serde_json::json!({"created_at": "desc"})).order(serde_json::json!({"id": "asc"})).rows_between(FrameBound::Preceding(2), FrameBound::CurrentRow);
+      let mut function = WindowFunction::<Order>::sum("amount");
+      function.over(window);
+      assert_eq!(String::try_from(function.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "SUM(`amount`) OVER (PARTITION BY `user_id` ORDER BY `created_at` desc,`id` asc ROWS BETWEEN 2 PRECEDING AND CURRENT ROW)");
+
+      let mut function = WindowFunction::<Order>::lag("amount", 1);
+      function.args.push(serde_json::json!(0));
+      function.over_window("w");
+      assert_eq!(String::try_from(function.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "LAG([amount], 1, 0) OVER [w]");
+      assert_eq!(String::try_from(WindowFunction::<Order>::row_number().to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "ROW_NUMBER() OVER ()");
+    }
+    #[test]
+    fn to_sql_with_frame() {
+      struct Order {}
+      impl crate::Manageable for Order {}
+
+      let mut window = Window::<Order>::default();
+      window.base("w").frame(FrameUnit::Groups, FrameBound::UnboundedPreceding, None);
+      assert_eq!(String::try_from(window.to_sql(crate::Dialect::Sqlite).unwrap()).unwrap(), "\"w\" GROUPS UNBOUNDED PRECEDING");
+      assert_eq!(window.to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::DialectUnsupported { dialect: crate::Dialect::Mysql, feature: "GROUPS frame".to_owned() });
+
+      let mut window = Window::<Order>::default();
+      window.order(serde_json::json!({"amount": "asc"})).range_between(FrameBound::Preceding(100), FrameBound::Following(100));
+      assert_eq!(String::try_from(window.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "ORDER BY \"amount\" asc RANGE BETWEEN 100 PRECEDING AND 100 FOLLOWING");
+      assert_eq!(window.to_sql(crate::Dialect::Mssql).unwrap_err(), crate::SqlError::DialectUnsupported { dialect: crate::Dialect::Mssql, feature: "RANGE frame with offset".to_owned() });
+      window.range_between(FrameBound::CurrentRow, FrameBound::UnboundedPreceding);
+      assert!(window.to_sql(crate::Dialect::Postgresql).is_err());
+
+      let function = WindowFunction::<Order>::new("SUM) OVER (); DROP TABLE orders; --", vec![]);
+      assert!(function.to_sql(crate::Dialect::Postgresql).is_err());
+    }
+}
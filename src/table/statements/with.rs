@@ -0,0 +1,79 @@
+use crate::collectors::Sql;
+
+pub struct Cte {
+  pub name: String,
+  // eg: tree (id, parent_id, depth) AS (...)
+  pub column_names: Option<Vec<String>>,
+  // postgresql, sqlite: Some(true): MATERIALIZED, Some(false): NOT MATERIALIZED
+  pub materialized: Option<bool>,
+  pub subquery: super::Subquery,
+}
+
+impl Cte {
+  pub fn new<T: Into<super::Subquery>>(name: &str, query: T) -> Self {
+    Self {
+      name: name.to_owned(),
+      column_names: None,
+      materialized: None,
+      subquery: query.into(),
+    }
+  }
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    let mut sql = Sql::new(dialect.try_quote_identifier(&self.name)?);
+    if let Some(ref column_names) = self.column_names {
+      let column_names = column_names.iter().map(|column_name| dialect.try_quote_identifier(column_name)).collect::<Result<Vec<String>, crate::error::SqlError>>()?;
+      sql.push_value(&format!(" ({})", column_names.join(",")));
+    }
+    sql.push_value(" AS ");
+    if let Some(materialized) = self.materialized {
+      if !matches!(dialect, crate::Dialect::Postgresql | crate::Dialect::Sqlite) {
+        return Err(crate::error::SqlError::DialectUnsupported { dialect, feature: "MATERIALIZED".to_owned() });
+      }
+      sql.push_value(if materialized { "MATERIALIZED " } else { "NOT MATERIALIZED " });
+    }
+    sql.push_sql(&self.subquery.to_sql(dialect)?);
+    Ok(sql)
+  }
+}
+
+#[derive(Default)]
+pub struct With {
+  pub recursive: bool,
+  pub ctes: Vec<Cte>,
+}
+
+impl With {
+  pub fn to_sql(&self, dialect: crate::Dialect) -> Result<Sql, crate::error::SqlError> {
+    // mssql: 递归 CTE 不需要 RECURSIVE
+    let mut sql = if self.recursive && dialect != crate::Dialect::Mssql { Sql::new("WITH RECURSIVE ".to_owned()) } else { Sql::new("WITH ".to_owned()) };
+    let ctes = self.ctes.iter().map(|cte| cte.to_sql(dialect)).collect::<Result<Vec<Sql>, crate::error::SqlError>>()?;
+    sql.push_sqls(&ctes, ", ");
+    sql.dialect = Some(dialect);
+    Ok(sql)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn to_sql() {
+      struct Category {}
+      impl crate::Manageable for Category {}
+
+      let mut roots = crate::manager::SelectManager::<Category>::default();
+      roots.where_prepare(serde_json::json!({"parent_id": null}));
+      let mut with = With::default();
+      let mut cte = Cte::new("roots", roots);
+      cte.column_names = Some(vec!["id".to_owned(), "name".to_owned()]);
+      cte.materialized = Some(false);
+      with.ctes.push(cte);
+      assert_eq!(String::try_from(with.to_sql(crate::Dialect::Postgresql).unwrap()).unwrap(), "WITH \"roots\" (\"id\",\"name\") AS NOT MATERIALIZED (SELECT * FROM \"categories\" WHERE \"parent_id\" IS NULL)");
+      assert_eq!(with.to_sql(crate::Dialect::Mysql).unwrap_err(), crate::SqlError::DialectUnsupported { dialect: crate::Dialect::Mysql, feature: "MATERIALIZED".to_owned() });
+
+      with.ctes[0].materialized = None;
+      with.recursive = true;
+      assert_eq!(String::try_from(with.to_sql(crate::Dialect::Mysql).unwrap()).unwrap(), "WITH RECURSIVE `roots` (`id`,`name`) AS (SELECT * FROM `categories` WHERE `parent_id` IS NULL)");
+      assert_eq!(String::try_from(with.to_sql(crate::Dialect::Mssql).unwrap()).unwrap(), "WITH [roots] ([id],[name]) AS (SELECT * FROM [categories] WHERE [parent_id] IS NULL)");
+    }
+}
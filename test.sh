@@ -1,4 +1,2 @@
-cargo test --no-default-features --features mysql #--verbose
-cargo test --no-default-features --features postgresql #--verbose
-cargo test --no-default-features --features mssql #--verbose
-cargo test --no-default-features --features sqlite #--verbose
\ No newline at end of file
+cargo test --workspace #--verbose
+cargo test --workspace --all-features #--verbose
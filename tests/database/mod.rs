@@ -1,2 +1,4 @@
-#[cfg(feature = "mysql")]
-mod mysql;
\ No newline at end of file
+mod mysql;
+mod postgresql;
+mod sqlite;
+mod mssql;
\ No newline at end of file
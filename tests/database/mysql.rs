@@ -1,7 +1,7 @@
-use sql_gen::SqlGen;
+use sql_gen::{Dialect, SqlGen};
 
 fn test_create_database() -> Result<(), sql_gen::SqlError> {
-  let sql: String = SqlGen::create_database("db_prod").try_into()?;
+  let sql: String = SqlGen::create_database("db_prod").to_sql(Dialect::Mysql).to_sql_string()?;
   assert_eq!(sql, "CREATE DATABASE IF NOT EXISTS db_prod CHARACTER SET utf8mb4 COLLATE utf8mb4_0900_ai_ci;");
 
   Ok(())
@@ -11,14 +11,14 @@ fn test_alter_database() -> Result<(), sql_gen::SqlError> {
   let sql: String = SqlGen::alter_database("db_prod", |db| {
     db.encryption = Some('Y');
     db.read_only = Some(1);
-  }).try_into()?;
+  }).to_sql(Dialect::Mysql).to_sql_string()?;
   assert_eq!(sql, "ALTER DATABASE db_prod ENCRYPTION 'Y' READ ONLY 1;");
 
   Ok(())
 }
 
 fn test_drop_database() -> Result<(), sql_gen::SqlError> {
-  let sql: String = SqlGen::drop_database("db_prod").try_into()?;
+  let sql: String = SqlGen::drop_database("db_prod").to_sql(Dialect::Mysql).to_sql_string()?;
   assert_eq!(sql, "DROP DATABASE IF EXISTS db_prod;");
 
   Ok(())
@@ -0,0 +1,36 @@
+use sql_gen::{Dialect, SqlGen};
+
+fn test_create_database() -> Result<(), sql_gen::SqlError> {
+  let sql: String = SqlGen::create_database("db_prod").to_sql(Dialect::Postgresql).to_sql_string()?;
+  assert_eq!(sql, "CREATE DATABASE db_prod ENCODING 'UTF8';");
+
+  Ok(())
+}
+
+fn test_alter_database() -> Result<(), sql_gen::SqlError> {
+  let sql: String = SqlGen::alter_database("db_prod", |db| {
+    db.encryption = Some('Y');
+    db.read_only = Some(1);
+  }).to_sql(Dialect::Postgresql).to_sql_string()?;
+  assert_eq!(sql, "ALTER DATABASE db_prod SET default_transaction_read_only = true;");
+
+  Ok(())
+}
+
+fn test_drop_database() -> Result<(), sql_gen::SqlError> {
+  let sql: String = SqlGen::drop_database("db_prod").to_sql(Dialect::Postgresql).to_sql_string()?;
+  assert_eq!(sql, "DROP DATABASE IF EXISTS db_prod;");
+
+  Ok(())
+}
+
+async fn main_test() -> Result<(), sql_gen::SqlError> {
+  test_create_database()?;
+  test_alter_database()?;
+  test_drop_database()?;
+  Ok(())
+}
+#[test]
+fn test_database() {
+    assert!(tokio_test::block_on(main_test()).is_ok());
+}
@@ -0,0 +1,237 @@
+use sql_gen::prelude::*;
+
+fn test_create_table() -> Result<(), sql_gen::SqlError> {
+  // id column
+  let sql: String = SqlGen::create_table("users", |table| {
+    table.add_column_integer("id", |integer| {
+      integer.set_null(false).set_auto_increment(true).set_primary_key(true).set_comment("ID");
+    })
+  }).to_sql(Dialect::Mssql)?.to_sql_string()?;
+  assert_eq!(sql, "IF OBJECT_ID(N'users', N'U') IS NULL CREATE TABLE [users] (\n[id] INT NOT NULL IDENTITY(1,1),\nCONSTRAINT [pk_on_id] PRIMARY KEY ([id])\n);\nEXEC sp_addextendedproperty N'MS_Description', N'ID', N'SCHEMA', N'dbo', N'TABLE', N'users', N'COLUMN', N'id';");
+
+  // integer column
+  let sql: String = SqlGen::create_table("users", |table| {
+    table.add_column_integer("id", |integer| {
+      integer.set_null(false).set_auto_increment(true).set_primary_key(true).set_comment("ID");
+    });
+    table.add_column_integer("user_id", |integer| {
+      integer.set_null(false).set_foreign_key("user_id".into()).set_comment("user_id");
+    });
+    // boolean
+    table.add_column_boolean("is_deleted", |boolean| {
+      boolean.set_null(false).set_default(true).set_comment("软删除").set_index(true);
+    });
+    // integer
+    table.add_column_integer("age", |integer| {
+      integer.set_unsigned(true).set_null(false).set_default(18).set_comment("年龄");
+    });
+    // float
+    table.add_column_float("price", |float| {
+      float.set_null(false).set_default(1.0).set_comment("价格");
+    });
+    // double
+    table.add_column_double("rate", |double| {
+      double.set_null(false).set_default(1.0).set_comment("利率");
+    });
+    // decimal
+    table.add_column_decimal("longitude", |decimal| {
+      decimal.set_precision_scale(10, 6).set_null(false).set_default(1.0).set_comment("经度");
+    });
+    table.add_column_decimal("latitude", |decimal| {
+      decimal.set_precision_scale(10, 6).set_null(false).set_default(1.0).set_comment("纬度");
+    });
+    // string
+    table.add_column_string("name", |string| {
+      string.set_length(200);
+    });
+    table.add_column_string("email", |string| {
+      string.set_null(false).set_index(true).set_unique(true);
+    });
+    table.add_column_text("remark", |_text| {});
+    // time
+    table.add_column_time("time_at", |_time| {});
+    // date
+    table.add_column_date("date_at", |_date| {});
+    // // datetime
+    table.add_column_datetime("datetime_at", |_datetime| {});
+    // // timestamp
+    table.add_column_timestamp("created_at", |_time| {});
+    // // index
+    table.add_index(vec!["name"], |_index| {
+      // index.set_unique(true);
+    });
+    table.add_unique(vec!["username"], |_index| {
+      // index.set_unique(true);
+    });
+    table.add_foreign_key("order_id", |_foreign_key| {});
+  }).to_sql(Dialect::Mssql)?.to_sql_string()?;
+  assert_eq!(sql, "IF OBJECT_ID(N'users', N'U') IS NULL CREATE TABLE [users] (
+[id] INT NOT NULL IDENTITY(1,1),
+[user_id] INT NOT NULL,
+[is_deleted] BIT NOT NULL DEFAULT 1,
+[age] INT NOT NULL DEFAULT 18,
+[price] REAL NOT NULL DEFAULT 1,
+[rate] FLOAT NOT NULL DEFAULT 1,
+[longitude] DECIMAL(10, 6) NOT NULL DEFAULT 1,
+[latitude] DECIMAL(10, 6) NOT NULL DEFAULT 1,
+[name] NVARCHAR(200),
+[email] NVARCHAR(255) NOT NULL,
+[remark] NVARCHAR(MAX),
+[time_at] TIME,
+[date_at] DATE,
+[datetime_at] DATETIME2,
+[created_at] DATETIMEOFFSET,
+CONSTRAINT [pk_on_id] PRIMARY KEY ([id]),
+CONSTRAINT [fk_on_user_id] FOREIGN KEY ([user_id]) REFERENCES [users] ([id]),
+CONSTRAINT [unique_on_username] UNIQUE ([username]),
+CONSTRAINT [fk_on_order_id] FOREIGN KEY ([order_id]) REFERENCES [orders] ([id])
+);
+CREATE INDEX [index_on_is_deleted] ON [users] ([is_deleted]);
+CREATE UNIQUE INDEX [unique_index_on_email] ON [users] ([email]);
+CREATE INDEX [index_on_name] ON [users] ([name]);
+EXEC sp_addextendedproperty N'MS_Description', N'ID', N'SCHEMA', N'dbo', N'TABLE', N'users', N'COLUMN', N'id';
+EXEC sp_addextendedproperty N'MS_Description', N'user_id', N'SCHEMA', N'dbo', N'TABLE', N'users', N'COLUMN', N'user_id';
+EXEC sp_addextendedproperty N'MS_Description', N'软删除', N'SCHEMA', N'dbo', N'TABLE', N'users', N'COLUMN', N'is_deleted';
+EXEC sp_addextendedproperty N'MS_Description', N'年龄', N'SCHEMA', N'dbo', N'TABLE', N'users', N'COLUMN', N'age';
+EXEC sp_addextendedproperty N'MS_Description', N'价格', N'SCHEMA', N'dbo', N'TABLE', N'users', N'COLUMN', N'price';
+EXEC sp_addextendedproperty N'MS_Description', N'利率', N'SCHEMA', N'dbo', N'TABLE', N'users', N'COLUMN', N'rate';
+EXEC sp_addextendedproperty N'MS_Description', N'经度', N'SCHEMA', N'dbo', N'TABLE', N'users', N'COLUMN', N'longitude';
+EXEC sp_addextendedproperty N'MS_Description', N'纬度', N'SCHEMA', N'dbo', N'TABLE', N'users', N'COLUMN', N'latitude';");
+
+  Ok(())
+}
+
+fn test_alter_table_columns() ->  Result<(), sql_gen::SqlError> {
+  // rename_table
+  let sql: String = SqlGen::rename_table("users", "new_users").to_sql(Dialect::Mssql)?.to_sql_string()?;
+  assert_eq!(sql, "EXEC sp_rename N'users', N'new_users';");
+
+  let table = SqlGen::alter_table("users", |table| {
+    table.add_column_string("name", |string| {
+      string.set_null(false);
+    });
+    table.modify_column_boolean("is_deleted", |boolean| {
+      boolean.set_default(false);
+    });
+    table.modify_column_integer("age", |integer| {
+      integer.set_unsigned(true);
+    });
+    table.modify_column_float("price", |float| {
+      float.set_default(0.0);
+    });
+    table.modify_column_double("rate", |double| {
+      double.set_default(0.0);
+    });
+    table.modify_column_decimal("rate", |decimal| {
+      decimal.set_precision_scale(10, 6);
+    });
+    table.modify_column_string("phone", |string| {
+      string.set_length(20).set_unique(true);
+    });
+    table.modify_column_text("remark", |text| {
+      text.set_default("''");
+    });
+    table.change_column_text("desc", "description", |text| {
+      text.set_not_null(true);
+    });
+    table.rename_column_string("addr", "address");
+    table.drop_column_string("email");
+    table.add_index(vec!["name", "phone"], |index| { 
+      index.set_unique(true);
+    });
+    table.drop_index("index_on_username");
+    table.drop_unique("unique_on_username");
+    table.drop_primary_key("id");
+    table.drop_foreign_key("order_id");
+    table.rename_index("index_on_uid", "idx_on_uid");
+  });
+  let sql: String = table.to_sql(Dialect::Mssql)?.to_sql_string()?;
+  assert_eq!(sql, "ALTER TABLE [users] ADD [name] NVARCHAR(255) NOT NULL;
+ALTER TABLE [users] ALTER COLUMN [is_deleted] BIT;
+ALTER TABLE [users] ADD CONSTRAINT [df_users_is_deleted] DEFAULT 0 FOR [is_deleted];
+ALTER TABLE [users] ALTER COLUMN [age] INT;
+ALTER TABLE [users] ALTER COLUMN [price] REAL;
+ALTER TABLE [users] ADD CONSTRAINT [df_users_price] DEFAULT 0 FOR [price];
+ALTER TABLE [users] ALTER COLUMN [rate] FLOAT;
+ALTER TABLE [users] ADD CONSTRAINT [df_users_rate] DEFAULT 0 FOR [rate];
+ALTER TABLE [users] ALTER COLUMN [rate] DECIMAL(10, 6);
+ALTER TABLE [users] ALTER COLUMN [phone] NVARCHAR(20);
+ALTER TABLE [users] ALTER COLUMN [remark] NVARCHAR(MAX);
+ALTER TABLE [users] ADD CONSTRAINT [df_users_remark] DEFAULT '' FOR [remark];
+ALTER TABLE [users] ALTER COLUMN [desc] NVARCHAR(MAX) NOT NULL;
+ALTER TABLE [users] DROP COLUMN [email];
+ALTER TABLE [users] DROP CONSTRAINT [unique_on_username];
+ALTER TABLE [users] DROP CONSTRAINT [pk_on_id];
+ALTER TABLE [users] DROP CONSTRAINT [fk_on_order_id];
+EXEC sp_rename N'users.desc', N'description', N'COLUMN';
+EXEC sp_rename N'users.addr', N'address', N'COLUMN';
+CREATE UNIQUE INDEX [unique_index_on_name_and_phone] ON [users] ([name],[phone]);
+DROP INDEX [index_on_username] ON [users];
+EXEC sp_rename N'users.index_on_uid', N'idx_on_uid', N'INDEX';");
+
+  // schema.table
+  let sql: String = SqlGen::alter_table("sales.orders", |table| {
+    table.add_column_integer("amount", |integer| {
+      integer.set_comment("金额");
+    });
+    table.modify_column_integer("total", |integer| {
+      integer.set_default(0).set_comment("合计");
+    });
+    table.rename_column_integer("qty", "quantity");
+  }).to_sql(Dialect::Mssql)?.to_sql_string()?;
+  assert_eq!(sql, "ALTER TABLE [sales].[orders] ADD [amount] INT;
+ALTER TABLE [sales].[orders] ALTER COLUMN [total] INT;
+ALTER TABLE [sales].[orders] ADD CONSTRAINT [df_orders_total] DEFAULT 0 FOR [total];
+EXEC sp_rename N'sales.orders.qty', N'quantity', N'COLUMN';
+EXEC sp_addextendedproperty N'MS_Description', N'金额', N'SCHEMA', N'sales', N'TABLE', N'orders', N'COLUMN', N'amount';
+IF EXISTS (SELECT 1 FROM sys.extended_properties WHERE major_id = OBJECT_ID(N'sales.orders') AND minor_id = COLUMNPROPERTY(OBJECT_ID(N'sales.orders'), N'total', 'ColumnId') AND name = N'MS_Description') EXEC sp_updateextendedproperty N'MS_Description', N'合计', N'SCHEMA', N'sales', N'TABLE', N'orders', N'COLUMN', N'total' ELSE EXEC sp_addextendedproperty N'MS_Description', N'合计', N'SCHEMA', N'sales', N'TABLE', N'orders', N'COLUMN', N'total';");
+  let sql: String = SqlGen::rename_table("sales.orders", "sales.new_orders").to_sql(Dialect::Mssql)?.to_sql_string()?;
+  assert_eq!(sql, "EXEC sp_rename N'sales.orders', N'new_orders';");
+
+  // 修改列名后按新列名更新注释
+  let sql: String = SqlGen::alter_table("users", |table| {
+    table.change_column_integer("qty", "quantity", |integer| {
+      integer.set_comment("数量");
+    });
+  }).to_sql(Dialect::Mssql)?.to_sql_string()?;
+  assert_eq!(sql, "ALTER TABLE [users] ALTER COLUMN [qty] INT;
+EXEC sp_rename N'users.qty', N'quantity', N'COLUMN';
+IF EXISTS (SELECT 1 FROM sys.extended_properties WHERE major_id = OBJECT_ID(N'dbo.users') AND minor_id = COLUMNPROPERTY(OBJECT_ID(N'dbo.users'), N'quantity', 'ColumnId') AND name = N'MS_Description') EXEC sp_updateextendedproperty N'MS_Description', N'数量', N'SCHEMA', N'dbo', N'TABLE', N'users', N'COLUMN', N'quantity' ELSE EXEC sp_addextendedproperty N'MS_Description', N'数量', N'SCHEMA', N'dbo', N'TABLE', N'users', N'COLUMN', N'quantity';");
+
+  // 存储过程的参数使用 quote_string 转义
+  let sql: String = SqlGen::alter_table("O'Brien", |table| {
+    table.rename_column_integer("it's", "its");
+    table.add_column_integer("amount", |integer| {
+      integer.set_comment("Tom's");
+    });
+  }).to_sql(Dialect::Mssql)?.to_sql_string()?;
+  assert_eq!(sql, "ALTER TABLE [O'Brien] ADD [amount] INT;
+EXEC sp_rename N'O''Brien.it''s', N'its', N'COLUMN';
+EXEC sp_addextendedproperty N'MS_Description', N'Tom''s', N'SCHEMA', N'dbo', N'TABLE', N'O''Brien', N'COLUMN', N'amount';");
+  let sql = SqlGen::alter_table("users", |table| {
+    table.add_column_integer("amount", |integer| {
+      integer.set_comment("a\0b");
+    });
+  }).to_sql(Dialect::Mssql);
+  assert!(matches!(sql, Err(sql_gen::SqlError::DialectUnsupported { dialect: Dialect::Mssql, .. })));
+
+  Ok(())
+}
+
+fn test_drop_table() -> Result<(), sql_gen::SqlError> {
+  let sql: String = SqlGen::drop_table("users").to_sql(Dialect::Mssql)?.to_sql_string()?;
+  assert_eq!(sql, "DROP TABLE IF EXISTS [users];");
+
+  Ok(())
+}
+
+async fn main_test() -> Result<(), sql_gen::SqlError> {
+  test_create_table()?;
+  test_alter_table_columns()?;
+  test_drop_table()?;
+  Ok(())
+}
+#[test]
+fn test_table() {
+    assert!(tokio_test::block_on(main_test()).is_ok());
+}
\ No newline at end of file
@@ -6,8 +6,8 @@ fn test_create_table() -> Result<(), sql_gen::SqlError> {
     table.add_column_integer("id", |integer| {
       integer.set_null(false).set_auto_increment(true).set_primary_key(true).set_comment("ID");
     })
-  }).try_into()?;
-  assert_eq!(sql, "CREATE TABLE IF NOT EXISTS users (\nid INT NOT NULL AUTO_INCREMENT COMMENT 'ID',\nPRIMARY KEY pk_on_id (id)\n);");
+  }).to_sql(Dialect::Mysql)?.to_sql_string()?;
+  assert_eq!(sql, "CREATE TABLE IF NOT EXISTS `users` (\n`id` INT NOT NULL AUTO_INCREMENT COMMENT 'ID',\nPRIMARY KEY `pk_on_id` (`id`)\n);");
 
   // integer column
   let sql: String = SqlGen::create_table("users", |table| {
@@ -64,30 +64,30 @@ fn test_create_table() -> Result<(), sql_gen::SqlError> {
       // index.set_unique(true);
     });
     table.add_foreign_key("order_id", |_foreign_key| {});
-  }).try_into()?;
-  assert_eq!(sql, "CREATE TABLE IF NOT EXISTS users (
-id INT NOT NULL AUTO_INCREMENT COMMENT 'ID',
-user_id INT NOT NULL COMMENT 'user_id',
-is_deleted BOOLEAN NOT NULL DEFAULT true COMMENT '软删除',
-age INT UNSIGNED NOT NULL DEFAULT 18 COMMENT '年龄',
-price FLOAT NOT NULL DEFAULT 1 COMMENT '价格',
-rate DOUBLE NOT NULL DEFAULT 1 COMMENT '利率',
-longitude DECIMAL(10, 6) NOT NULL DEFAULT 1 COMMENT '经度',
-latitude DECIMAL(10, 6) NOT NULL DEFAULT 1 COMMENT '纬度',
-name VARCHAR(200),
-email VARCHAR(255) NOT NULL,
-remark TEXT,
-time_at TIME,
-date_at DATE,
-datetime_at DATETIME,
-created_at TIMESTAMP,
-PRIMARY KEY pk_on_id (id),
-FOREIGN KEY fk_on_user_id (user_id) REFERENCES users (id),
-INDEX index_on_is_deleted (is_deleted),
-UNIQUE INDEX unique_index_on_email (email),
-INDEX index_on_name (name),
-UNIQUE unique_on_username (username),
-FOREIGN KEY fk_on_order_id (order_id) REFERENCES orders (id)
+  }).to_sql(Dialect::Mysql)?.to_sql_string()?;
+  assert_eq!(sql, "CREATE TABLE IF NOT EXISTS `users` (
+`id` INT NOT NULL AUTO_INCREMENT COMMENT 'ID',
+`user_id` INT NOT NULL COMMENT 'user_id',
+`is_deleted` BOOLEAN NOT NULL DEFAULT true COMMENT '软删除',
+`age` INT UNSIGNED NOT NULL DEFAULT 18 COMMENT '年龄',
+`price` FLOAT NOT NULL DEFAULT 1 COMMENT '价格',
+`rate` DOUBLE NOT NULL DEFAULT 1 COMMENT '利率',
+`longitude` DECIMAL(10, 6) NOT NULL DEFAULT 1 COMMENT '经度',
+`latitude` DECIMAL(10, 6) NOT NULL DEFAULT 1 COMMENT '纬度',
+`name` VARCHAR(200),
+`email` VARCHAR(255) NOT NULL,
+`remark` TEXT,
+`time_at` TIME,
+`date_at` DATE,
+`datetime_at` DATETIME,
+`created_at` TIMESTAMP,
+PRIMARY KEY `pk_on_id` (`id`),
+FOREIGN KEY `fk_on_user_id` (`user_id`) REFERENCES `users` (`id`),
+INDEX `index_on_is_deleted` (`is_deleted`),
+UNIQUE INDEX `unique_index_on_email` (`email`),
+INDEX `index_on_name` (`name`),
+UNIQUE `unique_on_username` (`username`),
+FOREIGN KEY `fk_on_order_id` (`order_id`) REFERENCES `orders` (`id`)
 );");
 
   Ok(())
@@ -95,8 +95,8 @@ FOREIGN KEY fk_on_order_id (order_id) REFERENCES orders (id)
 
 fn test_alter_table_columns() ->  Result<(), sql_gen::SqlError> {
   // rename_table
-  let sql: String = SqlGen::rename_table("users", "new_users").try_into()?;
-  assert_eq!(sql, "ALTER TABLE users RENAME TO new_users;");
+  let sql: String = SqlGen::rename_table("users", "new_users").to_sql(Dialect::Mysql)?.to_sql_string()?;
+  assert_eq!(sql, "ALTER TABLE `users` RENAME TO `new_users`;");
 
   let table = SqlGen::alter_table("users", |table| {
     table.add_column_string("name", |string| {
@@ -137,32 +137,32 @@ fn test_alter_table_columns() ->  Result<(), sql_gen::SqlError> {
     table.drop_foreign_key("order_id");
     table.rename_index("index_on_uid", "idx_on_uid");
   });
-  let sql: String = table.try_into()?;
-  assert_eq!(sql, "ALTER TABLE users
-ADD COLUMN name VARCHAR(255) NOT NULL,
-MODIFY COLUMN is_deleted BOOLEAN DEFAULT false,
-MODIFY COLUMN age INT UNSIGNED,
-MODIFY COLUMN price FLOAT DEFAULT 0,
-MODIFY COLUMN rate DOUBLE DEFAULT 0,
-MODIFY COLUMN rate DECIMAL(10, 6),
-MODIFY COLUMN phone VARCHAR(20),
-MODIFY COLUMN remark TEXT DEFAULT ,
-CHANGE COLUMN desc description TEXT NOT NULL,
-RENAME COLUMN addr TO address,
-DROP COLUMN email,
-ADD CONSTRAINT UNIQUE INDEX unique_index_on_name_and_phone (name,phone),
-DROP INDEX index_on_username,
-DROP INDEX unique_on_username,
-PRIMARY KEY pk_on_idDROP INDEX id,
-FOREIGN KEY fk_on_order_id,
-RENAME INDEX index_on_uid TO idx_on_uid;");
+  let sql: String = table.to_sql(Dialect::Mysql)?.to_sql_string()?;
+  assert_eq!(sql, "ALTER TABLE `users`
+ADD COLUMN `name` VARCHAR(255) NOT NULL,
+MODIFY COLUMN `is_deleted` BOOLEAN DEFAULT false,
+MODIFY COLUMN `age` INT UNSIGNED,
+MODIFY COLUMN `price` FLOAT DEFAULT 0,
+MODIFY COLUMN `rate` DOUBLE DEFAULT 0,
+MODIFY COLUMN `rate` DECIMAL(10, 6),
+MODIFY COLUMN `phone` VARCHAR(20),
+MODIFY COLUMN `remark` TEXT DEFAULT ,
+CHANGE COLUMN `desc` `description` TEXT NOT NULL,
+RENAME COLUMN `addr` TO `address`,
+DROP COLUMN `email`,
+ADD CONSTRAINT UNIQUE INDEX `unique_index_on_name_and_phone` (`name`,`phone`),
+DROP INDEX `index_on_username`,
+DROP INDEX `unique_on_username`,
+PRIMARY KEY `pk_on_id`DROP INDEX `id`,
+FOREIGN KEY `fk_on_order_id`,
+RENAME INDEX `index_on_uid` TO `idx_on_uid`;");
 
   Ok(())
 }
 
 fn test_drop_table() -> Result<(), sql_gen::SqlError> {
-  let sql: String = SqlGen::drop_table("users").try_into()?;
-  assert_eq!(sql, "DROP TABLE IF EXISTS users;");
+  let sql: String = SqlGen::drop_table("users").to_sql(Dialect::Mysql)?.to_sql_string()?;
+  assert_eq!(sql, "DROP TABLE IF EXISTS `users`;");
 
   Ok(())
 }
@@ -0,0 +1,195 @@
+use sql_gen::prelude::*;
+
+fn test_create_table() -> Result<(), sql_gen::SqlError> {
+  // id column
+  let sql: String = SqlGen::create_table("users", |table| {
+    table.add_column_integer("id", |integer| {
+      integer.set_null(false).set_auto_increment(true).set_primary_key(true).set_comment("ID");
+    })
+  }).to_sql(Dialect::Postgresql)?.to_sql_string()?;
+  assert_eq!(sql, "CREATE TABLE IF NOT EXISTS \"users\" (\n\"id\" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,\nCONSTRAINT \"pk_on_id\" PRIMARY KEY (\"id\")\n);\nCOMMENT ON COLUMN \"users\".\"id\" IS 'ID';");
+
+  // integer column
+  let sql: String = SqlGen::create_table("users", |table| {
+    table.add_column_integer("id", |integer| {
+      integer.set_null(false).set_auto_increment(true).set_primary_key(true).set_comment("ID");
+    });
+    table.add_column_integer("user_id", |integer| {
+      integer.set_null(false).set_foreign_key("user_id".into()).set_comment("user_id");
+    });
+    // boolean
+    table.add_column_boolean("is_deleted", |boolean| {
+      boolean.set_null(false).set_default(true).set_comment("软删除").set_index(true);
+    });
+    // integer
+    table.add_column_integer("age", |integer| {
+      integer.set_unsigned(true).set_null(false).set_default(18).set_comment("年龄");
+    });
+    // float
+    table.add_column_float("price", |float| {
+      float.set_null(false).set_default(1.0).set_comment("价格");
+    });
+    // double
+    table.add_column_double("rate", |double| {
+      double.set_null(false).set_default(1.0).set_comment("利率");
+    });
+    // decimal
+    table.add_column_decimal("longitude", |decimal| {
+      decimal.set_precision_scale(10, 6).set_null(false).set_default(1.0).set_comment("经度");
+    });
+    table.add_column_decimal("latitude", |decimal| {
+      decimal.set_precision_scale(10, 6).set_null(false).set_default(1.0).set_comment("纬度");
+    });
+    // string
+    table.add_column_string("name", |string| {
+      string.set_length(200);
+    });
+    table.add_column_string("email", |string| {
+      string.set_null(false).set_index(true).set_unique(true);
+    });
+    table.add_column_text("remark", |_text| {});
+    // time
+    table.add_column_time("time_at", |_time| {});
+    // date
+    table.add_column_date("date_at", |_date| {});
+    // // datetime
+    table.add_column_datetime("datetime_at", |_datetime| {});
+    // // timestamp
+    table.add_column_timestamp("created_at", |_time| {});
+    // // index
+    table.add_index(vec!["name"], |_index| {
+      // index.set_unique(true);
+    });
+    table.add_unique(vec!["username"], |_index| {
+      // index.set_unique(true);
+    });
+    table.add_foreign_key("order_id", |_foreign_key| {});
+  }).to_sql(Dialect::Postgresql)?.to_sql_string()?;
+  assert_eq!(sql, "CREATE TABLE IF NOT EXISTS \"users\" (
+\"id\" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+\"user_id\" INTEGER NOT NULL,
+\"is_deleted\" BOOLEAN NOT NULL DEFAULT true,
+\"age\" INTEGER NOT NULL DEFAULT 18,
+\"price\" REAL NOT NULL DEFAULT 1,
+\"rate\" DOUBLE PRECISION NOT NULL DEFAULT 1,
+\"longitude\" NUMERIC(10, 6) NOT NULL DEFAULT 1,
+\"latitude\" NUMERIC(10, 6) NOT NULL DEFAULT 1,
+\"name\" VARCHAR(200),
+\"email\" VARCHAR(255) NOT NULL,
+\"remark\" TEXT,
+\"time_at\" TIME,
+\"date_at\" DATE,
+\"datetime_at\" TIMESTAMP,
+\"created_at\" TIMESTAMPTZ,
+CONSTRAINT \"pk_on_id\" PRIMARY KEY (\"id\"),
+CONSTRAINT \"fk_on_user_id\" FOREIGN KEY (\"user_id\") REFERENCES \"users\" (\"id\"),
+CONSTRAINT \"unique_on_username\" UNIQUE (\"username\"),
+CONSTRAINT \"fk_on_order_id\" FOREIGN KEY (\"order_id\") REFERENCES \"orders\" (\"id\")
+);
+CREATE INDEX \"index_on_is_deleted\" ON \"users\" (\"is_deleted\");
+CREATE UNIQUE INDEX \"unique_index_on_email\" ON \"users\" (\"email\");
+CREATE INDEX \"index_on_name\" ON \"users\" (\"name\");
+COMMENT ON COLUMN \"users\".\"id\" IS 'ID';
+COMMENT ON COLUMN \"users\".\"user_id\" IS 'user_id';
+COMMENT ON COLUMN \"users\".\"is_deleted\" IS '软删除';
+COMMENT ON COLUMN \"users\".\"age\" IS '年龄';
+COMMENT ON COLUMN \"users\".\"price\" IS '价格';
+COMMENT ON COLUMN \"users\".\"rate\" IS '利率';
+COMMENT ON COLUMN \"users\".\"longitude\" IS '经度';
+COMMENT ON COLUMN \"users\".\"latitude\" IS '纬度';");
+
+  Ok(())
+}
+
+fn test_alter_table_columns() ->  Result<(), sql_gen::SqlError> {
+  // rename_table
+  let sql: String = SqlGen::rename_table("users", "new_users").to_sql(Dialect::Postgresql)?.to_sql_string()?;
+  assert_eq!(sql, "ALTER TABLE \"users\" RENAME TO \"new_users\";");
+  let sql: String = SqlGen::rename_table("public.users", "public.new_users").to_sql(Dialect::Postgresql)?.to_sql_string()?;
+  assert_eq!(sql, "ALTER TABLE \"public\".\"users\" RENAME TO \"new_users\";");
+
+  let table = SqlGen::alter_table("users", |table| {
+    table.add_column_string("name", |string| {
+      string.set_null(false);
+    });
+    table.modify_column_boolean("is_deleted", |boolean| {
+      boolean.set_default(false);
+    });
+    table.modify_column_integer("age", |integer| {
+      integer.set_unsigned(true);
+    });
+    table.modify_column_float("price", |float| {
+      float.set_default(0.0);
+    });
+    table.modify_column_double("rate", |double| {
+      double.set_default(0.0);
+    });
+    table.modify_column_decimal("rate", |decimal| {
+      decimal.set_precision_scale(10, 6);
+    });
+    table.modify_column_string("phone", |string| {
+      string.set_length(20).set_unique(true);
+    });
+    table.modify_column_text("remark", |text| {
+      text.set_default("''");
+    });
+    table.change_column_text("desc", "description", |text| {
+      text.set_not_null(true);
+    });
+    table.rename_column_string("addr", "address");
+    table.drop_column_string("email");
+    table.add_index(vec!["name", "phone"], |index| { 
+      index.set_unique(true);
+    });
+    table.drop_index("index_on_username");
+    table.drop_unique("unique_on_username");
+    table.drop_primary_key("id");
+    table.drop_foreign_key("order_id");
+    table.rename_index("index_on_uid", "idx_on_uid");
+  });
+  let sql: String = table.to_sql(Dialect::Postgresql)?.to_sql_string()?;
+  assert_eq!(sql, "ALTER TABLE \"users\"
+ADD COLUMN \"name\" VARCHAR(255) NOT NULL,
+ALTER COLUMN \"is_deleted\" TYPE BOOLEAN,
+ALTER COLUMN \"is_deleted\" SET DEFAULT false,
+ALTER COLUMN \"age\" TYPE INTEGER,
+ALTER COLUMN \"price\" TYPE REAL,
+ALTER COLUMN \"price\" SET DEFAULT 0,
+ALTER COLUMN \"rate\" TYPE DOUBLE PRECISION,
+ALTER COLUMN \"rate\" SET DEFAULT 0,
+ALTER COLUMN \"rate\" TYPE NUMERIC(10, 6),
+ALTER COLUMN \"phone\" TYPE VARCHAR(20),
+ALTER COLUMN \"remark\" TYPE TEXT,
+ALTER COLUMN \"remark\" SET DEFAULT '',
+ALTER COLUMN \"desc\" TYPE TEXT,
+ALTER COLUMN \"desc\" SET NOT NULL,
+DROP COLUMN \"email\",
+DROP CONSTRAINT \"unique_on_username\",
+DROP CONSTRAINT \"pk_on_id\",
+DROP CONSTRAINT \"fk_on_order_id\";
+ALTER TABLE \"users\" RENAME COLUMN \"desc\" TO \"description\";
+ALTER TABLE \"users\" RENAME COLUMN \"addr\" TO \"address\";
+CREATE UNIQUE INDEX \"unique_index_on_name_and_phone\" ON \"users\" (\"name\",\"phone\");
+DROP INDEX \"index_on_username\";
+ALTER INDEX \"index_on_uid\" RENAME TO \"idx_on_uid\";");
+
+  Ok(())
+}
+
+fn test_drop_table() -> Result<(), sql_gen::SqlError> {
+  let sql: String = SqlGen::drop_table("users").to_sql(Dialect::Postgresql)?.to_sql_string()?;
+  assert_eq!(sql, "DROP TABLE IF EXISTS \"users\";");
+
+  Ok(())
+}
+
+async fn main_test() -> Result<(), sql_gen::SqlError> {
+  test_create_table()?;
+  test_alter_table_columns()?;
+  test_drop_table()?;
+  Ok(())
+}
+#[test]
+fn test_table() {
+    assert!(tokio_test::block_on(main_test()).is_ok());
+}
\ No newline at end of file
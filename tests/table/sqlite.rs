@@ -0,0 +1,233 @@
+use sql_gen::prelude::*;
+
+fn test_create_table() -> Result<(), sql_gen::SqlError> {
+  // id column
+  let sql: String = SqlGen::create_table("users", |table| {
+    table.add_column_integer("id", |integer| {
+      integer.set_null(false).set_auto_increment(true).set_primary_key(true).set_comment("ID");
+    })
+  }).to_sql(Dialect::Sqlite)?.to_sql_string()?;
+  assert_eq!(sql, "CREATE TABLE IF NOT EXISTS \"users\" (\n\"id\" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT\n);");
+
+  // integer column
+  let sql: String = SqlGen::create_table("users", |table| {
+    table.add_column_integer("id", |integer| {
+      integer.set_null(false).set_auto_increment(true).set_primary_key(true).set_comment("ID");
+    });
+    table.add_column_integer("user_id", |integer| {
+      integer.set_null(false).set_foreign_key("user_id".into()).set_comment("user_id");
+    });
+    // boolean
+    table.add_column_boolean("is_deleted", |boolean| {
+      boolean.set_null(false).set_default(true).set_comment("软删除").set_index(true);
+    });
+    // integer
+    table.add_column_integer("age", |integer| {
+      integer.set_unsigned(true).set_null(false).set_default(18).set_comment("年龄");
+    });
+    // float
+    table.add_column_float("price", |float| {
+      float.set_null(false).set_default(1.0).set_comment("价格");
+    });
+    // double
+    table.add_column_double("rate", |double| {
+      double.set_null(false).set_default(1.0).set_comment("利率");
+    });
+    // decimal
+    table.add_column_decimal("longitude", |decimal| {
+      decimal.set_precision_scale(10, 6).set_null(false).set_default(1.0).set_comment("经度");
+    });
+    table.add_column_decimal("latitude", |decimal| {
+      decimal.set_precision_scale(10, 6).set_null(false).set_default(1.0).set_comment("纬度");
+    });
+    // string
+    table.add_column_string("name", |string| {
+      string.set_length(200);
+    });
+    table.add_column_string("email", |string| {
+      string.set_null(false).set_index(true).set_unique(true);
+    });
+    table.add_column_text("remark", |_text| {});
+    // time
+    table.add_column_time("time_at", |_time| {});
+    // date
+    table.add_column_date("date_at", |_date| {});
+    // // datetime
+    table.add_column_datetime("datetime_at", |_datetime| {});
+    // // timestamp
+    table.add_column_timestamp("created_at", |_time| {});
+    // // index
+    table.add_index(vec!["name"], |_index| {
+      // index.set_unique(true);
+    });
+    table.add_unique(vec!["username"], |_index| {
+      // index.set_unique(true);
+    });
+    table.add_foreign_key("order_id", |_foreign_key| {});
+  }).to_sql(Dialect::Sqlite)?.to_sql_string()?;
+  assert_eq!(sql, "CREATE TABLE IF NOT EXISTS \"users\" (
+\"id\" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+\"user_id\" INTEGER NOT NULL,
+\"is_deleted\" INTEGER NOT NULL DEFAULT true,
+\"age\" INTEGER NOT NULL DEFAULT 18,
+\"price\" REAL NOT NULL DEFAULT 1,
+\"rate\" REAL NOT NULL DEFAULT 1,
+\"longitude\" NUMERIC NOT NULL DEFAULT 1,
+\"latitude\" NUMERIC NOT NULL DEFAULT 1,
+\"name\" TEXT,
+\"email\" TEXT NOT NULL,
+\"remark\" TEXT,
+\"time_at\" TEXT,
+\"date_at\" TEXT,
+\"datetime_at\" TEXT,
+\"created_at\" TEXT,
+CONSTRAINT \"fk_on_user_id\" FOREIGN KEY (\"user_id\") REFERENCES \"users\" (\"id\"),
+CONSTRAINT \"fk_on_order_id\" FOREIGN KEY (\"order_id\") REFERENCES \"orders\" (\"id\")
+);
+CREATE INDEX \"index_on_is_deleted\" ON \"users\" (\"is_deleted\");
+CREATE UNIQUE INDEX \"unique_index_on_email\" ON \"users\" (\"email\");
+CREATE INDEX \"index_on_name\" ON \"users\" (\"name\");
+CREATE UNIQUE INDEX \"unique_on_username\" ON \"users\" (\"username\");");
+
+  Ok(())
+}
+
+fn test_alter_table_columns() ->  Result<(), sql_gen::SqlError> {
+  // rename_table
+  let sql: String = SqlGen::rename_table("users", "new_users").to_sql(Dialect::Sqlite)?.to_sql_string()?;
+  assert_eq!(sql, "ALTER TABLE \"users\" RENAME TO \"new_users\";");
+
+  // ADD COLUMN, RENAME COLUMN, DROP COLUMN, index
+  let sql: String = SqlGen::alter_table("users", |table| {
+    table.add_column_string("name", |string| {
+      string.set_null(false).set_default("''");
+    });
+    table.rename_column_string("addr", "address");
+    table.drop_column_string("email");
+    table.add_index(vec!["name", "phone"], |index| {
+      index.set_unique(true);
+    });
+    table.add_unique(vec!["username"], |_unique| {});
+    table.drop_index("index_on_age");
+  }).to_sql(Dialect::Sqlite)?.to_sql_string()?;
+  assert_eq!(sql, "ALTER TABLE \"users\" ADD COLUMN \"name\" TEXT NOT NULL DEFAULT '';
+ALTER TABLE \"users\" RENAME COLUMN \"addr\" TO \"address\";
+ALTER TABLE \"users\" DROP COLUMN \"email\";
+CREATE UNIQUE INDEX \"unique_index_on_name_and_phone\" ON \"users\" (\"name\",\"phone\");
+CREATE UNIQUE INDEX \"unique_on_username\" ON \"users\" (\"username\");
+DROP INDEX \"index_on_age\";");
+
+  // rebuild table
+  let sql: String = SqlGen::alter_table("users", |table| {
+    table.set_origin_table(SqlGen::create_table("users", |table| {
+      table.add_column_integer("id", |integer| {
+        integer.set_null(false).set_auto_increment(true).set_primary_key(true);
+      });
+      table.add_column_integer("age", |integer| {
+        integer.set_index(true);
+      });
+      table.add_column_text("desc", |_text| {});
+      table.add_column_string("email", |_string| {});
+      table.add_column_integer("order_id", |_integer| {});
+      table.add_foreign_key("order_id", |_foreign_key| {});
+      table.add_index(vec!["desc"], |_index| {});
+    }));
+    table.modify_column_integer("age", |integer| {
+      integer.set_null(false).set_default(18);
+    });
+    table.change_column_text("desc", "description", |text| {
+      text.set_not_null(true);
+    });
+    table.drop_column_string("email");
+    table.drop_foreign_key("order_id");
+    table.add_column_boolean("is_deleted", |boolean| {
+      boolean.set_null(false).set_default(false);
+    });
+    table.rename_index("index_on_age", "idx_on_age");
+  }).to_sql(Dialect::Sqlite)?.to_sql_string()?;
+  assert_eq!(sql, "PRAGMA foreign_keys = OFF;
+BEGIN TRANSACTION;
+CREATE TABLE \"new_users\" (
+\"id\" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+\"age\" INTEGER NOT NULL DEFAULT 18,
+\"description\" TEXT NOT NULL,
+\"order_id\" INTEGER,
+\"is_deleted\" INTEGER NOT NULL DEFAULT false
+);
+INSERT INTO \"new_users\" (\"id\", \"age\", \"description\", \"order_id\") SELECT \"id\", \"age\", \"desc\", \"order_id\" FROM \"users\";
+DROP TABLE \"users\";
+ALTER TABLE \"new_users\" RENAME TO \"users\";
+CREATE INDEX \"index_on_age\" ON \"users\" (\"age\");
+CREATE INDEX \"index_on_description\" ON \"users\" (\"description\");
+DROP INDEX \"index_on_age\";
+CREATE INDEX \"idx_on_age\" ON \"users\" (\"age\");
+PRAGMA foreign_key_check;
+COMMIT;
+PRAGMA foreign_keys = ON;");
+
+  // schema.table
+  let sql: String = SqlGen::alter_table("main.users", |table| {
+    table.set_origin_table(SqlGen::create_table("main.users", |table| {
+      table.add_column_integer("id", |integer| {
+        integer.set_null(false).set_primary_key(true);
+      });
+      table.add_column_integer("age", |integer| {
+        integer.set_index(true);
+      });
+    }));
+    table.modify_column_integer("age", |integer| {
+      integer.set_null(false).set_default(18);
+    });
+    table.rename_index("index_on_age", "idx_on_age");
+  }).to_sql(Dialect::Sqlite)?.to_sql_string()?;
+  assert_eq!(sql, "PRAGMA foreign_keys = OFF;
+BEGIN TRANSACTION;
+CREATE TABLE \"main\".\"new_users\" (
+\"id\" INTEGER NOT NULL,
+\"age\" INTEGER NOT NULL DEFAULT 18,
+CONSTRAINT \"pk_on_id\" PRIMARY KEY (\"id\")
+);
+INSERT INTO \"main\".\"new_users\" (\"id\", \"age\") SELECT \"id\", \"age\" FROM \"main\".\"users\";
+DROP TABLE \"main\".\"users\";
+ALTER TABLE \"main\".\"new_users\" RENAME TO \"users\";
+CREATE INDEX \"main\".\"index_on_age\" ON \"users\" (\"age\");
+DROP INDEX \"main\".\"index_on_age\";
+CREATE INDEX \"main\".\"idx_on_age\" ON \"users\" (\"age\");
+PRAGMA foreign_key_check;
+COMMIT;
+PRAGMA foreign_keys = ON;");
+  let sql: String = SqlGen::rename_table("main.users", "main.new_users").to_sql(Dialect::Sqlite)?.to_sql_string()?;
+  assert_eq!(sql, "ALTER TABLE \"main\".\"users\" RENAME TO \"new_users\";");
+
+  // rebuild table without origin table
+  let table = SqlGen::alter_table("users", |table| {
+    table.modify_column_integer("age", |integer| {
+      integer.set_null(false);
+    });
+  });
+  assert_eq!(table.try_to_sql(Dialect::Sqlite).unwrap_err(), sql_gen::SqlError::DialectUnsupported { dialect: Dialect::Sqlite, feature: "ALTER without origin table".to_owned() });
+  let table = SqlGen::alter_table("users", |table| {
+    table.rename_index("index_on_age", "idx_on_age");
+  });
+  assert_eq!(table.try_to_sql(Dialect::Sqlite).unwrap_err(), sql_gen::SqlError::DialectUnsupported { dialect: Dialect::Sqlite, feature: "RENAME INDEX without origin table".to_owned() });
+
+  Ok(())
+}
+
+fn test_drop_table() -> Result<(), sql_gen::SqlError> {
+  let sql: String = SqlGen::drop_table("users").to_sql(Dialect::Sqlite)?.to_sql_string()?;
+  assert_eq!(sql, "DROP TABLE IF EXISTS \"users\";");
+
+  Ok(())
+}
+
+async fn main_test() -> Result<(), sql_gen::SqlError> {
+  test_create_table()?;
+  test_alter_table_columns()?;
+  test_drop_table()?;
+  Ok(())
+}
+#[test]
+fn test_table() {
+    assert!(tokio_test::block_on(main_test()).is_ok());
+}
\ No newline at end of file